@@ -0,0 +1,77 @@
+use na::{Isometry2, Point2, Unit, Vector2};
+use ncollide2d::pipeline::narrow_phase::{
+    CapsuleCapsuleManifoldGenerator, ContactManifoldGenerator, DefaultContactDispatcher,
+};
+use ncollide2d::query::{Contact, ContactManifold, ContactNormalMode, ContactPrediction};
+use ncollide2d::shape::Capsule;
+
+#[test]
+fn contact_tangent_basis_is_orthonormal() {
+    for i in 0..16 {
+        let angle = i as f64 * std::f64::consts::PI / 8.0;
+        let normal = Unit::new_normalize(Vector2::new(angle.cos(), angle.sin()));
+        let contact = Contact::new(Point2::origin(), Point2::origin(), normal, 0.0);
+        let [tangent] = contact.tangent_basis();
+
+        assert_relative_eq!(tangent.norm(), 1.0, epsilon = 1.0e-10);
+        assert_relative_eq!(tangent.dot(&normal), 0.0, epsilon = 1.0e-10);
+        assert_relative_eq!(normal.perp(&tangent), 1.0, epsilon = 1.0e-10);
+    }
+}
+
+fn crossed_capsules_manifold(mode: ContactNormalMode) -> ContactManifold<f64> {
+    let capsule = Capsule::new(1.0, 0.5);
+    let m1 = Isometry2::identity();
+    // The second capsule is slightly rotated so the two capsule axes are not parallel.
+    let m2 = Isometry2::new(Vector2::new(0.95, 0.0), 0.05);
+    let prediction = ContactPrediction::new(0.1, 0.1, 0.1);
+    let dispatcher = DefaultContactDispatcher::new();
+    let mut generator = CapsuleCapsuleManifoldGenerator::new();
+    let mut manifold = ContactManifold::new();
+
+    manifold.set_normal_mode(mode);
+    assert_eq!(manifold.normal_mode(), mode);
+    assert!(generator.generate_contacts(
+        &dispatcher,
+        &m1,
+        &capsule,
+        None,
+        &m2,
+        &capsule,
+        None,
+        &prediction,
+        &mut manifold,
+    ));
+
+    manifold
+}
+
+#[test]
+fn capsule_manifold_shares_normal_by_default() {
+    let manifold = crossed_capsules_manifold(ContactNormalMode::Shared);
+    let normals: Vec<_> = manifold.contacts_with_normals().map(|(_, n)| n).collect();
+
+    assert_eq!(normals.len(), 2);
+    assert_eq!(normals[0], normals[1]);
+}
+
+#[test]
+fn capsule_manifold_keeps_distinct_normals() {
+    let manifold = crossed_capsules_manifold(ContactNormalMode::PerContact);
+    let contacts: Vec<_> = manifold.contacts_with_normals().collect();
+
+    assert_eq!(contacts.len(), 2);
+    assert!(contacts[0].1.dot(&contacts[1].1) < 1.0 - 1.0e-6);
+
+    for (c, n) in contacts {
+        assert_eq!(c.contact.normal, n);
+        // The witness points are consistent with the normal and the depth.
+        let dist = n.dot(&(c.contact.world2 - c.contact.world1));
+        assert_relative_eq!(dist, -c.contact.depth, epsilon = 1.0e-9);
+        assert_relative_eq!(
+            (c.contact.world2 - c.contact.world1).norm(),
+            c.contact.depth.abs(),
+            epsilon = 1.0e-9
+        );
+    }
+}
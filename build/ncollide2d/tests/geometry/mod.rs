@@ -1,6 +1,9 @@
 mod ball_ball_toi;
 mod ball_cuboid_contact;
 mod compound_penetration;
+mod contact;
 mod epa2;
 mod ray_cast;
+mod rigid_motion;
+mod shape;
 mod time_of_impact2;
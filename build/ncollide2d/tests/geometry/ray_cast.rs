@@ -1,6 +1,6 @@
-use na::{self, Isometry2, Point2, Vector2};
+use na::{self, DVector, Isometry2, Point2, Vector2};
 use ncollide2d::query::{Ray, RayCast};
-use ncollide2d::shape::{ConvexPolygon, Segment, Shape};
+use ncollide2d::shape::{ConvexPolygon, HeightField, Segment, Shape};
 
 #[test]
 fn issue_178_parallel_raycast() {
@@ -137,3 +137,52 @@ fn convexpoly_raycast_fuzz() {
         }
     }
 }
+
+#[test]
+fn ray_cast_on_transformed_heightfield() {
+    // The heightfield is the line `y = 0.5 * x + 1` for `x` in `[-2, 2]`.
+    let heights = DVector::from_fn(5, |i, _| i as f64 * 0.5);
+    let heightfield = HeightField::new(heights, Vector2::new(4.0, 1.0));
+    let m = Isometry2::new(Vector2::new(10.0, -3.0), 0.3);
+
+    let ls_ray = Ray::new(Point2::new(-1.5, 5.0), Vector2::new(1.0, -1.0));
+    let ray = ls_ray.transform_by(&m);
+
+    // Intersection with the line: 5 - t = 0.5 * (-1.5 + t) + 1.
+    let toi = 4.75 / 1.5;
+    let inter = heightfield
+        .toi_and_normal_with_ray(&m, &ray, std::f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, toi, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        ray.point_at(inter.toi),
+        m * Point2::new(-1.5 + toi, 5.0 - toi),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn segment_ray_cast_backface_culling() {
+    let m1 = Isometry2::identity();
+    // The segment normal points toward +y.
+    let seg = Segment::new(Point2::new(1.0, 0.0), Point2::new(-1.0, 0.0));
+    let front = Ray::new(Point2::new(0.0, 2.0), -Vector2::y());
+    let back = Ray::new(Point2::new(0.0, -2.0), Vector2::y());
+    let edge_on = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+
+    let hit = seg
+        .toi_and_normal_with_ray_culled(&m1, &front, std::f32::MAX, true)
+        .unwrap();
+    assert_eq!(hit.toi, 2.0);
+    assert_eq!(hit.normal.normalize(), Vector2::y());
+
+    assert!(seg
+        .toi_and_normal_with_ray_culled(&m1, &back, std::f32::MAX, true)
+        .is_none());
+    assert!(seg
+        .toi_and_normal_with_ray_culled(&m1, &back, std::f32::MAX, false)
+        .is_some());
+    assert!(seg
+        .toi_and_normal_with_ray_culled(&m1, &edge_on, std::f32::MAX, true)
+        .is_none());
+}
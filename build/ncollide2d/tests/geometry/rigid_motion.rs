@@ -0,0 +1,32 @@
+use na::{Isometry2, Point2, Vector2};
+use ncollide2d::interpolation::{
+    ConstantVelocityRigidMotion, InterpolatedRigidMotion, RigidMotion,
+};
+
+#[test]
+fn constant_velocity_exact_at_any_time() {
+    let start = Isometry2::new(Vector2::new(1.0, -2.0), 0.3);
+    let linvel = Vector2::new(2.0, -1.0);
+    let motion = ConstantVelocityRigidMotion::new(0.5, start, Point2::new(0.2, -1.0), linvel, 0.7);
+
+    for t in [-3.0, 0.0, 0.5, 7.25].iter() {
+        // The linear velocity is the one of the local origin, which rotates around the center.
+        let h = 1.0e-6;
+        let origin = |t| motion.position_at_time(t).translation.vector;
+        let expected = (origin(*t + h) - origin(*t - h)) / (2.0 * h);
+        let (motion_linvel, motion_angvel) = motion.velocity_at_time(*t);
+        assert_relative_eq!(motion_linvel, expected, epsilon = 1.0e-6);
+        assert_eq!(motion_angvel, 0.7);
+    }
+}
+
+#[test]
+fn finite_difference_velocity() {
+    let start = Isometry2::new(Vector2::new(1.0, -2.0), 0.3);
+    let end = Isometry2::new(Vector2::new(2.0, 0.0), -0.5);
+    let motion = InterpolatedRigidMotion::new(start, end);
+
+    let (linvel, angvel) = motion.velocity_at_time(0.4);
+    assert_relative_eq!(linvel, Vector2::new(1.0, 2.0), epsilon = 1.0e-6);
+    assert_relative_eq!(angvel, -0.8, epsilon = 1.0e-6);
+}
@@ -0,0 +1,101 @@
+use na::{Isometry2, Point2, Unit, UnitComplex, Vector2};
+use ncollide2d::bounding_volume::{self, BoundingSphere, BoundingVolumeMeasures, AABB, OBB};
+use ncollide2d::shape::{Capsule, SupportMap};
+use std::f64::consts::PI;
+
+#[test]
+fn capsule2_segment() {
+    let capsule = Capsule::new(1.5f64, 0.5);
+    let segment = capsule.segment();
+
+    assert_eq!(segment.a, Point2::new(0.0, -1.5));
+    assert_eq!(segment.b, Point2::new(0.0, 1.5));
+}
+
+#[test]
+fn capsule2_support_point_perpendicular_to_axis() {
+    let capsule = Capsule::new(1.5f64, 0.5);
+
+    // Any point of the flat side is a valid support point, the result must lie on it.
+    for dir in &[Vector2::x(), -Vector2::x()] {
+        let pt = capsule.local_support_point(dir);
+        assert_relative_eq!(pt.x, dir.x * 0.5);
+        assert!(pt.y.abs() <= 1.5);
+    }
+}
+
+#[test]
+fn capsule2_support_point_along_axis_and_on_caps() {
+    let capsule = Capsule::new(1.5f64, 0.5);
+
+    assert_relative_eq!(
+        capsule.local_support_point(&Vector2::y()),
+        Point2::new(0.0, 2.0)
+    );
+    assert_relative_eq!(
+        capsule.local_support_point(&-Vector2::y()),
+        Point2::new(0.0, -2.0)
+    );
+
+    // Directions pointing toward a cap give a point of the corresponding half-disk.
+    let dir = Unit::new_normalize(Vector2::new(1.0, -1.0));
+    let pt = capsule.local_support_point_toward(&dir);
+    assert_relative_eq!(pt, Point2::new(0.0, -1.5) + *dir * 0.5);
+}
+
+#[test]
+fn capsule2_aabb() {
+    let capsule = Capsule::new(1.5f64, 0.5);
+
+    let aabb = bounding_volume::aabb(&capsule, &Isometry2::identity());
+    assert_relative_eq!(aabb.mins, Point2::new(-0.5, -2.0));
+    assert_relative_eq!(aabb.maxs, Point2::new(0.5, 2.0));
+
+    let m = Isometry2::new(Vector2::new(1.0, 2.0), std::f64::consts::FRAC_PI_2);
+    let aabb = bounding_volume::aabb(&capsule, &m);
+    assert_relative_eq!(aabb.mins, Point2::new(-1.0, 1.5), epsilon = 1.0e-10);
+    assert_relative_eq!(aabb.maxs, Point2::new(3.0, 2.5), epsilon = 1.0e-10);
+}
+
+#[test]
+fn capsule2_mass_properties() {
+    let capsule = Capsule::new(1.0f64, 1.0);
+    let props = capsule.mass_properties(1.0);
+
+    // A 2x2 square and two half-disks with their centers of mass at `4 / (3pi)` from the square.
+    let pi = std::f64::consts::PI;
+    assert_relative_eq!(props.mass, 4.0 + pi, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        props.angular_inertia,
+        16.0 / 3.0 + 1.5 * pi,
+        epsilon = 1.0e-10
+    );
+
+    // Without its rectangular part, the capsule is a disk.
+    let disk = Capsule::new(0.0f64, 0.5).mass_properties(2.0);
+    let mass = 2.0 * pi * 0.25;
+    assert_relative_eq!(disk.mass, mass, epsilon = 1.0e-10);
+    assert_relative_eq!(disk.angular_inertia, mass * 0.25 / 2.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn unit_square_perimeter_and_area() {
+    let aabb = AABB::new(Point2::new(1.0f64, 2.0), Point2::new(2.0, 3.0));
+    assert_relative_eq!(aabb.surface_area(), 4.0);
+    assert_relative_eq!(aabb.volume(), 1.0);
+
+    let obb = OBB::new(
+        Point2::origin(),
+        UnitComplex::new(0.7),
+        Vector2::new(1.0, 0.5),
+    );
+    assert_relative_eq!(obb.surface_area(), 6.0);
+    assert_relative_eq!(obb.volume(), 2.0);
+}
+
+#[test]
+fn unit_disk_perimeter_and_area() {
+    let disk = BoundingSphere::new(Point2::new(1.0f64, 2.0), 1.0);
+    assert_relative_eq!(disk.surface_area(), 2.0 * PI);
+    assert_relative_eq!(disk.volume(), PI);
+}
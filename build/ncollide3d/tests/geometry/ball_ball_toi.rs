@@ -1,6 +1,6 @@
 // Issue #35
 
-use na::{self, Isometry3, Vector3};
+use na::{self, Isometry3, Point3, Vector3};
 use ncollide3d::query;
 use ncollide3d::shape::Ball;
 
@@ -25,3 +25,75 @@ fn test_ball_ball_toi() {
 
     assert_eq!(cast.unwrap().toi, 0.9);
 }
+
+fn check_against_generic_toi(center1: Vector3<f64>, vel1: Vector3<f64>, vel2: Vector3<f64>) {
+    let b1 = Ball::new(0.5f64);
+    let b2 = Ball::new(1.5f64);
+    let center1 = Point3::from(center1);
+    let center2 = Point3::origin();
+
+    let closed_form =
+        query::time_of_impact_ball_ball(&center1, &vel1, &b1, &center2, &vel2, &b2, 100.0, 0.0)
+            .unwrap();
+    let generic = query::time_of_impact_support_map_support_map(
+        &Isometry3::translation(center1.x, center1.y, center1.z),
+        &vel1,
+        &b1,
+        &Isometry3::identity(),
+        &vel2,
+        &b2,
+        100.0,
+        0.0,
+    )
+    .unwrap();
+
+    assert_relative_eq!(closed_form.toi, generic.toi, epsilon = 1.0e-5);
+    assert_relative_eq!(
+        closed_form.normal1.into_inner(),
+        generic.normal1.into_inner(),
+        epsilon = 1.0e-3
+    );
+    assert_relative_eq!(closed_form.witness1, generic.witness1, epsilon = 1.0e-3);
+    assert_relative_eq!(closed_form.witness2, generic.witness2, epsilon = 1.0e-3);
+}
+
+#[test]
+fn ball_ball_toi_head_on() {
+    check_against_generic_toi(
+        Vector3::new(-10.0, 0.0, 0.0),
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(-1.0, 0.0, 0.0),
+    );
+}
+
+#[test]
+fn ball_ball_toi_oblique() {
+    check_against_generic_toi(
+        Vector3::new(-10.0, 1.0, 0.5),
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+    );
+}
+
+#[test]
+fn ball_ball_toi_initially_overlapping() {
+    let b1 = Ball::new(0.5f64);
+    let b2 = Ball::new(1.5f64);
+
+    let toi = query::time_of_impact_ball_ball(
+        &Point3::new(1.0, 0.0, 0.0),
+        &Vector3::new(1.0, 0.0, 0.0),
+        &b1,
+        &Point3::origin(),
+        &na::zero(),
+        &b2,
+        100.0,
+        0.0,
+    )
+    .unwrap();
+
+    assert_eq!(toi.toi, 0.0);
+    assert_eq!(toi.status, query::TOIStatus::Penetrating);
+    assert_relative_eq!(toi.normal1.into_inner(), -Vector3::x());
+    assert_relative_eq!(toi.witness1, Point3::new(-0.5, 0.0, 0.0));
+}
@@ -0,0 +1,275 @@
+use na::{Isometry3, Point3, UnitQuaternion, Vector3};
+use ncollide3d::bounding_volume::{
+    self, BoundingSphere, BoundingVolume, BoundingVolumeMeasures, AABB, OBB,
+};
+use ncollide3d::shape::{Ball, Compound, Cuboid, Shape, ShapeHandle};
+use std::f64::consts::{FRAC_PI_4, PI, SQRT_2};
+
+#[test]
+fn obb_separated_along_edge_edge_axis() {
+    // Two unit cubes touching edge-to-edge, then moved slightly apart along the
+    // edge-edge axis. None of the face normals separates them.
+    let cube = Cuboid::new(Vector3::repeat(1.0f64));
+    let global = Isometry3::new(na::zero(), Vector3::new(0.3, 0.5, 0.7));
+
+    let m1 = global * Isometry3::new(na::zero(), Vector3::x() * FRAC_PI_4);
+    let m2 = global
+        * Isometry3::new(
+            Vector3::z() * (2.0 * SQRT_2 + 0.05),
+            Vector3::y() * FRAC_PI_4,
+        );
+
+    let obb1 = bounding_volume::bounding_obb(&cube, &m1);
+    let obb2 = bounding_volume::bounding_obb(&cube, &m2);
+
+    assert!(bounding_volume::aabb(&cube, &m1).intersects(&bounding_volume::aabb(&cube, &m2)));
+    assert!(!obb1.intersects(&obb2));
+
+    let axis = obb1.separating_axis(&obb2).unwrap().normalize();
+    let expected = global * Vector3::z();
+    assert_relative_eq!(axis.dot(&expected).abs(), 1.0, epsilon = 1.0e-7);
+
+    // Moving them closer makes them overlap.
+    let m2 = global
+        * Isometry3::new(
+            Vector3::z() * (2.0 * SQRT_2 - 0.05),
+            Vector3::y() * FRAC_PI_4,
+        );
+    let obb2 = bounding_volume::bounding_obb(&cube, &m2);
+    assert!(obb1.intersects(&obb2));
+}
+
+#[test]
+fn obb_contains_and_merge() {
+    let rot = UnitQuaternion::from_scaled_axis(Vector3::new(0.1, 0.2, 0.3));
+    let big = OBB::new(
+        Point3::new(1.0f64, 2.0, 3.0),
+        rot,
+        Vector3::new(2.0, 3.0, 4.0),
+    );
+    let small = OBB::new(
+        Point3::new(1.5f64, 2.0, 3.0),
+        rot,
+        Vector3::new(0.5, 0.5, 0.5),
+    );
+    let far = OBB::new(
+        Point3::new(10.0f64, 2.0, 3.0),
+        rot,
+        Vector3::new(0.5, 0.5, 0.5),
+    );
+
+    assert!(big.contains(&small));
+    assert!(!small.contains(&big));
+    assert!(!big.contains(&far));
+
+    // Loosen to account for rounding errors on the shared boundary.
+    let merged = big.merged(&far).loosened(1.0e-7);
+    assert!(merged.contains(&big));
+    assert!(merged.contains(&far));
+}
+
+#[test]
+fn compound_cached_aabbs() {
+    let shapes = vec![
+        (
+            Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3)),
+            ShapeHandle::new(Cuboid::new(Vector3::new(0.5f64, 1.0, 1.5))),
+        ),
+        (
+            Isometry3::translation(-3.0, 0.0, 1.0),
+            ShapeHandle::new(Ball::new(0.75f64)),
+        ),
+    ];
+    let compound = Compound::new(shapes.clone());
+
+    let mut union = AABB::new_invalid();
+
+    for (i, (delta, shape)) in shapes.iter().enumerate() {
+        let fresh = shape.aabb(delta).loosened(0.04);
+        assert_eq!(*compound.aabb_at(i), fresh);
+        union.merge(&fresh);
+    }
+
+    assert_eq!(compound.local_aabb(), union);
+    assert_eq!(compound.local_aabb(), *compound.aabb());
+}
+
+#[test]
+fn aabb_split_at_midpoint() {
+    let aabb = AABB::new(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+
+    for axis in 0..3 {
+        let (lower, upper) = aabb.split_at(axis, 0.5);
+
+        assert_eq!(lower.maxs[axis], 0.5);
+        assert_eq!(upper.mins[axis], 0.5);
+        assert_eq!(lower.extents()[axis], 0.5);
+        assert_eq!(upper.extents()[axis], 0.5);
+        assert!(aabb.contains(&lower));
+        assert!(aabb.contains(&upper));
+        assert_eq!(lower.merged(&upper), aabb);
+    }
+}
+
+#[test]
+fn aabb_split_outside_of_the_extent() {
+    let aabb = AABB::new(Point3::new(-1.0, 2.0, 0.0), Point3::new(1.0, 3.0, 4.0));
+
+    let (lower, upper) = aabb.split_at(1, 10.0);
+    assert_eq!(lower, aabb);
+    assert_eq!(upper.mins[1], 3.0);
+    assert_eq!(upper.extents()[1], 0.0);
+
+    let (lower, upper) = aabb.split_at(2, -10.0);
+    assert_eq!(lower.maxs[2], 0.0);
+    assert_eq!(lower.extents()[2], 0.0);
+    assert_eq!(upper, aabb);
+}
+
+fn aabb() -> AABB<f64> {
+    AABB::new(Point3::new(-1.0, 0.0, 2.0), Point3::new(3.0, 1.0, 5.0))
+}
+
+#[test]
+fn aabb_closest_point_inside() {
+    let pt = Point3::new(0.5, 0.2, 4.9);
+    assert_eq!(aabb().closest_local_point(&pt), pt);
+    assert_eq!(aabb().distance_to_local_point(&pt), 0.0);
+}
+
+#[test]
+fn aabb_closest_point_off_face() {
+    let pt = Point3::new(0.5, 3.5, 4.0);
+    assert_eq!(aabb().closest_local_point(&pt), Point3::new(0.5, 1.0, 4.0));
+    assert_relative_eq!(aabb().distance_to_local_point(&pt), 2.5);
+}
+
+#[test]
+fn aabb_closest_point_off_corner() {
+    let pt = Point3::new(-2.0, -2.0, 7.0);
+    assert_eq!(aabb().closest_local_point(&pt), Point3::new(-1.0, 0.0, 5.0));
+    assert_relative_eq!(aabb().distance_to_local_point(&pt), 3.0);
+}
+
+#[test]
+fn bounding_sphere_of_two_offset_balls() {
+    let ball1 = Ball::new(1.0f64);
+    let ball2 = Ball::new(2.0f64);
+    let shapes: [(&dyn Shape<f64>, _); 2] = [
+        (&ball1, Isometry3::translation(-3.0, 0.0, 0.0)),
+        (&ball2, Isometry3::translation(4.0, 0.0, 0.0)),
+    ];
+
+    // The enclosing sphere spans x in [-4, 6].
+    let bs = bounding_volume::bounding_sphere_of_shapes(&shapes);
+    assert_relative_eq!(*bs.center(), Point3::new(1.0, 0.0, 0.0), epsilon = 1.0e-10);
+    assert_relative_eq!(bs.radius(), 5.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn bounding_sphere_of_one_or_no_shape() {
+    let ball = Ball::new(1.5f64);
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::y());
+    let shapes: [(&dyn Shape<f64>, _); 1] = [(&ball, m)];
+
+    let bs = bounding_volume::bounding_sphere_of_shapes(&shapes);
+    assert_eq!(bs, ball.bounding_sphere(&m));
+
+    let bs = bounding_volume::bounding_sphere_of_shapes::<f64>(&[]);
+    assert_eq!(bs, BoundingSphere::new(Point3::origin(), 0.0));
+}
+
+#[test]
+fn aabb_intersection_overlapping() {
+    let a = AABB::new(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 2.0, 2.0));
+    let b = AABB::new(Point3::new(1.0, -1.0, 0.5), Point3::new(3.0, 1.5, 1.0));
+
+    let expected = AABB::new(Point3::new(1.0, 0.0, 0.5), Point3::new(2.0, 1.5, 1.0));
+    assert_eq!(a.intersection(&b), Some(expected));
+    assert_eq!(b.intersection(&a), Some(expected));
+    assert_relative_eq!(a.intersection_volume(&b), 0.75);
+
+    // Contained AABB.
+    let c = AABB::new(Point3::new(0.5, 0.5, 0.5), Point3::new(1.0, 1.5, 2.0));
+    assert_eq!(a.intersection(&c), Some(c));
+    assert_relative_eq!(a.intersection_volume(&c), 0.75);
+}
+
+#[test]
+fn aabb_intersection_touching() {
+    let a = AABB::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    let b = AABB::new(Point3::new(1.0, 0.5, 0.0), Point3::new(2.0, 2.0, 1.0));
+
+    let expected = AABB::new(Point3::new(1.0, 0.5, 0.0), Point3::new(1.0, 1.0, 1.0));
+    assert_eq!(a.intersection(&b), Some(expected));
+    assert_eq!(a.intersection_volume(&b), 0.0);
+}
+
+#[test]
+fn aabb_intersection_disjoint() {
+    let a = AABB::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    let b = AABB::new(Point3::new(0.5, 0.5, 1.1), Point3::new(2.0, 2.0, 2.0));
+
+    assert_eq!(a.intersection(&b), None);
+    assert_eq!(a.intersection_volume(&b), 0.0);
+}
+
+#[test]
+fn unit_box_surface_area_and_volume() {
+    let aabb = AABB::new(Point3::new(1.0f64, 2.0, 3.0), Point3::new(2.0, 3.0, 4.0));
+    assert_relative_eq!(aabb.surface_area(), 6.0);
+    assert_relative_eq!(aabb.volume(), 1.0);
+
+    let aabb = AABB::new(Point3::origin(), Point3::new(1.0f64, 2.0, 3.0));
+    assert_relative_eq!(aabb.surface_area(), 22.0);
+    assert_relative_eq!(aabb.volume(), 6.0);
+}
+
+#[test]
+fn unit_obb_surface_area_and_volume() {
+    let rot = UnitQuaternion::from_scaled_axis(Vector3::new(0.3, -0.2, 1.1));
+    let obb = OBB::new(Point3::new(1.0f64, 2.0, 3.0), rot, Vector3::repeat(0.5));
+    assert_relative_eq!(obb.surface_area(), 6.0, epsilon = 1.0e-10);
+    assert_relative_eq!(obb.volume(), 1.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn unit_sphere_surface_area_and_volume() {
+    let sphere = BoundingSphere::new(Point3::new(1.0f64, 2.0, 3.0), 1.0);
+    assert_relative_eq!(sphere.surface_area(), 4.0 * PI);
+    assert_relative_eq!(sphere.volume(), 4.0 / 3.0 * PI);
+}
+
+#[test]
+fn aabb_try_from_points_matches_min_max() {
+    let points = (0..50).map(|i| {
+        let t = i as f64;
+        Point3::new((t * 1.3).sin() * t, (t * 0.7).cos() * 3.0, t * 0.1 - 2.0)
+    });
+
+    let aabb = AABB::try_from_points(points.clone()).unwrap();
+
+    let mut mins = Point3::new(f64::MAX, f64::MAX, f64::MAX);
+    let mut maxs = Point3::new(-f64::MAX, -f64::MAX, -f64::MAX);
+
+    for pt in points {
+        for i in 0..3 {
+            mins[i] = mins[i].min(pt[i]);
+            maxs[i] = maxs[i].max(pt[i]);
+        }
+    }
+
+    assert_eq!(aabb.mins, mins);
+    assert_eq!(aabb.maxs, maxs);
+}
+
+#[test]
+fn aabb_try_from_points_empty() {
+    let aabb = AABB::<f64>::try_from_points(std::iter::empty());
+    assert!(aabb.is_none());
+
+    let pt = Point3::new(1.0, 2.0, 3.0);
+    let aabb = AABB::try_from_points(std::iter::once(pt)).unwrap();
+    assert_eq!(aabb.mins, pt);
+    assert_eq!(aabb.maxs, pt);
+}
@@ -1,7 +1,12 @@
-use na::{zero, Isometry3, Point3, Vector3};
+use na::{self, zero, Isometry3, Point3, Quaternion, Unit, UnitQuaternion, Vector3};
 use ncollide3d::bounding_volume::*;
-use ncollide3d::pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType};
+use ncollide3d::pipeline::{
+    CollisionGroups, CollisionWorld, DefaultContactDispatcher, GeometricQueryType,
+};
+use ncollide3d::query::{self, Contact, ContactManifold, ContactPrediction, IdAllocator};
 use ncollide3d::shape::*;
+use ncollide3d::transformation::ToTriMesh;
+use std::collections::HashSet;
 
 // Issue #182.
 #[test]
@@ -24,3 +29,384 @@ fn just_touching_nan3d() {
 
     world.update();
 }
+
+#[test]
+fn penetration_mtv_overlapping_cuboids() {
+    let cuboid1 = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let cuboid2 = Cuboid::new(Vector3::new(2.0f64, 1.0, 3.0));
+    let m1 = Isometry3::identity();
+    // Overlaps of 0.1, 1.5 and 3.0 along x, y, and z.
+    let m2 = Isometry3::translation(2.9, 0.5, 1.0);
+
+    let mtv = query::penetration_mtv(&m1, &cuboid1, &m2, &cuboid2).unwrap();
+    assert_relative_eq!(mtv, Vector3::new(0.1, 0.0, 0.0), epsilon = 1.0e-6);
+
+    // The MTV actually separates the shapes.
+    let m2 = Isometry3::new(m2.translation.vector + mtv * 1.001, na::zero());
+    assert!(query::penetration_mtv(&m1, &cuboid1, &m2, &cuboid2).is_none());
+}
+
+#[test]
+fn penetration_mtv_shallow_overlap() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(0.3, 1.99999, -0.2);
+
+    let mtv = query::penetration_mtv(&m1, &cuboid, &m2, &cuboid).unwrap();
+    assert_relative_eq!(mtv, Vector3::new(0.0, 1.0e-5, 0.0), epsilon = 1.0e-7);
+}
+
+#[test]
+fn penetration_mtv_flat_epa_polytope() {
+    // A penetration of about 1.0e-7, too shallow for the EPA polytope to be anything but flat.
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 0.5, 2.0));
+    let m1 = Isometry3::identity();
+    let rot = UnitQuaternion::new_unchecked(Quaternion::new(
+        0.1560265,
+        0.6984789,
+        0.0084979525,
+        0.69836277,
+    ));
+    let m2 = Isometry3::from_parts(Vector3::new(-0.73138267, 1.5969994, -0.7212284).into(), rot);
+
+    let mtv = query::penetration_mtv(&m1, &cuboid, &m2, &cuboid).unwrap();
+    assert!(mtv.norm() < 1.0e-6);
+    assert!(mtv.dot(&m2.translation.vector) > 0.0);
+}
+
+#[test]
+fn penetration_mtv_disjoint() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(2.5, 0.0, 0.0);
+
+    assert!(query::penetration_mtv(&m1, &cuboid, &m2, &cuboid).is_none());
+}
+
+#[test]
+fn contact_approx_eq() {
+    let eps = 1.0e-6;
+    let contact = Contact::new(
+        Point3::new(1.0, 2.0, 3.0),
+        Point3::new(1.0, 1.9, 3.0),
+        Vector3::y_axis(),
+        0.1f64,
+    );
+
+    let perturbed = Contact::new(
+        contact.world1 + Vector3::new(1.0e-7, -2.0e-7, 0.0),
+        contact.world2 + Vector3::new(0.0, 3.0e-7, 1.0e-7),
+        Unit::new_normalize(Vector3::new(1.0e-7, 1.0, -1.0e-7)),
+        contact.depth + 5.0e-7,
+    );
+
+    let mut flipped = contact;
+    flipped.normal = -contact.normal;
+
+    let mut far = contact;
+    far.world2 += Vector3::new(0.0, 1.0e-3, 0.0);
+
+    assert!(contact.approx_eq(&contact, eps));
+    assert!(contact.approx_eq(&perturbed, eps));
+    assert!(perturbed.approx_eq(&contact, eps));
+    assert!(!contact.approx_eq(&flipped, eps));
+    assert!(!contact.approx_eq(&far, eps));
+}
+
+fn box_mesh() -> TriMesh<f64> {
+    Cuboid::new(Vector3::new(1.0, 1.0, 1.0))
+        .to_trimesh(())
+        .into()
+}
+
+#[test]
+fn deepest_contact_between_interpenetrating_box_meshes() {
+    let mesh1 = box_mesh();
+    let mesh2 = box_mesh();
+    let m1 = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), na::zero());
+    let m2 = Isometry3::new(Vector3::new(2.7, -1.7, 0.3), na::zero());
+
+    let contact = query::deepest_contact_composite_composite(&m1, &mesh1, &m2, &mesh2).unwrap();
+
+    // The boxes overlap by 0.3 along `x`, which is the smallest overlap.
+    assert_relative_eq!(contact.depth, 0.3, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        contact.depth,
+        -contact.normal.dot(&(contact.world2 - contact.world1)),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn deepest_contact_between_disjoint_meshes() {
+    let mesh1 = box_mesh();
+    let mesh2 = box_mesh();
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(4.0, 0.3, -0.2), Vector3::new(0.1, 0.2, 0.3));
+
+    assert!(query::deepest_contact_composite_composite(&m1, &mesh1, &m2, &mesh2).is_none());
+}
+
+fn check_basis(normal: Vector3<f64>) {
+    let normal = Unit::new_normalize(normal);
+    let contact = Contact::new(Point3::origin(), Point3::origin(), normal, 0.0);
+    let [t1, t2] = contact.tangent_basis();
+
+    assert_relative_eq!(t1.norm(), 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(t2.norm(), 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(t1.dot(&t2), 0.0, epsilon = 1.0e-10);
+    assert_relative_eq!(t1.dot(&normal), 0.0, epsilon = 1.0e-10);
+    assert_relative_eq!(t2.dot(&normal), 0.0, epsilon = 1.0e-10);
+    assert_relative_eq!(t1.cross(&t2), *normal, epsilon = 1.0e-10);
+}
+
+#[test]
+fn contact_tangent_basis_axis_aligned_normals() {
+    for i in 0..3 {
+        let mut normal = Vector3::zeros();
+        normal[i] = 1.0;
+        check_basis(normal);
+        check_basis(-normal);
+    }
+}
+
+#[test]
+fn contact_tangent_basis_near_x_axis() {
+    check_basis(Vector3::new(1.0, 1.0e-9, -1.0e-9));
+    check_basis(Vector3::new(-1.0, 0.0, 1.0e-12));
+}
+
+#[test]
+fn contact_tangent_basis_spread_of_normals() {
+    for i in 0..20 {
+        for j in 0..20 {
+            let theta = i as f64 * std::f64::consts::PI / 19.0;
+            let phi = j as f64 * 2.0 * std::f64::consts::PI / 20.0;
+            check_basis(Vector3::new(
+                theta.sin() * phi.cos(),
+                theta.cos(),
+                theta.sin() * phi.sin(),
+            ));
+        }
+    }
+}
+
+#[test]
+fn contact_interval_ball_through_ball() {
+    let b1 = Ball::new(1.0f64);
+    let b2 = Ball::new(0.5f64);
+    let m1 = Isometry3::translation(0.5, -0.2, 0.0);
+    let m2 = Isometry3::translation(-5.0, 0.5, 0.3);
+    let vel1 = Vector3::new(0.5, 0.0, 0.0);
+    let vel2 = Vector3::new(2.5, 0.0, 0.0);
+
+    // Roots of |dp + dv * t|² = (r1 + r2)².
+    let dp = m2.translation.vector - m1.translation.vector;
+    let dv = vel2 - vel1;
+    let a = dv.norm_squared();
+    let b: f64 = 2.0 * dp.dot(&dv);
+    let c = dp.norm_squared() - (b1.radius + b2.radius).powi(2);
+    let sqrt_delta = (b * b - 4.0 * a * c).sqrt();
+    let enter = (-b - sqrt_delta) / (2.0 * a);
+    let exit = (-b + sqrt_delta) / (2.0 * a);
+
+    let (t_enter, t_exit) =
+        query::contact_interval_linear(&m1, &vel1, &b1, &m2, &vel2, &b2, 10.0).unwrap();
+    assert_relative_eq!(t_enter, enter, epsilon = 1.0e-5);
+    assert_relative_eq!(t_exit, exit, epsilon = 1.0e-5);
+
+    // The interval is clamped to `max_toi`.
+    let (t_enter, t_exit) =
+        query::contact_interval_linear(&m1, &vel1, &b1, &m2, &vel2, &b2, enter + 0.1).unwrap();
+    assert_relative_eq!(t_enter, enter, epsilon = 1.0e-5);
+    assert_relative_eq!(t_exit, enter + 0.1, epsilon = 1.0e-5);
+
+    assert!(
+        query::contact_interval_linear(&m1, &vel1, &b1, &m2, &vel2, &b2, enter - 0.1).is_none()
+    );
+}
+
+#[test]
+fn contact_interval_starting_in_contact() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let ball = Ball::new(0.5f64);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(0.0, 1.2, 0.0);
+    let vel2 = Vector3::new(0.0, 1.0, 0.0);
+
+    let (t_enter, t_exit) =
+        query::contact_interval_linear(&m1, &Vector3::zeros(), &cuboid, &m2, &vel2, &ball, 5.0)
+            .unwrap();
+    assert_eq!(t_enter, 0.0);
+    assert_relative_eq!(t_exit, 0.3, epsilon = 1.0e-5);
+}
+
+#[test]
+fn contact_update_depth_after_separation() {
+    let m1 = Isometry3::new(Vector3::new(0.0, 1.0, 0.0), Vector3::z() * 0.3);
+    let m2 = Isometry3::new(Vector3::new(0.0, 2.7, 0.2), Vector3::x() * 0.5);
+    let local1 = Point3::new(0.1, 1.0, 0.0);
+    let local2 = Point3::new(0.0, -0.5, 0.3);
+
+    let normal = Vector3::y_axis();
+    let mut contact = Contact::new_wo_depth(m1 * local1, m2 * local2, normal);
+    let initial_depth = contact.depth;
+
+    // Move the second shape away from the first along the normal, and sideways.
+    let shift = Vector3::new(0.4, 0.25, -0.1);
+    let new_m2 = Isometry3::from_parts((m2.translation.vector + shift).into(), m2.rotation);
+    contact.update_depth(&m1, &new_m2, &local1, &local2);
+
+    assert_relative_eq!(contact.depth, initial_depth - 0.25, epsilon = 1.0e-10);
+    assert_relative_eq!(contact.world1, m1 * local1, epsilon = 1.0e-10);
+    assert_relative_eq!(contact.world2, new_m2 * local2, epsilon = 1.0e-10);
+    assert_eq!(contact.normal, normal);
+}
+
+#[test]
+fn box_resting_on_two_steps() {
+    // Two steps with the same top at `y = 0`, separated by a gap.
+    let step = Cuboid::new(Vector3::new(1.0, 0.5, 1.0));
+    let parts = vec![
+        (
+            Isometry3::translation(-1.5, -0.5, 0.0),
+            ShapeHandle::new(step),
+        ),
+        (
+            Isometry3::translation(1.5, -0.5, 0.0),
+            ShapeHandle::new(step),
+        ),
+    ];
+    let compound = Compound::new(parts);
+
+    // A box bridging the gap, slightly penetrating both steps.
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 0.5));
+    let m1 = Isometry3::translation(0.0, 0.49, 0.0);
+    let m2 = Isometry3::identity();
+    let prediction = ContactPrediction::new(0.01, 0.0, 0.0);
+    let dispatcher = DefaultContactDispatcher::new();
+    let mut id_alloc = IdAllocator::with_key();
+    let mut manifold = ContactManifold::new();
+
+    query::contacts_shape_compound(
+        &dispatcher,
+        &m1,
+        &cuboid,
+        &m2,
+        &compound,
+        &prediction,
+        &mut id_alloc,
+        &mut manifold,
+    );
+
+    let contacts: Vec<_> = manifold.contacts().collect();
+    assert!(contacts.iter().any(|c| c.contact.world2.x < -0.5));
+    assert!(contacts.iter().any(|c| c.contact.world2.x > 0.5));
+
+    for c in &contacts {
+        assert_relative_eq!(c.contact.depth, 0.01, epsilon = 1.0e-7);
+        assert_relative_eq!(*c.contact.normal, -Vector3::y(), epsilon = 1.0e-7);
+    }
+
+    // The identifiers are all distinct, even among contacts from different steps.
+    let ids: HashSet<_> = contacts.iter().map(|c| c.id).collect();
+    assert_eq!(ids.len(), contacts.len());
+    assert!(ids.iter().all(|id| id_alloc.contains_key(*id)));
+    assert_eq!(id_alloc.len(), contacts.len());
+
+    // The contacts keep their identifiers from one call to the next.
+    let mut prev_ids: Vec<_> = ids.into_iter().collect();
+    query::contacts_shape_compound(
+        &dispatcher,
+        &m1,
+        &cuboid,
+        &m2,
+        &compound,
+        &prediction,
+        &mut id_alloc,
+        &mut manifold,
+    );
+    let mut curr_ids: Vec<_> = manifold.contacts().map(|c| c.id).collect();
+    prev_ids.sort();
+    curr_ids.sort();
+    assert_eq!(prev_ids, curr_ids);
+    assert_eq!(id_alloc.len(), curr_ids.len());
+
+    // The identifiers of the lost contacts are released.
+    let m1 = Isometry3::translation(0.0, 2.0, 0.0);
+    query::contacts_shape_compound(
+        &dispatcher,
+        &m1,
+        &cuboid,
+        &m2,
+        &compound,
+        &prediction,
+        &mut id_alloc,
+        &mut manifold,
+    );
+    assert_eq!(manifold.len(), 0);
+    assert!(id_alloc.is_empty());
+}
+
+fn head_on_contact() -> Contact<f64> {
+    // Body 1 on the left, body 2 on the right, touching at `x = 1`.
+    Contact::new(
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Vector3::x_axis(),
+        0.0,
+    )
+}
+
+#[test]
+fn contact_relative_normal_velocity_head_on() {
+    let contact = head_on_contact();
+    let zero = Vector3::zeros();
+
+    let approaching = contact.relative_normal_velocity(
+        &Vector3::new(2.0, 0.0, 0.0),
+        &zero,
+        &Vector3::new(-1.0, 0.0, 0.0),
+        &zero,
+    );
+    assert_relative_eq!(approaching, -3.0);
+
+    let separating = contact.relative_normal_velocity(
+        &Vector3::new(-2.0, 0.0, 0.0),
+        &zero,
+        &Vector3::new(1.0, 0.0, 0.0),
+        &zero,
+    );
+    assert_relative_eq!(separating, 3.0);
+
+    // Tangential velocities do not contribute.
+    let sliding = contact.relative_normal_velocity(
+        &Vector3::new(0.0, 5.0, 0.0),
+        &zero,
+        &Vector3::new(0.0, 0.0, -4.0),
+        &zero,
+    );
+    assert_relative_eq!(sliding, 0.0);
+}
+
+#[test]
+fn contact_relative_normal_velocity_with_rotation() {
+    let contact = Contact::new(
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Vector3::x_axis(),
+        0.0,
+    );
+    let zero = Vector3::zeros();
+
+    // Rotating about `-z` moves the point at `y = 2` toward `+x`, i.e., toward the second body.
+    let w1 = Vector3::new(0.0, 0.0, -1.5);
+    assert_relative_eq!(
+        contact.relative_normal_velocity(&zero, &w1, &zero, &zero),
+        -3.0
+    );
+    assert_relative_eq!(
+        contact.relative_normal_velocity(&zero, &zero, &zero, &w1),
+        3.0
+    );
+}
@@ -0,0 +1,407 @@
+use na::{Point3, Unit, Vector3};
+use ncollide3d::query::{
+    Contact, ContactCache, ContactId, ContactKinematic, ContactManifold, ContactTrackingMode,
+    FlatContact, IdAllocator, NeighborhoodGeometry,
+};
+use ncollide3d::shape::FeatureId;
+use slotmap::Key;
+use std::collections::{HashMap, HashSet};
+
+fn manifold_with_depths(depths: &[f64], id_alloc: &mut IdAllocator) -> ContactManifold<f64> {
+    let mut manifold = ContactManifold::new();
+
+    for (i, depth) in depths.iter().enumerate() {
+        let pt = Point3::new(i as f64, 0.0, 0.0);
+        let contact = Contact::new(pt, pt, Unit::new_unchecked(Vector3::y()), *depth);
+        let _ = manifold.push(contact, ContactKinematic::new(), pt, None, None);
+    }
+
+    for contact in manifold.contacts_mut() {
+        contact.id = id_alloc.insert(false);
+    }
+
+    manifold
+}
+
+#[test]
+fn contact_manifold_merge_reallocates_colliding_ids() {
+    let mut id_alloc = IdAllocator::with_key();
+    let mut manifold1 = manifold_with_depths(&[0.1, 0.2], &mut id_alloc);
+    let mut manifold2 = manifold_with_depths(&[0.3, 0.05, 0.15], &mut id_alloc);
+
+    // Force an id collision between both manifolds.
+    let shared_id = manifold1.contacts().next().unwrap().id;
+    manifold2.contacts_mut().next().unwrap().id = shared_id;
+
+    manifold1.merge_from(&manifold2, &mut id_alloc);
+
+    assert_eq!(manifold1.len(), 5);
+    assert_eq!(manifold1.contacts().count(), 5);
+
+    let ids: HashSet<_> = manifold1.contacts().map(|c| c.id).collect();
+    assert_eq!(ids.len(), 5);
+
+    let mut depths: Vec<_> = manifold1.contacts().map(|c| c.contact.depth).collect();
+    depths.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(depths, vec![0.05, 0.1, 0.15, 0.2, 0.3]);
+    assert_eq!(manifold1.deepest_contact().unwrap().contact.depth, 0.3);
+}
+
+fn build_sorted_manifold(
+    features: &[(FeatureId, FeatureId, ContactId)],
+    order: &[usize],
+) -> ContactManifold<f64> {
+    let mut manifold = ContactManifold::new();
+    manifold.set_tracking_mode(ContactTrackingMode::FeatureBased);
+
+    for i in order {
+        let (f1, f2, _) = features[*i];
+        let pt = Point3::new(*i as f64, 0.0, 0.0);
+        let contact = Contact::new(pt, pt, Unit::new_unchecked(Vector3::y()), 0.1);
+        let mut kinematic = ContactKinematic::new();
+        kinematic.set_approx1(f1, pt, NeighborhoodGeometry::Point);
+        kinematic.set_approx2(f2, pt, NeighborhoodGeometry::Point);
+        let _ = manifold.push(contact, kinematic, pt, None, None);
+    }
+
+    // Assign the ids in insertion order, so both manifolds differ before sorting.
+    let mut ids = order.iter().map(|i| features[*i].2);
+    for contact in manifold.contacts_mut() {
+        contact.id = ids.next().unwrap();
+    }
+
+    manifold.sort_contacts_by_feature();
+    manifold
+}
+
+fn contents(
+    manifold: &ContactManifold<f64>,
+) -> Vec<(FeatureId, FeatureId, ContactId, Point3<f64>)> {
+    manifold
+        .contacts()
+        .map(|c| {
+            (
+                c.kinematic.feature1(),
+                c.kinematic.feature2(),
+                c.id,
+                c.contact.world1,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn contact_manifold_sort_by_feature() {
+    let mut id_alloc = IdAllocator::with_key();
+    let features = [
+        (
+            FeatureId::Face(2),
+            FeatureId::Vertex(1),
+            id_alloc.insert(false),
+        ),
+        (
+            FeatureId::Vertex(3),
+            FeatureId::Face(0),
+            id_alloc.insert(false),
+        ),
+        (
+            FeatureId::Edge(1),
+            FeatureId::Edge(4),
+            id_alloc.insert(false),
+        ),
+        (
+            FeatureId::Vertex(3),
+            FeatureId::Edge(0),
+            id_alloc.insert(false),
+        ),
+        (
+            FeatureId::Face(0),
+            FeatureId::Face(5),
+            id_alloc.insert(false),
+        ),
+    ];
+
+    let manifold1 = build_sorted_manifold(&features, &[0, 1, 2, 3, 4]);
+    let manifold2 = build_sorted_manifold(&features, &[3, 1, 4, 0, 2]);
+
+    let contents1 = contents(&manifold1);
+    assert_eq!(contents1, contents(&manifold2));
+
+    let sorted_features: Vec<_> = contents1.iter().map(|c| (c.0, c.1)).collect();
+    assert_eq!(
+        sorted_features,
+        vec![
+            (FeatureId::Vertex(3), FeatureId::Edge(0)),
+            (FeatureId::Vertex(3), FeatureId::Face(0)),
+            (FeatureId::Edge(1), FeatureId::Edge(4)),
+            (FeatureId::Face(0), FeatureId::Face(5)),
+            (FeatureId::Face(2), FeatureId::Vertex(1)),
+        ]
+    );
+    assert_eq!(manifold1.deepest_contact().unwrap().contact.depth, 0.1);
+}
+
+// Simulates one frame of the narrow phase: the manifold is refilled with contacts on the
+// given features, and new contacts are given fresh identifiers.
+fn step(manifold: &mut ContactManifold<f64>, features: &[usize], id_alloc: &mut IdAllocator) {
+    manifold.save_cache_and_clear();
+
+    for i in features {
+        let pt = Point3::new(*i as f64, 0.0, 0.0);
+        let contact = Contact::new(pt, pt, Vector3::y_axis(), 0.1);
+        let mut kinematic = ContactKinematic::new();
+        kinematic.set_approx1(FeatureId::Face(*i), pt, NeighborhoodGeometry::Point);
+        kinematic.set_approx2(FeatureId::Vertex(*i), pt, NeighborhoodGeometry::Point);
+        let _ = manifold.push(contact, kinematic, pt, None, None);
+    }
+
+    for contact in manifold.contacts_mut() {
+        if contact.id.is_null() {
+            contact.id = id_alloc.insert(false);
+        }
+    }
+}
+
+fn id_of(manifold: &ContactManifold<f64>, feature: usize) -> ContactId {
+    manifold
+        .contacts()
+        .find(|c| c.kinematic.feature1() == FeatureId::Face(feature))
+        .unwrap()
+        .id
+}
+
+#[test]
+fn contact_manifold_diff_across_frames() {
+    let mut id_alloc = IdAllocator::with_key();
+    let mut manifold = ContactManifold::new();
+    manifold.set_tracking_mode(ContactTrackingMode::FeatureBased);
+
+    // Frame 1: contact 0 appears.
+    let prev = manifold.clone();
+    step(&mut manifold, &[0], &mut id_alloc);
+    let id0 = id_of(&manifold, 0);
+    let diff = manifold.diff(&prev);
+    assert_eq!(diff.added, vec![id0]);
+    assert!(diff.persisted.is_empty());
+    assert!(diff.removed.is_empty());
+
+    // Frame 2: contact 0 persists with the same id, contact 1 appears.
+    let prev = manifold.clone();
+    step(&mut manifold, &[0, 1], &mut id_alloc);
+    let id1 = id_of(&manifold, 1);
+    assert_eq!(id_of(&manifold, 0), id0);
+    let diff = manifold.diff(&prev);
+    assert_eq!(diff.added, vec![id1]);
+    assert_eq!(diff.persisted, vec![id0]);
+    assert!(diff.removed.is_empty());
+
+    // Frame 3: contact 0 vanishes.
+    let prev = manifold.clone();
+    step(&mut manifold, &[1], &mut id_alloc);
+    let diff = manifold.diff(&prev);
+    assert!(diff.added.is_empty());
+    assert_eq!(diff.persisted, vec![id1]);
+    assert_eq!(diff.removed, vec![id0]);
+
+    // Frame 4: nothing changes.
+    let prev = manifold.clone();
+    step(&mut manifold, &[1], &mut id_alloc);
+    assert!(manifold.diff(&prev).is_unchanged());
+}
+
+// A 5x4 grid of contacts on the plane `y = 0`, the deepest one being inside of the grid.
+fn grid_manifold(id_alloc: &mut IdAllocator) -> ContactManifold<f64> {
+    let mut manifold = ContactManifold::new();
+
+    for i in 0..5 {
+        for j in 0..4 {
+            let pt = Point3::new(i as f64, 0.0, j as f64);
+            let depth = if (i, j) == (2, 1) {
+                1.0
+            } else {
+                0.01 * (i + j) as f64
+            };
+            let contact = Contact::new(pt, pt, Unit::new_unchecked(Vector3::y()), depth);
+            let mut kinematic = ContactKinematic::new();
+            kinematic.set_approx1(
+                FeatureId::Vertex(i * 4 + j),
+                pt,
+                NeighborhoodGeometry::Point,
+            );
+            kinematic.set_approx2(FeatureId::Face(0), pt, NeighborhoodGeometry::Point);
+            let _ = manifold.push(contact, kinematic, pt, None, None);
+        }
+    }
+
+    for contact in manifold.contacts_mut() {
+        contact.id = id_alloc.insert(false);
+    }
+
+    manifold
+}
+
+#[test]
+fn limit_contacts_keeps_deepest_and_spans_patch() {
+    let mut id_alloc = IdAllocator::with_key();
+    let mut manifold = grid_manifold(&mut id_alloc);
+    assert_eq!(manifold.len(), 20);
+    let full_area = manifold.contact_patch_area();
+    let ids: HashMap<ContactId, Point3<f64>> = manifold
+        .contacts()
+        .map(|c| (c.id, c.contact.world1))
+        .collect();
+
+    manifold.set_max_contacts(Some(4));
+    manifold.limit_contacts();
+
+    assert_eq!(manifold.len(), 4);
+    assert_eq!(manifold.contacts().count(), 4);
+
+    let deepest = manifold.deepest_contact().unwrap();
+    assert_eq!(deepest.contact.world1, Point3::new(2.0, 0.0, 1.0));
+    assert!(manifold.contacts().any(|c| c.id == deepest.id));
+
+    // The kept contacts retain their identifiers.
+    for c in manifold.contacts() {
+        assert_eq!(ids[&c.id], c.contact.world1);
+    }
+
+    // The kept contacts span a significant part of the patch.
+    assert!(manifold.contact_patch_area() >= full_area * 0.5);
+}
+
+#[test]
+fn limit_contacts_without_limit_or_under_it() {
+    let mut id_alloc = IdAllocator::with_key();
+    let mut manifold = grid_manifold(&mut id_alloc);
+
+    manifold.limit_contacts();
+    assert_eq!(manifold.len(), 20);
+
+    manifold.set_max_contacts(Some(20));
+    manifold.limit_contacts();
+    assert_eq!(manifold.len(), 20);
+    assert_eq!(manifold.contacts().count(), 20);
+}
+
+fn build_manifold(features: &[(FeatureId, FeatureId)]) -> ContactManifold<f64> {
+    let mut manifold = ContactManifold::new();
+    manifold.set_tracking_mode(ContactTrackingMode::FeatureBased);
+
+    for (i, (f1, f2)) in features.iter().enumerate() {
+        let pt = Point3::new(i as f64, 0.0, 0.0);
+        let contact = Contact::new(pt, pt, Unit::new_unchecked(Vector3::y()), 0.1);
+        let mut kinematic = ContactKinematic::new();
+        kinematic.set_approx1(*f1, pt, NeighborhoodGeometry::Point);
+        kinematic.set_approx2(*f2, pt, NeighborhoodGeometry::Point);
+        let _ = manifold.push(contact, kinematic, pt, None, None);
+    }
+
+    manifold
+}
+
+#[test]
+fn contact_cache_evicts_missing_pairs() {
+    let pair1 = (FeatureId::Face(0), FeatureId::Vertex(3));
+    let pair2 = (FeatureId::Edge(1), FeatureId::Edge(2));
+    let pair3 = (FeatureId::Vertex(5), FeatureId::Face(0));
+
+    let mut cache = ContactCache::new();
+    assert_eq!(cache.insert(pair1.0, pair1.1, 1.0), None);
+    assert_eq!(cache.insert(pair2.0, pair2.1, 2.0), None);
+    assert_eq!(cache.insert(pair3.0, pair3.1, 3.0), None);
+    assert_eq!(cache.insert(pair3.0, pair3.1, 4.0), Some(3.0));
+    assert_eq!(cache.len(), 3);
+
+    // The feature pairs are ordered.
+    assert_eq!(cache.get(pair1.1, pair1.0), None);
+
+    let manifold = build_manifold(&[pair1, pair3]);
+    cache.retain_matching(&manifold);
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(pair1.0, pair1.1), Some(&1.0));
+    assert_eq!(cache.get(pair2.0, pair2.1), None);
+    assert_eq!(cache.get(pair3.0, pair3.1), Some(&4.0));
+}
+
+#[test]
+fn deepest_two_breaks_ties_by_id() {
+    let mut id_alloc = IdAllocator::with_key();
+    let ids: Vec<_> = (0..4).map(|_| id_alloc.insert(true)).collect();
+    let depths = [0.1, 0.3, 0.2, 0.3];
+    // Push the contacts so that the one with the largest id is added first.
+    let order = [3, 2, 1, 0];
+
+    let mut manifold = ContactManifold::new();
+
+    for i in order.iter() {
+        let pt = Point3::new(*i as f64, 0.0, 0.0);
+        let contact = Contact::new(pt, pt, Unit::new_unchecked(Vector3::y()), depths[*i]);
+        let _ = manifold.push(contact, ContactKinematic::new(), pt, None, None);
+    }
+
+    let mut ids_in_order = order.iter().map(|i| ids[*i]);
+    for contact in manifold.contacts_mut() {
+        contact.id = ids_in_order.next().unwrap();
+    }
+
+    match manifold.deepest_two() {
+        (Some(first), Some(second)) => {
+            assert_eq!(first.id, ids[1]);
+            assert_eq!(second.id, ids[3]);
+            assert_eq!(first.contact.depth, 0.3);
+            assert_eq!(second.contact.depth, 0.3);
+        }
+        _ => panic!("The manifold should have at least two contacts."),
+    }
+
+    manifold.clear();
+    let (first, second) = manifold.deepest_two();
+    assert!(first.is_none() && second.is_none());
+}
+
+#[test]
+fn export_flat_matches_tracked_contacts() {
+    let mut id_alloc = IdAllocator::with_key();
+    let mut manifold = ContactManifold::new();
+
+    for i in 0..3 {
+        let world1 = Point3::new(i as f64, 0.0, 1.0);
+        let world2 = Point3::new(i as f64, -0.1 * i as f64, 1.0);
+        let normal = Unit::new_normalize(Vector3::new(0.0, -1.0, 0.1 * i as f64));
+        let contact = Contact::new(world1, world2, normal, 0.1 * i as f64);
+        let _ = manifold.push(contact, ContactKinematic::new(), world1, None, None);
+    }
+
+    for contact in manifold.contacts_mut() {
+        contact.id = id_alloc.insert(true);
+    }
+
+    let mut flat = vec![FlatContact {
+        p1: Point3::origin(),
+        p2: Point3::origin(),
+        normal: Vector3::zeros(),
+        depth: 0.0,
+        id: 0,
+    }];
+    manifold.export_flat(&mut flat);
+
+    // The exported contacts are appended.
+    assert_eq!(flat.len(), 4);
+    assert_eq!(flat[0].id, 0);
+
+    for (flat, tracked) in flat[1..].iter().zip(manifold.contacts()) {
+        assert_eq!(flat.p1, tracked.contact.world1);
+        assert_eq!(flat.p2, tracked.contact.world2);
+        assert_eq!(flat.normal, *tracked.contact.normal);
+        assert_eq!(flat.depth, tracked.contact.depth);
+        assert_eq!(flat.id, tracked.id.data().as_ffi());
+    }
+
+    // Each point or vector is stored as 3 contiguous scalars.
+    assert_eq!(
+        std::mem::size_of::<FlatContact<f64>>(),
+        10 * std::mem::size_of::<f64>() + std::mem::size_of::<u64>()
+    );
+}
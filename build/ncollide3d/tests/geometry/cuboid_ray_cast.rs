@@ -2,7 +2,7 @@
 
 use na::{Isometry3, Point3, Translation3, UnitQuaternion, Vector3};
 use ncollide3d::query::{PointQuery, Ray, RayCast};
-use ncollide3d::shape::{Ball, Cuboid, Shape};
+use ncollide3d::shape::{Ball, Capsule, Cuboid, Shape};
 
 fn run_test<S>(name: &str, shape: S)
 where
@@ -82,4 +82,5 @@ fn shape_ray_cast_points_to_surface() {
         "tall and slim rectangle",
         Cuboid::new(Vector3::new(0.5, 1.0, 0.5)),
     );
+    run_test("capsule", Capsule::new(1.0, 0.5));
 }
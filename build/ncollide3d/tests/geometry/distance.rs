@@ -0,0 +1,381 @@
+use na::{self, Isometry3, Point3, Unit, Vector3};
+use ncollide3d::query::{
+    self, ClosestPoints, ContactKinematic, NeighborhoodGeometry, TriTriIntersection,
+};
+use ncollide3d::shape::{Ball, Cuboid, FeatureId, Segment, SupportMap, Triangle};
+
+fn polygon_area(pts: &[Point3<f64>]) -> f64 {
+    let mut area = na::zero::<na::Vector3<f64>>();
+
+    for i in 1..pts.len() - 1 {
+        area += (pts[i] - pts[0]).cross(&(pts[i + 1] - pts[0]));
+    }
+
+    area.norm() / 2.0
+}
+
+#[test]
+fn crossing_triangles() {
+    let t1 = Triangle::new(
+        Point3::new(-1.0, 0.0, -1.0),
+        Point3::new(2.0, 0.0, -1.0),
+        Point3::new(-1.0, 0.0, 2.0),
+    );
+    let t2 = Triangle::new(
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 5.0),
+    );
+
+    match query::triangle_triangle_intersection(&t1, &t2) {
+        Some(TriTriIntersection::Segment(seg)) => {
+            // t2 crosses the plane y = 0 along the x = 0 line for z in [0, 5], and t1 covers
+            // z in [-1, 1] on this line.
+            let (a, b) = if seg.a.z < seg.b.z {
+                (seg.a, seg.b)
+            } else {
+                (seg.b, seg.a)
+            };
+            assert_relative_eq!(a, Point3::new(0.0, 0.0, 0.0), epsilon = 1.0e-10);
+            assert_relative_eq!(b, Point3::new(0.0, 0.0, 1.0), epsilon = 1.0e-10);
+        }
+        res => panic!("Unexpected intersection: {:?}", res),
+    }
+
+    match query::triangle_triangle_intersection(&t2, &t1) {
+        Some(TriTriIntersection::Segment(seg)) => {
+            assert_relative_eq!(seg.length(), 1.0, epsilon = 1.0e-10);
+        }
+        res => panic!("Unexpected intersection: {:?}", res),
+    }
+}
+
+#[test]
+fn coplanar_overlapping_triangles() {
+    let t1 = Triangle::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(2.0, 0.0, 1.0),
+        Point3::new(0.0, 2.0, 1.0),
+    );
+    let t2 = Triangle::new(
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(1.0, -1.0, 1.0),
+        Point3::new(3.0, 1.0, 1.0),
+    );
+
+    match query::triangle_triangle_intersection(&t1, &t2) {
+        Some(TriTriIntersection::Polygon(pts)) => {
+            // The overlap is the triangle (1, 0), (2, 0), (1, 1).
+            assert_eq!(pts.len(), 3);
+            assert_relative_eq!(polygon_area(&pts), 0.5, epsilon = 1.0e-10);
+
+            for pt in &pts {
+                assert_relative_eq!(pt.z, 1.0);
+            }
+        }
+        res => panic!("Unexpected intersection: {:?}", res),
+    }
+}
+
+#[test]
+fn separated_triangles() {
+    let t1 = Triangle::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+
+    // On a parallel plane.
+    let t2 = Triangle::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    );
+    assert!(query::triangle_triangle_intersection(&t1, &t2).is_none());
+
+    // Crossing the plane of `t1` outside of `t1`.
+    let t3 = Triangle::new(
+        Point3::new(2.0, 2.0, -1.0),
+        Point3::new(3.0, 2.0, 1.0),
+        Point3::new(2.0, 3.0, 1.0),
+    );
+    assert!(query::triangle_triangle_intersection(&t1, &t3).is_none());
+
+    // Coplanar but disjoint.
+    let t4 = Triangle::new(
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(2.0, 1.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+    );
+    assert!(query::triangle_triangle_intersection(&t1, &t4).is_none());
+}
+
+#[test]
+fn separating_axis_face() {
+    let cuboid = Cuboid::new(Vector3::repeat(1.0f64));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(3.0, 0.5, -0.2);
+
+    let axis = query::separating_axis(&m1, &cuboid, &m2, &cuboid).unwrap();
+    assert_relative_eq!(axis, Vector3::x_axis(), epsilon = 1.0e-7);
+
+    let axis = query::separating_axis(&m2, &cuboid, &m1, &cuboid).unwrap();
+    assert_relative_eq!(axis, -Vector3::x_axis(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn separating_axis_edge_edge() {
+    let cuboid = Cuboid::new(Vector3::repeat(1.0f64));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(3.0, 3.0, 0.5);
+
+    let axis = query::separating_axis(&m1, &cuboid, &m2, &cuboid).unwrap();
+    let expected = Unit::new_normalize(Vector3::new(1.0, 1.0, 0.0));
+    assert_relative_eq!(axis, expected, epsilon = 1.0e-7);
+}
+
+#[test]
+fn separating_axis_intersecting() {
+    let cuboid = Cuboid::new(Vector3::repeat(1.0f64));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(1.5, 0.5, 0.0);
+
+    assert!(query::separating_axis(&m1, &cuboid, &m2, &cuboid).is_none());
+}
+
+#[test]
+fn ball_ball_closest_points_straddling_margin() {
+    let b1 = Ball::new(1.0f64);
+    let b2 = Ball::new(0.5f64);
+    let m1 = Isometry3::translation(1.0, -2.0, 0.5);
+    let dir = Vector3::new(1.0, 2.0, -2.0).normalize();
+    let margin = 0.3;
+
+    for i in 0..=200 {
+        let gap = 0.25 + 0.1 * (i as f64) / 200.0;
+        let m2 = Isometry3::from_parts(
+            (m1.translation.vector + dir * (1.5 + gap)).into(),
+            na::one(),
+        );
+        let distance = query::distance(&m1, &b1, &m2, &b2);
+
+        let (p1, p2) = query::closest_points_witnesses(&m1, &b1, &m2, &b2).unwrap();
+
+        match query::closest_points(&m1, &b1, &m2, &b2, margin) {
+            ClosestPoints::WithinMargin(q1, q2) => {
+                assert!(distance <= margin);
+                assert_eq!((q1, q2), (p1, p2));
+            }
+            ClosestPoints::Disjoint => assert!(distance > margin),
+            ClosestPoints::Intersecting => panic!("The balls are not intersecting."),
+        }
+
+        assert_relative_eq!(
+            na::distance(&p1, &Point3::from(m1.translation.vector)),
+            1.0,
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(
+            na::distance(&p2, &Point3::from(m2.translation.vector)),
+            0.5,
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(na::distance(&p1, &p2), distance, epsilon = 1.0e-10);
+    }
+
+    // A margin larger than any distance always yields the witness points.
+    let m2 = Isometry3::translation(100.0, 0.0, 0.0);
+    assert!(match query::closest_points(&m1, &b1, &m2, &b2, f64::MAX) {
+        ClosestPoints::WithinMargin(..) => true,
+        _ => false,
+    });
+}
+
+#[test]
+fn cuboid_cuboid_disjoint_closest_points() {
+    let c = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(5.0, 0.5, 0.0);
+
+    match query::closest_points(&m1, &c, &m2, &c, 1.0) {
+        ClosestPoints::Disjoint => {}
+        res => panic!("Unexpected closest points: {:?}", res),
+    }
+
+    match query::closest_points_witnesses(&m1, &c, &m2, &c) {
+        Some((p1, p2)) => {
+            assert_relative_eq!(p1.x, 1.0, epsilon = 1.0e-6);
+            assert_relative_eq!(p2.x, 4.0, epsilon = 1.0e-6);
+            assert_relative_eq!(na::distance(&p1, &p2), 3.0, epsilon = 1.0e-6);
+        }
+        None => panic!("The cuboids are not intersecting."),
+    }
+
+    let m2 = Isometry3::translation(1.5, 0.5, 0.0);
+    assert!(query::closest_points_witnesses(&m1, &c, &m2, &c).is_none());
+}
+
+#[test]
+fn distance_between_support_map_trait_objects() {
+    let ball1 = Ball::new(0.5);
+    let ball2 = Ball::new(1.0);
+    let s1: &dyn SupportMap<f64> = &ball1;
+    let s2: &dyn SupportMap<f64> = &ball2;
+
+    let m1 = Isometry3::new(Vector3::new(1.0, 2.0, -1.0), Vector3::new(0.1, 0.2, 0.3));
+    let m2 = Isometry3::new(Vector3::new(4.0, -1.0, 2.0), na::zero());
+
+    let dist = query::support_map_distance(&m1, s1, &m2, s2);
+    let expected = query::distance(&m1, &ball1, &m2, &ball2);
+
+    assert_relative_eq!(dist, expected, epsilon = 1.0e-6);
+    assert_relative_eq!(dist, 27.0f64.sqrt() - 1.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn line_line_parameters_parallel() {
+    let orig1 = Point3::new(1.0f64, 0.0, 0.0);
+    let orig2 = Point3::new(0.3, 1.0, 0.0);
+    let eps = 1.0e-10;
+
+    // Exactly parallel: `orig1` is projected on the second line.
+    let (s, t, parallel) = query::closest_points_line_line_parameters_relative_eps(
+        &orig1,
+        &Vector3::x(),
+        &orig2,
+        &Vector3::x(),
+        eps,
+    );
+    assert!(parallel);
+    assert_eq!((s, t), (0.0, 0.7));
+
+    // Nearly parallel.
+    let dir2 = Vector3::new(1.0, 1.0e-6, 0.0);
+    let (s, t, parallel) = query::closest_points_line_line_parameters_relative_eps(
+        &orig1,
+        &Vector3::x(),
+        &orig2,
+        &dir2,
+        eps,
+    );
+    assert!(parallel);
+    assert_eq!(s, 0.0);
+    assert!(t.is_finite());
+    assert_relative_eq!(
+        orig2 + dir2 * t,
+        Point3::new(1.0, 1.0, 0.0),
+        epsilon = 1.0e-5
+    );
+
+    // The test does not depend on the norm of the directions.
+    let dir1 = Vector3::new(1.0, 0.0, 0.0) * 1.0e-3;
+    let dir2 = Vector3::new(1.0, 1.0, 0.0) * 1.0e-3;
+    let (_, _, parallel) =
+        query::closest_points_line_line_parameters_relative_eps(&orig1, &dir1, &orig2, &dir2, eps);
+    assert!(!parallel);
+
+    // The absolute test does.
+    let (_, _, parallel) =
+        query::closest_points_line_line_parameters_eps(&orig1, &dir1, &orig2, &dir2, eps);
+    assert!(parallel);
+}
+
+#[test]
+fn contact_kinematic_parallel_lines() {
+    let segment = Segment::new(Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(0.3, 1.0, 0.0);
+    let mut kinematic = ContactKinematic::new();
+    kinematic.set_approx1(
+        FeatureId::Edge(0),
+        Point3::origin(),
+        NeighborhoodGeometry::Line(Vector3::x_axis()),
+    );
+
+    for dir2 in &[
+        Vector3::x(),
+        Vector3::new(1.0, 1.0e-6, 0.0),
+        Vector3::new(1.0, 0.0, -1.0e-7),
+    ] {
+        kinematic.set_approx2(
+            FeatureId::Edge(0),
+            Point3::origin(),
+            NeighborhoodGeometry::Line(Unit::new_normalize(*dir2)),
+        );
+
+        let contact = kinematic
+            .contact(&m1, &segment, None, &m2, &segment, None, &Vector3::y_axis())
+            .unwrap();
+
+        assert_relative_eq!(contact.world1, Point3::origin());
+        assert_relative_eq!(contact.world2, Point3::new(0.0, 1.0, 0.0), epsilon = 1.0e-5);
+        assert_relative_eq!(*contact.normal, Vector3::y(), epsilon = 1.0e-5);
+        assert_relative_eq!(contact.depth, -1.0, epsilon = 1.0e-5);
+    }
+}
+
+#[test]
+fn closest_feature_pair_face_face() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(3.0, 0.5, -0.2);
+
+    let (f1, f2) = query::closest_feature_pair(&m1, &cuboid, &m2, &cuboid);
+    assert_eq!(f1, FeatureId::Face(0));
+    assert_eq!(f2, FeatureId::Face(3));
+}
+
+#[test]
+fn closest_feature_pair_vertex_vertex() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(3.0, 3.0, 3.0);
+
+    let (f1, f2) = query::closest_feature_pair(&m1, &cuboid, &m2, &cuboid);
+    assert_eq!(f1, FeatureId::Vertex(0));
+    assert_eq!(f2, FeatureId::Vertex(7));
+}
+
+#[test]
+fn closest_feature_pair_intersecting() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(1.5, 0.0, 0.0);
+
+    let (f1, f2) = query::closest_feature_pair(&m1, &cuboid, &m2, &cuboid);
+    assert_eq!(f1, FeatureId::Unknown);
+    assert_eq!(f2, FeatureId::Unknown);
+}
+
+#[test]
+fn segment_ball_distance_interior_projection() {
+    let segment = Segment::new(Point3::new(-2.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0));
+    let ball = Ball::new(0.5);
+    let m = Isometry3::new(Vector3::new(1.0, 3.0, 0.0), na::zero());
+
+    // The center projects inside of the segment at distance 3.
+    let dist = query::distance_segment_shape(&segment, &m, &ball);
+    assert_relative_eq!(dist, 3.0 - 0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn segment_ball_distance_clamped_to_endpoint() {
+    let segment = Segment::new(Point3::new(-2.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0));
+    let ball = Ball::new(0.5);
+    let center = Vector3::new(5.0, 4.0, 0.0);
+    let m = Isometry3::new(center, na::zero());
+
+    // The projection on the supporting line lies outside of the segment so the closest
+    // point is the endpoint (2, 0, 0), at distance 5 from the ball center.
+    let dist = query::distance_segment_shape(&segment, &m, &ball);
+    assert_relative_eq!(dist, 5.0 - 0.5, epsilon = 1.0e-6);
+}
+
+#[test]
+fn segment_ball_distance_intersecting() {
+    let segment = Segment::new(Point3::new(-2.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0));
+    let ball = Ball::new(0.5);
+    let m = Isometry3::new(Vector3::new(0.0, 0.25, 0.0), na::zero());
+
+    assert_eq!(query::distance_segment_shape(&segment, &m, &ball), 0.0);
+}
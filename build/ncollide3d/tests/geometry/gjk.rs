@@ -0,0 +1,237 @@
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide3d::query::algorithms::gjk::{self, GJKResult, GjkParams};
+use ncollide3d::query::algorithms::{CSOPoint, VoronoiSimplex};
+use ncollide3d::shape::{Ball, Cuboid};
+
+#[test]
+fn sliver_tetrahedron_reduces_to_triangle() {
+    let a = Point3::new(-1.0f64, -1.0, 1.0);
+    let c = Point3::new(2.0, -1.0, 1.0);
+    let d = Point3::new(-1.0, 2.0, 1.0);
+    // Nearly coincident with `a`.
+    let b = Point3::new(-1.0, -1.0, 1.0 + 1.0e-9);
+
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(CSOPoint::single_point(a));
+    assert!(simplex.add_point(CSOPoint::single_point(c)));
+    assert!(simplex.add_point(CSOPoint::single_point(d)));
+    assert!(simplex.add_point(CSOPoint::single_point(b)));
+    assert_eq!(simplex.dimension(), 3);
+
+    let proj = simplex.project_origin_and_reduce();
+
+    assert_eq!(simplex.dimension(), 2);
+    assert_relative_eq!(proj, Point3::new(0.0, 0.0, 1.0), epsilon = 1.0e-7);
+
+    for i in 0..3 {
+        assert!(simplex.point(i).point != b);
+    }
+}
+
+fn thin_tetrahedron_around_origin<N: na::RealField>(height: N) -> VoronoiSimplex<N> {
+    let half: N = height * na::convert(0.5);
+    let pt = |x: f64, y: f64, z: N| {
+        CSOPoint::single_point(Point3::new(na::convert(x), na::convert(y), z))
+    };
+
+    // A zero tolerance so the apex is not rejected by `add_point`.
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(pt(-1.0, -1.0, -half));
+    assert!(simplex.add_point_with_tolerance(pt(2.0, -1.0, -half), N::zero()));
+    assert!(simplex.add_point_with_tolerance(pt(-1.0, 2.0, -half), N::zero()));
+    assert!(simplex.add_point_with_tolerance(pt(0.0, 0.0, half), N::zero()));
+    simplex
+}
+
+#[test]
+fn sliver_tetrahedron_containing_origin_reduces_to_triangle() {
+    let mut simplex = thin_tetrahedron_around_origin(1.0e-16f64);
+    let proj = simplex.project_origin_and_reduce();
+
+    // Without the reduction, the origin would be found inside of the tetrahedron.
+    assert_eq!(simplex.dimension(), 2);
+    assert_relative_eq!(proj, Point3::origin(), epsilon = 1.0e-15);
+}
+
+#[test]
+fn thin_f32_tetrahedron_is_not_reduced() {
+    // The height is small, but well above the precision of `f32`.
+    let mut simplex = thin_tetrahedron_around_origin(1.0e-4f32);
+    let proj = simplex.project_origin_and_reduce();
+
+    assert_eq!(simplex.dimension(), 3);
+    assert_eq!(proj, Point3::origin());
+}
+
+fn tiny_balls_closest_points(params: &GjkParams<f64>) -> GJKResult<f64> {
+    // Two balls at a sub-femtometer scale, separated by a gap of 1.0e-15.
+    let scale = 1.0e-13;
+    let ball = Ball::new(scale);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(2.0 * scale + 1.0e-15, 0.0, 0.0);
+
+    let mut simplex = VoronoiSimplex::new();
+    let dir = Vector3::x_axis();
+    simplex.reset(CSOPoint::from_shapes(&m1, &ball, &m2, &ball, &dir));
+
+    gjk::closest_points_with_params(&m1, &ball, &m2, &ball, f64::MAX, true, &mut simplex, params)
+}
+
+#[test]
+fn gjk_params_scaled_tolerance() {
+    let default = GjkParams::default();
+    assert_eq!(default.eps_tol, None);
+
+    // The gap is below the default tolerance so the balls are considered intersecting.
+    assert_eq!(tiny_balls_closest_points(&default), GJKResult::Intersection);
+
+    // Scaling the tolerance with the size of the shapes fixes this.
+    let scaled = GjkParams::new(gjk::eps_tol::<f64>() * 1.0e-13, default.max_iterations);
+
+    match tiny_balls_closest_points(&scaled) {
+        GJKResult::ClosestPoints(p1, p2, _) => {
+            assert_relative_eq!(na::distance(&p1, &p2), 1.0e-15, epsilon = 1.0e-20)
+        }
+        res => panic!("Unexpected GJK result: {:?}", res),
+    }
+}
+
+#[test]
+fn simplex_tolerance_is_a_distance() {
+    let eps_tol = 1.0e-3;
+    let pt = |x: f64, y: f64, z: f64| CSOPoint::single_point(Point3::new(x, y, z));
+
+    // Each point is rejected if closer than `eps_tol` to the current simplex, whatever its
+    // dimension and the length of its edges.
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(pt(0.0, 0.0, 0.0));
+    assert!(!simplex.add_point_with_tolerance(pt(0.9e-3, 0.0, 0.0), eps_tol));
+    assert!(simplex.add_point_with_tolerance(pt(1.1e-3, 0.0, 0.0), eps_tol));
+    assert!(!simplex.add_point_with_tolerance(pt(10.0, 0.9e-3, 0.0), eps_tol));
+    assert!(simplex.add_point_with_tolerance(pt(10.0, 1.1e-3, 0.0), eps_tol));
+    assert!(!simplex.add_point_with_tolerance(pt(5.0, 5.0, 0.9e-3), eps_tol));
+    assert!(simplex.add_point_with_tolerance(pt(5.0, 5.0, 1.1e-3), eps_tol));
+
+    // The same tolerance is used to detect flat tetrahedra, here of height 5.0e-3.
+    let mut tetrahedron = VoronoiSimplex::new();
+    tetrahedron.reset(pt(-1.0, -1.0, -2.5e-3));
+    assert!(tetrahedron.add_point_with_tolerance(pt(2.0, -1.0, -2.5e-3), eps_tol));
+    assert!(tetrahedron.add_point_with_tolerance(pt(-1.0, 2.0, -2.5e-3), eps_tol));
+    assert!(tetrahedron.add_point_with_tolerance(pt(0.0, 0.0, 2.5e-3), eps_tol));
+
+    let mut flat = tetrahedron.clone();
+    let _ = tetrahedron.project_origin_and_reduce_with_tolerance(eps_tol);
+    assert_eq!(tetrahedron.dimension(), 3);
+    let _ = flat.project_origin_and_reduce_with_tolerance(1.0e-2);
+    assert_eq!(flat.dimension(), 2);
+}
+
+#[test]
+fn simplex_default_tolerance_is_unchanged() {
+    let pt = |x: f64| CSOPoint::single_point(Point3::new(x, 0.0, 0.0));
+
+    // Without an explicit tolerance, `gjk::eps_tol()` is compared with the squared distance.
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(pt(0.0));
+    assert!(!simplex.add_point(pt(1.0e-8)));
+    assert!(simplex.add_point_with_tolerance(pt(1.0e-8), gjk::eps_tol()));
+}
+
+#[test]
+fn gjk_project_origin_on_cso_separated_balls() {
+    let b1 = Ball::new(0.5f64);
+    let b2 = Ball::new(1.0f64);
+    let m1 = Isometry3::translation(1.0, 2.0, -1.0);
+    let m2 = Isometry3::translation(-2.0, 3.0, 2.5);
+
+    let (witnesses, _) = gjk::project_origin_on_cso(&m1, &b1, &m2, &b2);
+    let (pt1, pt2) = witnesses.unwrap();
+    let center_dist = (m2.translation.vector - m1.translation.vector).norm();
+    assert_relative_eq!((pt1 - pt2).norm(), center_dist - 1.5, epsilon = 1.0e-6);
+
+    // The witness points are on the surface of each ball. Their positions are less accurate
+    // than the distance because the balls' surfaces are flat at first order near them.
+    let dir = (m2.translation.vector - m1.translation.vector) / center_dist;
+    assert_relative_eq!(pt1, m1 * Point3::from(dir * 0.5), epsilon = 1.0e-3);
+    assert_relative_eq!(pt2, m2 * Point3::from(-dir * 1.0), epsilon = 1.0e-3);
+}
+
+#[test]
+fn gjk_project_origin_on_cso_intersecting_balls() {
+    let b = Ball::new(1.0f64);
+    let m1 = Isometry3::translation(0.0, 0.5, 0.0);
+    let m2 = Isometry3::translation(0.3, 0.0, 0.0);
+
+    let (witnesses, _) = gjk::project_origin_on_cso(&m1, &b, &m2, &b);
+    assert!(witnesses.is_none());
+}
+
+fn closest_points(seed_dir: Option<Unit<Vector3<f64>>>, max_iterations: usize) -> GJKResult<f64> {
+    let cuboid1 = Cuboid::new(Vector3::new(1.0, 0.5, 0.5));
+    let cuboid2 = Cuboid::new(Vector3::new(0.5, 1.0, 0.5));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::new(Vector3::new(3.0, 0.3, 0.2), Vector3::new(0.3, 0.2, 0.1));
+
+    // Start from a poor initial point of the Minkowski difference.
+    let start = CSOPoint::from_shapes(&m1, &cuboid1, &m2, &cuboid2, &Vector3::y_axis());
+    let mut simplex = VoronoiSimplex::new();
+
+    match seed_dir {
+        Some(dir) => {
+            simplex.reset_with_dir(start, dir);
+            assert_eq!(simplex.seed_dir(), Some(&dir));
+        }
+        None => simplex.reset(start),
+    }
+
+    let params = GjkParams::new(gjk::eps_tol(), max_iterations);
+    let res = gjk::closest_points_with_params(
+        &m1,
+        &cuboid1,
+        &m2,
+        &cuboid2,
+        f64::MAX,
+        true,
+        &mut simplex,
+        &params,
+    );
+    assert!(simplex.seed_dir().is_none());
+    res
+}
+
+// The smallest number of iterations for which GJK converges.
+fn num_iterations(seed_dir: Option<Unit<Vector3<f64>>>) -> usize {
+    (1..100)
+        .find(|i| match closest_points(seed_dir, *i) {
+            GJKResult::ClosestPoints(..) => true,
+            _ => false,
+        })
+        .unwrap()
+}
+
+#[test]
+fn gjk_seed_dir_same_closest_points() {
+    let (p1, p2, dir) = match closest_points(None, 100) {
+        GJKResult::ClosestPoints(p1, p2, dir) => (p1, p2, dir),
+        res => panic!("Unexpected GJK result: {:?}", res),
+    };
+
+    match closest_points(Some(dir), 100) {
+        GJKResult::ClosestPoints(q1, q2, _) => {
+            assert_relative_eq!(q1, p1, epsilon = 1.0e-6);
+            assert_relative_eq!(q2, p2, epsilon = 1.0e-6);
+        }
+        res => panic!("Unexpected GJK result: {:?}", res),
+    }
+}
+
+#[test]
+fn gjk_seed_dir_changes_iterations() {
+    let dir = match closest_points(None, 100) {
+        GJKResult::ClosestPoints(_, _, dir) => dir,
+        res => panic!("Unexpected GJK result: {:?}", res),
+    };
+
+    // Seeding with the final separating direction skips some of the first iterations.
+    assert!(num_iterations(Some(dir)) < num_iterations(None));
+}
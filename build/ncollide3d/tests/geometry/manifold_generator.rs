@@ -0,0 +1,781 @@
+use na::{self, Isometry3, Point3, Unit, Vector3};
+use ncollide3d::pipeline::narrow_phase::{
+    CompositeShapeShapeManifoldGenerator, ContactManifoldGenerator,
+    ConvexPolyhedronConvexPolyhedronManifoldGenerator, CuboidBallManifoldGenerator,
+    DefaultContactDispatcher, PlaneConvexPolyhedronManifoldGenerator,
+    TriangleBallManifoldGenerator,
+};
+use ncollide3d::query::{
+    Contact, ContactKinematic, ContactManifold, ContactPrediction, NeighborhoodGeometry,
+    TrackedContact,
+};
+use ncollide3d::shape::{
+    Ball, Capsule, Compound, ConvexHull, Cuboid, FeatureId, Plane, Shape, ShapeHandle, Triangle,
+};
+use std::f64::consts::PI;
+
+#[test]
+fn cuboid_cuboid_contact_patch_area() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(0.0, 1.9, 0.0);
+    let prediction = ContactPrediction::new(0.1, 0.0, 0.0);
+    let mut manifold = ContactManifold::new();
+    let mut generator = ConvexPolyhedronConvexPolyhedronManifoldGenerator::new();
+
+    let _ = generator.generate_contacts(
+        &DefaultContactDispatcher::new(),
+        &m1,
+        &cuboid,
+        None,
+        &m2,
+        &cuboid,
+        None,
+        &prediction,
+        &mut manifold,
+    );
+
+    assert_eq!(manifold.len(), 4);
+    assert_relative_eq!(manifold.contact_patch_area(), 4.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn capsule_capsule_contact_patch_area() {
+    // Two parallel capsules lying side by side touch along a segment.
+    let capsule = Capsule::new(1.0f64, 0.5);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(0.9, 0.0, 0.0);
+    let normal = Unit::new_unchecked(Vector3::x());
+    let mut manifold = ContactManifold::new();
+
+    let (a1, b1) = capsule.world_endpoints(&m1);
+    let (a2, b2) = capsule.world_endpoints(&m2);
+
+    for (a, b) in [(a1, a2), (b1, b2)].iter() {
+        let world1 = a + *normal * capsule.radius;
+        let world2 = b - *normal * capsule.radius;
+        let contact = Contact::new_wo_depth(world1, world2, normal);
+        let _ = manifold.push(contact, ContactKinematic::new(), world1, None, None);
+    }
+
+    assert_eq!(manifold.len(), 2);
+    assert_eq!(manifold.contact_patch_area(), 0.0);
+}
+
+fn cuboid_pos() -> Isometry3<f64> {
+    Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, -0.2, 0.7))
+}
+
+fn cuboid_contact_with_ball_at(local_center: Point3<f64>, flip: bool) -> TrackedContact<f64> {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let ball = Ball::new(0.5);
+    let m_cuboid = cuboid_pos();
+    let m_ball = Isometry3::new(
+        (m_cuboid * local_center).coords,
+        Vector3::new(0.1, 0.2, 0.3),
+    );
+    let prediction = ContactPrediction::new(0.0, 0.0, 0.0);
+    let dispatcher = DefaultContactDispatcher::new();
+    let mut generator = CuboidBallManifoldGenerator::new(flip);
+    let mut manifold = ContactManifold::new();
+
+    let generated = if !flip {
+        generator.generate_contacts(
+            &dispatcher,
+            &m_cuboid,
+            &cuboid,
+            None,
+            &m_ball,
+            &ball,
+            None,
+            &prediction,
+            &mut manifold,
+        )
+    } else {
+        generator.generate_contacts(
+            &dispatcher,
+            &m_ball,
+            &ball,
+            None,
+            &m_cuboid,
+            &cuboid,
+            None,
+            &prediction,
+            &mut manifold,
+        )
+    };
+
+    assert!(generated);
+    assert_eq!(manifold.len(), 1);
+    *manifold.deepest_contact().unwrap()
+}
+
+fn check_cuboid_contact(
+    local_center: Point3<f64>,
+    local1: Point3<f64>,
+    local_normal: Vector3<f64>,
+    depth: f64,
+    feature: FeatureId,
+) {
+    let m = cuboid_pos();
+    let normal = m * Unit::new_normalize(local_normal);
+    let world1 = m * local1;
+    let world2 = m * local_center - *normal * 0.5;
+
+    let c = cuboid_contact_with_ball_at(local_center, false);
+    assert_relative_eq!(c.contact.depth, depth, epsilon = 1.0e-10);
+    assert_relative_eq!(*c.contact.normal, *normal, epsilon = 1.0e-10);
+    assert_relative_eq!(c.contact.world1, world1, epsilon = 1.0e-10);
+    assert_relative_eq!(c.contact.world2, world2, epsilon = 1.0e-10);
+    assert_eq!(c.kinematic.feature1(), feature);
+    assert_eq!(c.kinematic.feature2(), FeatureId::Face(0));
+
+    let c = cuboid_contact_with_ball_at(local_center, true);
+    assert_relative_eq!(c.contact.depth, depth, epsilon = 1.0e-10);
+    assert_relative_eq!(*c.contact.normal, -*normal, epsilon = 1.0e-10);
+    assert_relative_eq!(c.contact.world1, world2, epsilon = 1.0e-10);
+    assert_relative_eq!(c.contact.world2, world1, epsilon = 1.0e-10);
+    assert_eq!(c.kinematic.feature1(), FeatureId::Face(0));
+    assert_eq!(c.kinematic.feature2(), feature);
+}
+
+#[test]
+fn cuboid_ball_face_contact() {
+    check_cuboid_contact(
+        Point3::new(1.3, 0.5, 0.2),
+        Point3::new(1.0, 0.5, 0.2),
+        Vector3::x(),
+        0.2,
+        FeatureId::Face(0),
+    );
+    check_cuboid_contact(
+        Point3::new(0.5, -2.4, 0.2),
+        Point3::new(0.5, -2.0, 0.2),
+        -Vector3::y(),
+        0.1,
+        FeatureId::Face(4),
+    );
+}
+
+#[test]
+fn cuboid_ball_edge_contact() {
+    check_cuboid_contact(
+        Point3::new(1.2, 2.2, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Vector3::new(1.0, 1.0, 0.0),
+        0.5 - 0.08f64.sqrt(),
+        FeatureId::Edge(2),
+    );
+}
+
+#[test]
+fn cuboid_ball_vertex_contact() {
+    check_cuboid_contact(
+        Point3::new(-1.2, -2.2, -3.2),
+        Point3::new(-1.0, -2.0, -3.0),
+        Vector3::new(-1.0, -1.0, -1.0),
+        0.5 - 0.12f64.sqrt(),
+        FeatureId::Vertex(0b111),
+    );
+}
+
+#[test]
+fn cuboid_ball_center_inside() {
+    check_cuboid_contact(
+        Point3::new(0.8, 0.5, -2.0),
+        Point3::new(1.0, 0.5, -2.0),
+        Vector3::x(),
+        0.7,
+        FeatureId::Face(0),
+    );
+    check_cuboid_contact(
+        Point3::new(0.1, 0.5, -2.5),
+        Point3::new(0.1, 0.5, -3.0),
+        -Vector3::z(),
+        1.0,
+        FeatureId::Face(5),
+    );
+}
+
+#[test]
+fn reference_face_is_stable_on_symmetric_box_stack() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    // The same box, stacked on top of the first one and slightly penetrating it.
+    let m2 = Isometry3::new(Vector3::new(0.0, 1.99, 0.0), na::zero());
+    let dispatcher = DefaultContactDispatcher::new();
+    let prediction = ContactPrediction::new(0.01, 0.0, 0.0);
+    let mut generator = ConvexPolyhedronConvexPolyhedronManifoldGenerator::new();
+    let mut manifold = ContactManifold::new();
+    let mut first = None;
+
+    for _ in 0..10 {
+        manifold.save_cache_and_clear();
+        assert!(generator.generate_contacts(
+            &dispatcher,
+            &m1,
+            &cuboid,
+            None,
+            &m2,
+            &cuboid,
+            None,
+            &prediction,
+            &mut manifold,
+        ));
+        assert_eq!(manifold.len(), 4);
+
+        let features = generator.manifold_features().unwrap();
+        assert_eq!(features.reference_shape, 0);
+        match (features.reference_feature, features.incident_feature) {
+            (FeatureId::Face(_), FeatureId::Face(_)) => {}
+            _ => panic!("Box-box stacking should be face-face."),
+        }
+
+        if let Some(first) = first {
+            assert_eq!(features, first);
+        } else {
+            first = Some(features);
+        }
+    }
+
+    // Disjoint boxes do not have any manifold features.
+    let far = Isometry3::new(Vector3::new(0.0, 5.0, 0.0), na::zero());
+    manifold.save_cache_and_clear();
+    let _ = generator.generate_contacts(
+        &dispatcher,
+        &m1,
+        &cuboid,
+        None,
+        &far,
+        &cuboid,
+        None,
+        &prediction,
+        &mut manifold,
+    );
+    assert!(generator.manifold_features().is_none());
+}
+
+#[test]
+fn reference_face_is_on_the_shape_aligned_with_the_normal() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    // A box resting on one of its edges on the top face of the second box.
+    let angle = std::f64::consts::FRAC_PI_4;
+    let m1 = Isometry3::new(
+        Vector3::new(0.0, 2.0f64.sqrt() + 0.99, 0.0),
+        Vector3::z() * angle,
+    );
+    let m2 = Isometry3::identity();
+    let mut generator = ConvexPolyhedronConvexPolyhedronManifoldGenerator::new();
+    let mut manifold = ContactManifold::new();
+
+    assert!(generator.generate_contacts(
+        &DefaultContactDispatcher::new(),
+        &m1,
+        &cuboid,
+        None,
+        &m2,
+        &cuboid,
+        None,
+        &ContactPrediction::new(0.01, 0.0, 0.0),
+        &mut manifold,
+    ));
+
+    let features = generator.manifold_features().unwrap();
+    assert_eq!(features.reference_shape, 1);
+    match features.reference_feature {
+        FeatureId::Face(_) => {}
+        _ => panic!("The reference feature should be the top face of the second box."),
+    }
+}
+
+#[test]
+fn no_manifold_features_without_clipping() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m1 = Isometry3::identity();
+    let mut generator = ConvexPolyhedronConvexPolyhedronManifoldGenerator::new();
+    let mut manifold = ContactManifold::new();
+
+    // Two boxes touching by their corners and by their edges: the only contact is the one
+    // computed by GJK.
+    for t in &[
+        Vector3::new(2.005, 2.005, 2.005),
+        Vector3::new(2.005, 2.005, 0.0),
+    ] {
+        manifold.save_cache_and_clear();
+        assert!(generator.generate_contacts(
+            &DefaultContactDispatcher::new(),
+            &m1,
+            &cuboid,
+            None,
+            &Isometry3::new(*t, na::zero()),
+            &cuboid,
+            None,
+            &ContactPrediction::new(0.01, 0.0, 0.0),
+            &mut manifold,
+        ));
+        assert_eq!(manifold.len(), 1);
+        assert!(generator.manifold_features().is_none());
+    }
+}
+
+fn generate(
+    generator: &mut dyn ContactManifoldGenerator<f64>,
+    m1: &Isometry3<f64>,
+    g1: &dyn Shape<f64>,
+    m2: &Isometry3<f64>,
+    g2: &dyn Shape<f64>,
+) -> Vec<Contact<f64>> {
+    let dispatcher = DefaultContactDispatcher::new();
+    let prediction = ContactPrediction::new(0.01, 0.0, 0.0);
+    let mut manifold = generator.init_manifold();
+
+    // Run a few times so the generator caches are warm.
+    for _ in 0..3 {
+        manifold.save_cache_and_clear();
+        let _ = generator.generate_contacts(
+            &dispatcher,
+            m1,
+            g1,
+            None,
+            m2,
+            g2,
+            None,
+            &prediction,
+            &mut manifold,
+        );
+    }
+
+    contacts(&manifold)
+}
+
+fn contacts(manifold: &ContactManifold<f64>) -> Vec<Contact<f64>> {
+    manifold.contacts().map(|c| c.contact).collect()
+}
+
+#[test]
+fn reset_convex_polyhedron_generator() {
+    let cuboid1 = Cuboid::new(Vector3::new(1.0, 0.5, 2.0));
+    let cuboid2 = Cuboid::new(Vector3::new(0.3, 0.3, 0.3));
+
+    let mut recycled = ConvexPolyhedronConvexPolyhedronManifoldGenerator::new();
+    let first = generate(
+        &mut recycled,
+        &Isometry3::identity(),
+        &cuboid1,
+        &Isometry3::new(Vector3::new(0.2, 0.98, 0.1), Vector3::y() * 0.3),
+        &cuboid1,
+    );
+    assert!(!first.is_empty());
+
+    let m1 = Isometry3::new(Vector3::new(5.0, 0.0, 0.0), Vector3::z() * 0.2);
+    let m2 = Isometry3::new(Vector3::new(5.3, 0.7, 0.0), Vector3::new(0.1, 0.4, 0.2));
+    recycled.reset();
+    let recycled_contacts = generate(&mut recycled, &m1, &cuboid1, &m2, &cuboid2);
+
+    let mut fresh = ConvexPolyhedronConvexPolyhedronManifoldGenerator::new();
+    let fresh_contacts = generate(&mut fresh, &m1, &cuboid1, &m2, &cuboid2);
+
+    assert!(!fresh_contacts.is_empty());
+    assert_eq!(recycled_contacts, fresh_contacts);
+}
+
+#[test]
+fn reset_composite_shape_generator() {
+    let cuboid = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let parts = vec![
+        (Isometry3::identity(), ShapeHandle::new(cuboid)),
+        (
+            Isometry3::new(Vector3::new(2.0, 0.0, 0.0), na::zero()),
+            ShapeHandle::new(cuboid),
+        ),
+    ];
+    let compound = Compound::new(parts);
+    let small = Cuboid::new(Vector3::new(0.2, 0.2, 0.2));
+
+    let mut recycled = CompositeShapeShapeManifoldGenerator::new(false);
+    let first = generate(
+        &mut recycled,
+        &Isometry3::identity(),
+        &compound,
+        &Isometry3::translation(0.0, 0.69, 0.0),
+        &small,
+    );
+    assert!(!first.is_empty());
+
+    let m2 = Isometry3::new(Vector3::new(2.1, 0.68, 0.0), Vector3::y() * 0.5);
+    recycled.reset();
+    let recycled_contacts = generate(
+        &mut recycled,
+        &Isometry3::identity(),
+        &compound,
+        &m2,
+        &small,
+    );
+
+    let mut fresh = CompositeShapeShapeManifoldGenerator::new(false);
+    let fresh_contacts = generate(&mut fresh, &Isometry3::identity(), &compound, &m2, &small);
+
+    assert!(!fresh_contacts.is_empty());
+    assert_eq!(recycled_contacts, fresh_contacts);
+}
+
+#[test]
+fn contact_kinematic_negative_dilations() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let ball = Ball::new(0.5);
+    let m1 = Isometry3::identity();
+    // The ball center is 0.2 above the top face of the cuboid.
+    let m2 = Isometry3::translation(0.3, 1.2, -0.1);
+
+    let mut kinematic = ContactKinematic::new();
+    kinematic.set_approx1(
+        FeatureId::Face(1),
+        Point3::new(0.0, 1.0, 0.0),
+        NeighborhoodGeometry::Plane(Vector3::y_axis()),
+    );
+    kinematic.set_approx2(
+        FeatureId::Face(0),
+        Point3::origin(),
+        NeighborhoodGeometry::Point,
+    );
+
+    // The ball dilation is its radius, so the initial depth is 0.3.
+    kinematic.set_dilation2(ball.radius);
+
+    for i in 0..=10 {
+        // Erode the cuboid down to past the ball.
+        let erosion = i as f64 * 0.1;
+        kinematic.set_dilation1(-erosion);
+
+        let contact = kinematic
+            .contact(&m1, &cuboid, None, &m2, &ball, None, &Vector3::y_axis())
+            .unwrap();
+        let clamped_erosion = erosion.min(0.3);
+
+        assert_relative_eq!(*contact.normal, Vector3::y(), epsilon = 1.0e-10);
+        assert_relative_eq!(contact.depth, 0.3 - clamped_erosion, epsilon = 1.0e-10);
+        assert!(contact.depth >= 0.0);
+        assert_relative_eq!(
+            contact.world1,
+            Point3::new(0.3, 1.0 - clamped_erosion, -0.1),
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(
+            contact.world2,
+            Point3::new(0.3, 0.7, -0.1),
+            epsilon = 1.0e-10
+        );
+    }
+
+    // Both solids eroded, with the ball center 0.1 below the top face: the erosions are scaled
+    // down together.
+    let below = Isometry3::translation(0.3, 0.9, -0.1);
+    kinematic.set_dilation1(-0.2);
+    kinematic.set_dilation2(-0.4);
+    let contact = kinematic
+        .contact(&m1, &cuboid, None, &below, &ball, None, &Vector3::y_axis())
+        .unwrap();
+    assert_relative_eq!(contact.depth, 0.0, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        contact.world1,
+        Point3::new(0.3, 1.0 - 0.2 / 6.0, -0.1),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(contact.world1, contact.world2, epsilon = 1.0e-10);
+
+    // Separated solids are not affected by the erosion.
+    kinematic.set_dilation1(-0.5);
+    kinematic.set_dilation2(0.1);
+    let contact = kinematic
+        .contact(&m1, &cuboid, None, &m2, &ball, None, &Vector3::y_axis())
+        .unwrap();
+    assert_relative_eq!(contact.depth, -0.1, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        contact.world1,
+        Point3::new(0.3, 1.0, -0.1),
+        epsilon = 1.0e-10
+    );
+}
+
+fn triangle_pos() -> Isometry3<f64> {
+    Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, -0.2, 0.7))
+}
+
+fn triangle_contact_with_ball_at(local_center: Point3<f64>, flip: bool) -> TrackedContact<f64> {
+    let triangle = Triangle::new(
+        Point3::origin(),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+    );
+    let ball = Ball::new(0.5);
+    let m_triangle = triangle_pos();
+    let m_ball = Isometry3::new(
+        (m_triangle * local_center).coords,
+        Vector3::new(0.1, 0.2, 0.3),
+    );
+    let prediction = ContactPrediction::new(0.0, 0.0, 0.0);
+    let dispatcher = DefaultContactDispatcher::new();
+    let mut generator = TriangleBallManifoldGenerator::new(flip);
+    let mut manifold = ContactManifold::new();
+
+    let generated = if !flip {
+        generator.generate_contacts(
+            &dispatcher,
+            &m_triangle,
+            &triangle,
+            None,
+            &m_ball,
+            &ball,
+            None,
+            &prediction,
+            &mut manifold,
+        )
+    } else {
+        generator.generate_contacts(
+            &dispatcher,
+            &m_ball,
+            &ball,
+            None,
+            &m_triangle,
+            &triangle,
+            None,
+            &prediction,
+            &mut manifold,
+        )
+    };
+
+    assert!(generated);
+    assert_eq!(manifold.len(), 1);
+    *manifold.deepest_contact().unwrap()
+}
+
+fn check_triangle_contact(
+    local_center: Point3<f64>,
+    local1: Point3<f64>,
+    local_normal: Vector3<f64>,
+    depth: f64,
+    feature: FeatureId,
+) {
+    let m = triangle_pos();
+    let normal = m * Unit::new_normalize(local_normal);
+    let world1 = m * local1;
+    let world2 = m * local_center - *normal * 0.5;
+
+    let c = triangle_contact_with_ball_at(local_center, false);
+    assert_relative_eq!(c.contact.depth, depth, epsilon = 1.0e-10);
+    assert_relative_eq!(*c.contact.normal, *normal, epsilon = 1.0e-10);
+    assert_relative_eq!(c.contact.world1, world1, epsilon = 1.0e-10);
+    assert_relative_eq!(c.contact.world2, world2, epsilon = 1.0e-10);
+    assert_eq!(c.kinematic.feature1(), feature);
+    assert_eq!(c.kinematic.feature2(), FeatureId::Face(0));
+
+    let c = triangle_contact_with_ball_at(local_center, true);
+    assert_relative_eq!(c.contact.depth, depth, epsilon = 1.0e-10);
+    assert_relative_eq!(*c.contact.normal, -*normal, epsilon = 1.0e-10);
+    assert_relative_eq!(c.contact.world1, world2, epsilon = 1.0e-10);
+    assert_relative_eq!(c.contact.world2, world1, epsilon = 1.0e-10);
+    assert_eq!(c.kinematic.feature1(), FeatureId::Face(0));
+    assert_eq!(c.kinematic.feature2(), feature);
+}
+
+#[test]
+fn triangle_ball_face_contact() {
+    check_triangle_contact(
+        Point3::new(0.5, 0.5, 0.3),
+        Point3::new(0.5, 0.5, 0.0),
+        Vector3::z(),
+        0.2,
+        FeatureId::Face(0),
+    );
+    check_triangle_contact(
+        Point3::new(0.5, 0.5, -0.4),
+        Point3::new(0.5, 0.5, 0.0),
+        -Vector3::z(),
+        0.1,
+        FeatureId::Face(1),
+    );
+}
+
+#[test]
+fn triangle_ball_edge_contact() {
+    check_triangle_contact(
+        Point3::new(1.0, -0.3, 0.2),
+        Point3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, -0.3, 0.2),
+        0.5 - 0.13f64.sqrt(),
+        FeatureId::Edge(0),
+    );
+    check_triangle_contact(
+        Point3::new(1.2, 1.2, 0.1),
+        Point3::new(1.0, 1.0, 0.0),
+        Vector3::new(0.2, 0.2, 0.1),
+        0.2,
+        FeatureId::Edge(1),
+    );
+    check_triangle_contact(
+        Point3::new(-0.3, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        -Vector3::x(),
+        0.2,
+        FeatureId::Edge(2),
+    );
+}
+
+#[test]
+fn triangle_ball_vertex_contact() {
+    check_triangle_contact(
+        Point3::new(-0.2, -0.2, 0.2),
+        Point3::origin(),
+        Vector3::new(-1.0, -1.0, 1.0),
+        0.5 - 0.12f64.sqrt(),
+        FeatureId::Vertex(0),
+    );
+    check_triangle_contact(
+        Point3::new(2.3, -0.1, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Vector3::new(0.3, -0.1, 0.0),
+        0.5 - 0.1f64.sqrt(),
+        FeatureId::Vertex(1),
+    );
+}
+
+fn plane_cuboid_manifold(m_cuboid: &Isometry3<f64>, flip: bool) -> ContactManifold<f64> {
+    let plane = Plane::new(Vector3::y_axis());
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.5, 2.0));
+    let m_plane = Isometry3::identity();
+    let prediction = ContactPrediction::new(0.01, 0.0, 0.0);
+    let dispatcher = DefaultContactDispatcher::new();
+    let mut generator = PlaneConvexPolyhedronManifoldGenerator::new(flip);
+    let mut manifold = ContactManifold::new();
+
+    let generated = if !flip {
+        generator.generate_contacts(
+            &dispatcher,
+            &m_plane,
+            &plane,
+            None,
+            m_cuboid,
+            &cuboid,
+            None,
+            &prediction,
+            &mut manifold,
+        )
+    } else {
+        generator.generate_contacts(
+            &dispatcher,
+            m_cuboid,
+            &cuboid,
+            None,
+            &m_plane,
+            &plane,
+            None,
+            &prediction,
+            &mut manifold,
+        )
+    };
+
+    assert!(generated);
+    manifold
+}
+
+#[test]
+fn plane_cuboid_flat_face_contact() {
+    // The cuboid rests on the plane, slightly penetrating it.
+    let m_cuboid = Isometry3::new(Vector3::new(1.0, 0.49, -3.0), Vector3::y() * 0.4);
+
+    for flip in [false, true].iter() {
+        let manifold = plane_cuboid_manifold(&m_cuboid, *flip);
+        assert_eq!(manifold.len(), 4);
+
+        for c in manifold.contacts() {
+            assert_relative_eq!(c.contact.depth, 0.01, epsilon = 1.0e-10);
+            let expected_normal = if *flip { -Vector3::y() } else { Vector3::y() };
+            assert_relative_eq!(*c.contact.normal, expected_normal, epsilon = 1.0e-10);
+        }
+    }
+}
+
+#[test]
+fn plane_cuboid_tilted_edge_contact() {
+    // The cuboid is tilted around the `z` axis so that it rests on one of its bottom edges.
+    let angle = 0.3f64;
+    let height = 0.5 * angle.cos() + 1.0 * angle.sin();
+    let m_cuboid = Isometry3::new(Vector3::new(0.0, height, 0.0), Vector3::z() * angle);
+
+    for flip in [false, true].iter() {
+        let manifold = plane_cuboid_manifold(&m_cuboid, *flip);
+        assert_eq!(manifold.len(), 2);
+
+        for c in manifold.contacts() {
+            assert_relative_eq!(c.contact.depth, 0.0, epsilon = 1.0e-10);
+        }
+    }
+}
+
+fn prism(nsides: usize) -> ConvexHull<f64> {
+    let mut points = Vec::new();
+
+    for i in 0..nsides {
+        let angle = 2.0 * PI * (i as f64) / (nsides as f64);
+        points.push(Point3::new(angle.cos(), -0.5, angle.sin()));
+        points.push(Point3::new(angle.cos(), 0.5, angle.sin()));
+    }
+
+    ConvexHull::try_from_points(&points).unwrap()
+}
+
+fn prism_on_cuboid_manifold(max_contacts: Option<usize>) -> ContactManifold<f64> {
+    let prism = prism(12);
+    let cuboid = Cuboid::new(Vector3::new(5.0, 0.5, 5.0));
+    // The prism is slightly tilted around `z` so that its bottom face penetrates the
+    // cuboid more deeply along `-x`.
+    let m_prism = Isometry3::new(Vector3::new(0.0, 0.99, 0.0), Vector3::z() * -0.005);
+    let m_cuboid = Isometry3::identity();
+    let prediction = ContactPrediction::new(0.1, 0.0, 0.0);
+    let dispatcher = DefaultContactDispatcher::new();
+    let mut generator = ConvexPolyhedronConvexPolyhedronManifoldGenerator::new();
+    let mut manifold = ContactManifold::new();
+
+    generator.set_max_contacts(max_contacts);
+    assert_eq!(generator.max_contacts(), max_contacts);
+    assert!(generator.generate_contacts(
+        &dispatcher,
+        &m_cuboid,
+        &cuboid,
+        None,
+        &m_prism,
+        &prism,
+        None,
+        &prediction,
+        &mut manifold,
+    ));
+
+    manifold
+}
+
+#[test]
+fn convex_manifold_unbounded_by_default() {
+    let manifold = prism_on_cuboid_manifold(None);
+    assert_eq!(manifold.len(), 12);
+}
+
+#[test]
+fn convex_manifold_capped_to_deepest_spread_contacts() {
+    let all = prism_on_cuboid_manifold(None);
+    let capped = prism_on_cuboid_manifold(Some(4));
+    assert_eq!(capped.len(), 4);
+
+    let max_depth = all
+        .contacts()
+        .fold(-f64::MAX, |acc, c| acc.max(c.contact.depth));
+    assert_relative_eq!(capped.deepest_contact().unwrap().contact.depth, max_depth);
+
+    // The kept contacts span the whole bottom face of the prism.
+    let points: Vec<_> = capped.contacts().map(|c| c.contact.world2).collect();
+
+    for (i, pt1) in points.iter().enumerate() {
+        for pt2 in &points[i + 1..] {
+            assert!(na::distance(pt1, pt2) > 1.2);
+        }
+    }
+}
@@ -1,11 +1,22 @@
 mod ball_ball_toi;
 mod ball_triangle_toi;
+mod bounding_volume;
 mod contact;
+mod contact_manifold;
 mod cuboid_ray_cast;
 mod cylinder_cuboid_contact;
+mod distance;
 mod epa3;
 mod first_interference_with_ray;
+mod gjk;
 mod interferences_with_ray;
+mod manifold_generator;
+mod point_query;
+mod ray_cast;
+mod rigid_motion;
+mod shape;
 mod still_objects_toi;
 mod time_of_impact3;
+mod trimesh;
 mod trimesh_trimesh_toi;
+mod utils;
@@ -0,0 +1,303 @@
+use na::{self, DMatrix, Isometry3, Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::query::{self, PointQuery, Ray};
+use ncollide3d::shape::{Ball, Capsule, ConvexHull, Cuboid, HeightField, Rounded, ShapeHandle};
+
+#[test]
+fn ball_sdf_grid_zero_crossing() {
+    let ball = Ball::new(1.0f64);
+    let m = Isometry3::translation(10.0, 0.0, 0.0);
+    let aabb = AABB::new(Point3::new(8.0, -2.0, -2.0), Point3::new(12.0, 2.0, 2.0));
+    let resolution = 9;
+    let step = 0.5;
+
+    let sdf = query::sample_sdf_grid(&ball, &m, &aabb, resolution);
+    assert_eq!(sdf.len(), resolution * resolution * resolution);
+
+    // Check the samples against the exact ball SDF.
+    for (id, dist) in sdf.iter().enumerate() {
+        let i = id % resolution;
+        let j = (id / resolution) % resolution;
+        let k = id / (resolution * resolution);
+        let pt = Vector3::new(i as f64, j as f64, k as f64) * step - Vector3::repeat(2.0);
+        assert_relative_eq!(*dist, pt.norm() - 1.0, epsilon = 1.0e-7);
+    }
+
+    // Locate the zero-crossing along the row passing through the ball center.
+    let row_start = 4 * resolution + 4 * resolution * resolution;
+    let row = &sdf[row_start..row_start + resolution];
+    let center = resolution / 2;
+    let i = (center..resolution).find(|i| row[*i] >= 0.0).unwrap();
+    let t = row[i - 1] / (row[i - 1] - row[i]);
+    let radius = ((i - 1 - center) as f64 + t) * step;
+
+    assert!(row[center] < 0.0);
+    assert!((radius - ball.radius).abs() <= step);
+}
+
+#[test]
+fn small_cuboid_inside_big_cuboid() {
+    let big = Cuboid::new(Vector3::new(2.0f64, 2.0, 2.0));
+    let small = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+    let m1 = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let m2 = m1 * Isometry3::new(Vector3::new(0.5, -0.5, 1.0), Vector3::new(0.4, 0.0, 0.2));
+
+    assert!(query::convex_contains_convex(&m1, &big, &m2, &small).unwrap());
+    assert!(!query::convex_contains_convex(&m2, &small, &m1, &big).unwrap());
+}
+
+#[test]
+fn overlapping_cuboids_do_not_contain_each_other() {
+    let cuboid1 = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let cuboid2 = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(1.2, 0.0, 0.0);
+
+    assert!(!query::convex_contains_convex(&m1, &cuboid1, &m2, &cuboid2).unwrap());
+    assert!(!query::convex_contains_convex(&m2, &cuboid2, &m1, &cuboid1).unwrap());
+}
+
+#[test]
+fn cuboid_inside_ball() {
+    let ball = Ball::new(2.0f64);
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1 = Isometry3::translation(1.0, 1.0, 1.0);
+    let m2 = Isometry3::new(Vector3::new(1.1, 0.9, 1.0), Vector3::new(0.3, 0.2, 0.1));
+
+    // The cuboid diagonal is sqrt(3) ~= 1.73 < 2.
+    assert!(query::convex_contains_convex(&m1, &ball, &m2, &cuboid).unwrap());
+
+    let m2 = Isometry3::translation(1.5, 1.0, 1.0);
+    assert!(!query::convex_contains_convex(&m1, &ball, &m2, &cuboid).unwrap());
+}
+
+#[test]
+fn rounded_shapes_inside_ball_and_hull() {
+    let ball = Ball::new(2.0f64);
+    let capsule = Capsule::new(1.0f64, 0.5);
+    let m = Isometry3::identity();
+
+    assert!(query::convex_contains_convex(&m, &ball, &m, &capsule).unwrap());
+    let m2 = Isometry3::translation(0.0, 0.6, 0.0);
+    assert!(!query::convex_contains_convex(&m, &ball, &m2, &capsule).unwrap());
+
+    let hull = ConvexHull::try_from_points(&[
+        Point3::new(-2.0, -2.0, -2.0),
+        Point3::new(6.0, -2.0, -2.0),
+        Point3::new(-2.0, 6.0, -2.0),
+        Point3::new(-2.0, -2.0, 6.0),
+    ])
+    .unwrap();
+    let small_ball = Ball::new(0.5f64);
+
+    assert!(query::convex_contains_convex(&m, &hull, &m, &small_ball).unwrap());
+    let m2 = Isometry3::translation(0.0, 0.0, -1.6);
+    assert!(!query::convex_contains_convex(&m, &hull, &m2, &small_ball).unwrap());
+}
+
+#[test]
+fn ball_projection_carries_distance() {
+    let ball = Ball::new(1.5f64);
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, 0.2, 0.1));
+
+    for pt in &[
+        Point3::new(4.0, 2.0, -1.0),
+        Point3::new(1.5, 2.0, 3.2),
+        Point3::new(1.0, 2.0, 4.5),
+    ] {
+        for solid in &[true, false] {
+            let proj = ball.project_point(&m, pt, *solid);
+            let distance = proj.distance().unwrap();
+
+            assert_relative_eq!(distance, na::distance(pt, &proj.point), epsilon = 1.0e-10);
+            assert_relative_eq!(
+                distance,
+                ball.distance_to_point(&m, pt, *solid).abs(),
+                epsilon = 1.0e-10
+            );
+        }
+    }
+}
+
+#[test]
+fn cuboid_projection_does_not_carry_distance() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let proj = cuboid.project_point(&Isometry3::identity(), &Point3::new(2.0, 0.0, 0.0), true);
+
+    assert!(proj.distance().is_none());
+}
+
+#[test]
+fn heightfield_point_above_cell() {
+    let heights = DMatrix::from_element(4, 4, 0.5);
+    let heightfield = HeightField::new(heights, Vector3::new(3.0, 2.0, 3.0));
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, -3.0), Vector3::y() * 0.4);
+
+    let pt = Point3::new(0.2, 4.0, -0.7);
+    assert_eq!(heightfield.closest_cell_at_point(&pt), (0, 1));
+
+    let proj = heightfield.project_point(&m, &(m * pt), true);
+    assert!(!proj.is_inside);
+    assert_relative_eq!(
+        proj.point,
+        m * Point3::new(0.2, 1.0, -0.7),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        heightfield.distance_to_point(&m, &(m * pt), true),
+        3.0,
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn heightfield_point_outside_footprint() {
+    let heights = DMatrix::from_element(4, 4, 0.0);
+    let heightfield = HeightField::new(heights, Vector3::new(2.0, 1.0, 2.0));
+
+    // Beyond the `x = 1` edge of the heightfield.
+    let pt = Point3::new(3.0, 0.5, 0.2);
+    assert_eq!(heightfield.closest_cell_at_point(&pt), (1, 2));
+
+    let proj = heightfield.project_point(&Isometry3::identity(), &pt, true);
+    assert_relative_eq!(proj.point, Point3::new(1.0, 0.0, 0.2), epsilon = 1.0e-7);
+}
+
+#[test]
+fn heightfield_point_projection_matches_brute_force() {
+    let heights = DMatrix::from_fn(6, 5, |i, j| ((i * 7 + j * 3) % 5) as f64 * 0.3);
+    let heightfield = HeightField::new(heights, Vector3::new(2.0, 1.5, 3.0));
+    let m = Isometry3::identity();
+
+    for k in 0..50 {
+        let t = k as f64;
+        let pt = Point3::new(
+            (t * 0.37).sin() * 1.5,
+            (t * 0.53).cos() * 1.2,
+            (t * 0.71).sin() * 2.0,
+        );
+
+        let expected = heightfield
+            .triangles()
+            .map(|tri| na::distance(&pt, &tri.project_point(&m, &pt, false).point))
+            .fold(std::f64::MAX, f64::min);
+
+        let proj = heightfield.project_point(&m, &pt, false);
+        assert_relative_eq!(na::distance(&pt, &proj.point), expected, epsilon = 1.0e-7);
+    }
+}
+
+/// Signed distance from `pt`, expressed in the box local-space, to the boundary of a box
+/// with half-extents `he` rounded by `radius`.
+fn rounded_box_sdf(he: &Vector3<f64>, radius: f64, pt: &Point3<f64>) -> f64 {
+    let q = pt.coords.abs() - he;
+    let outside = q.map(|e| e.max(0.0)).norm();
+    let inside = q.max().min(0.0);
+    outside + inside - radius
+}
+
+#[test]
+fn rounded_cuboid_project_point_on_face_and_corner() {
+    let shape = Rounded::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)), 0.5);
+    let m = Isometry3::identity();
+
+    let face = shape.project_point(&m, &Point3::new(3.0, 0.2, 0.1), true);
+    assert!(!face.is_inside);
+    assert_relative_eq!(face.point, Point3::new(1.5, 0.2, 0.1), epsilon = 1.0e-10);
+
+    let corner = shape.project_point(&m, &Point3::new(3.0, 3.0, 3.0), true);
+    let expected = Point3::new(1.0, 1.0, 1.0) + Vector3::repeat(1.0).normalize() * 0.5;
+    assert!(!corner.is_inside);
+    assert_relative_eq!(corner.point, expected, epsilon = 1.0e-10);
+}
+
+#[test]
+fn rounded_cuboid_contains_points_of_the_margin() {
+    let shape = Rounded::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)), 0.5);
+    let m = Isometry3::identity();
+    let margin_pt = Point3::new(1.2, 0.0, 0.0);
+    let core_pt = Point3::new(0.8, 0.0, 0.0);
+
+    assert!(shape.contains_point(&m, &margin_pt));
+    assert!(shape.contains_point(&m, &core_pt));
+    assert!(!shape.contains_point(&m, &Point3::new(1.4, 1.4, 1.4)));
+
+    let solid = shape.project_point(&m, &margin_pt, true);
+    assert!(solid.is_inside);
+    assert_eq!(solid.point, margin_pt);
+
+    let hollow = shape.project_point(&m, &margin_pt, false);
+    assert!(hollow.is_inside);
+    assert_relative_eq!(hollow.point, Point3::new(1.5, 0.0, 0.0), epsilon = 1.0e-10);
+
+    let hollow = shape.project_point(&m, &core_pt, false);
+    assert!(hollow.is_inside);
+    assert_relative_eq!(hollow.point, Point3::new(1.5, 0.0, 0.0), epsilon = 1.0e-10);
+}
+
+#[test]
+fn rounded_cuboid_distance_matches_offset_surface() {
+    let he = Vector3::new(1.0, 2.0, 0.5);
+    let radius = 0.3;
+    let shape = Rounded::new(Cuboid::new(he), radius);
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.2, 0.4, -0.3));
+
+    for i in 0..9 {
+        for j in 0..9 {
+            for k in 0..9 {
+                let local_pt = Point3::new(
+                    -2.0 + i as f64 * 0.5 + 0.01,
+                    -3.0 + j as f64 * 0.75 + 0.02,
+                    -1.5 + k as f64 * 0.375 + 0.03,
+                );
+                let pt = m * local_pt;
+                let sdf = rounded_box_sdf(&he, radius, &local_pt);
+
+                assert_relative_eq!(
+                    shape.distance_to_point(&m, &pt, false),
+                    sdf,
+                    epsilon = 1.0e-8
+                );
+                assert_relative_eq!(
+                    shape.distance_to_point(&m, &pt, true),
+                    sdf.max(0.0),
+                    epsilon = 1.0e-8
+                );
+                assert_eq!(shape.contains_point(&m, &pt), sdf <= 0.0);
+            }
+        }
+    }
+}
+
+#[test]
+fn rounded_cuboid_as_a_shape() {
+    let shape = ShapeHandle::new(Rounded::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)), 0.5));
+    let m = Isometry3::translation(1.0, 0.0, 0.0);
+
+    let aabb = shape.aabb(&m);
+    assert_relative_eq!(aabb.mins, Point3::new(-0.5, -1.5, -1.5), epsilon = 1.0e-10);
+    assert_relative_eq!(aabb.maxs, Point3::new(2.5, 1.5, 1.5), epsilon = 1.0e-10);
+    assert!(shape.bounding_sphere(&m).radius() >= 3.0f64.sqrt() + 0.5);
+
+    let support = shape
+        .as_support_map()
+        .unwrap()
+        .support_point(&m, &Vector3::new(1.0, 1.0, 1.0));
+    let expected = Point3::new(2.0, 1.0, 1.0) + Vector3::repeat(1.0).normalize() * 0.5;
+    assert_relative_eq!(support, expected, epsilon = 1.0e-10);
+
+    let ray = Ray::new(Point3::new(10.0, 0.2, 0.1), -Vector3::x());
+    let toi = shape
+        .as_ray_cast()
+        .unwrap()
+        .toi_with_ray(&m, &ray, 100.0, true);
+    assert_relative_eq!(toi.unwrap(), 7.5, epsilon = 1.0e-6);
+
+    let other = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m2 = Isometry3::translation(5.0, 0.0, 0.0);
+    assert_relative_eq!(
+        query::distance(&m, &*shape, &m2, &other),
+        1.5,
+        epsilon = 1.0e-6
+    );
+}
@@ -0,0 +1,486 @@
+use na::{DMatrix, Isometry3, Point3, Translation3, Unit, UnitQuaternion, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::query::{self, PointQuery, Ray, RayCast, RayIntersection};
+use ncollide3d::shape::{
+    Ball, Capsule, Compound, Cuboid, FeatureId, HeightField, Shape, ShapeHandle, TriMesh, Triangle,
+};
+use ncollide3d::transformation::ToTriMesh;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn triangle() -> Triangle<f64> {
+    // Counter-clockwise when seen from +z, so the normal points toward +z.
+    Triangle::new(
+        Point3::new(-1.0, -1.0, 0.0),
+        Point3::new(1.0, -1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    )
+}
+
+#[test]
+fn triangle_ray_cast_backface_culling() {
+    let tri = triangle();
+    let m = Isometry3::identity();
+    let front = Ray::new(Point3::new(0.0, 0.0, 2.0), -Vector3::z());
+    let back = Ray::new(Point3::new(0.0, 0.0, -2.0), Vector3::z());
+
+    let hit = tri
+        .toi_and_normal_with_ray_culled(&m, &front, std::f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.0);
+    assert_relative_eq!(hit.normal, Vector3::z());
+
+    assert!(tri
+        .toi_and_normal_with_ray_culled(&m, &back, std::f64::MAX, true)
+        .is_none());
+
+    let hit = tri
+        .toi_and_normal_with_ray_culled(&m, &back, std::f64::MAX, false)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 2.0);
+    assert_relative_eq!(hit.normal, -Vector3::z());
+    assert!(tri.toi_with_ray(&m, &back, std::f64::MAX, true).is_some());
+}
+
+#[test]
+fn triangle_ray_cast_edge_on_is_a_miss_with_culling() {
+    let tri = triangle();
+    let m = Isometry3::identity();
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+
+    assert!(tri
+        .toi_and_normal_with_ray_culled(&m, &ray, std::f64::MAX, true)
+        .is_none());
+}
+
+#[test]
+fn ray_passing_near_ball() {
+    let ball = Ball::new(1.0f64);
+    let m = Isometry3::translation(5.0, 3.0, 0.0);
+    let ray = Ray::new(Point3::origin(), Vector3::x());
+
+    let (pt_ray, pt_ball, dist) = query::closest_point_ray_shape(&ray, &m, &ball);
+
+    // The GJK converges on the distance much faster than on the witness points.
+    assert_relative_eq!(dist, 2.0, epsilon = 1.0e-6);
+    assert_relative_eq!(pt_ray, Point3::new(5.0, 0.0, 0.0), epsilon = 1.0e-3);
+    assert_relative_eq!(pt_ball, Point3::new(5.0, 2.0, 0.0), epsilon = 1.0e-3);
+}
+
+#[test]
+fn ray_pointing_away_from_ball() {
+    // The ball is behind the ray origin: the closest point on the ray is its origin.
+    let ball = Ball::new(1.0f64);
+    let m = Isometry3::translation(-5.0, 0.0, 0.0);
+    let ray = Ray::new(Point3::new(0.0, 1.0, 0.0), Vector3::x());
+
+    let (pt_ray, pt_ball, dist) = query::closest_point_ray_shape(&ray, &m, &ball);
+
+    assert_relative_eq!(pt_ray, ray.origin, epsilon = 1.0e-6);
+    assert_relative_eq!(dist, 26.0f64.sqrt() - 1.0, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        na::distance(&pt_ball, &m.translation.vector.into()),
+        1.0,
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn ray_hitting_ball() {
+    let ball = Ball::new(1.0f64);
+    let m = Isometry3::translation(5.0, 0.0, 0.0);
+    let ray = Ray::new(Point3::origin(), Vector3::x());
+
+    let (pt_ray, pt_ball, dist) = query::closest_point_ray_shape(&ray, &m, &ball);
+
+    assert_eq!(dist, 0.0);
+    assert_relative_eq!(pt_ray, Point3::new(4.0, 0.0, 0.0), epsilon = 1.0e-6);
+    assert_relative_eq!(pt_ball, pt_ray);
+}
+
+#[test]
+fn ray_cast_on_heightfield_slope() {
+    // The heightfield is the plane `y = 0.5 * x + 1` for `x, z` in `[-2, 2]`.
+    let heights = DMatrix::from_fn(5, 5, |_, j| j as f64 * 0.5);
+    let heightfield = HeightField::new(heights, Vector3::new(4.0, 1.0, 4.0));
+    let m = Isometry3::new(Vector3::new(10.0, -3.0, 5.0), Vector3::y() * 0.3);
+
+    // Starts above the heightfield and descends onto the slope.
+    let ls_ray = Ray::new(Point3::new(-1.5, 5.0, 0.3), Vector3::new(1.0, -1.0, 0.2));
+    let ray = ls_ray.transform_by(&m);
+
+    // Intersection with the plane: 5 - t = 0.5 * (-1.5 + t) + 1.
+    let toi = 4.75 / 1.5;
+    let normal = Vector3::new(-0.5, 1.0, 0.0).normalize();
+
+    let inter = heightfield
+        .toi_and_normal_with_ray(&m, &ray, std::f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, toi, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, m * normal, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        ray.point_at(inter.toi),
+        m * Point3::new(-1.5 + toi, 5.0 - toi, 0.3 + 0.2 * toi),
+        epsilon = 1.0e-7
+    );
+
+    assert!(heightfield
+        .toi_and_normal_with_ray(&m, &ray, toi - 0.01, true)
+        .is_none());
+}
+
+#[test]
+fn ray_cast_on_heightfield_matches_triangles() {
+    let heights = DMatrix::from_fn(9, 7, |i, j| {
+        ((i as f64) * 0.7).sin() + ((j as f64) * 1.3).cos() * 0.5
+    });
+    let heightfield = HeightField::new(heights, Vector3::new(6.0, 1.0, 8.0));
+    let m = Isometry3::new(Vector3::new(-2.0, 1.0, 3.0), Vector3::new(0.1, 0.4, -0.2));
+    let id = Isometry3::identity();
+
+    let rays = [
+        Ray::new(Point3::new(-4.0, 3.0, -5.0), Vector3::new(1.0, -0.4, 1.2)),
+        Ray::new(Point3::new(4.0, 2.5, 5.0), Vector3::new(-1.0, -0.3, -0.7)),
+        Ray::new(Point3::new(-4.0, 0.0, 1.0), Vector3::new(1.0, 0.05, 0.0)),
+        Ray::new(Point3::new(0.3, 5.0, -0.2), Vector3::new(0.0, -1.0, 0.0)),
+        Ray::new(Point3::new(0.0, 3.0, 0.0), Vector3::new(0.2, 1.0, 0.1)),
+    ];
+
+    for ls_ray in &rays {
+        let ray = ls_ray.transform_by(&m);
+        let expected = heightfield
+            .triangles()
+            .filter_map(|tri| tri.toi_with_ray(&id, ls_ray, std::f64::MAX, true))
+            .fold(None, |best: Option<f64>, toi| {
+                Some(best.map_or(toi, |best| best.min(toi)))
+            });
+        let toi = heightfield.toi_with_ray(&m, &ray, std::f64::MAX, true);
+
+        match (toi, expected) {
+            (Some(toi), Some(expected)) => assert_relative_eq!(toi, expected, epsilon = 1.0e-7),
+            (None, None) => {}
+            _ => panic!("Ray cast mismatch: {:?} != {:?}", toi, expected),
+        }
+    }
+}
+
+// A cuboid that counts how many times it has been ray-cast, and how many of those casts hit.
+#[derive(Clone)]
+struct CountingCuboid {
+    cuboid: Cuboid<f64>,
+    casts: Arc<AtomicUsize>,
+    hits: Arc<AtomicUsize>,
+}
+
+impl CountingCuboid {
+    fn new(casts: &Arc<AtomicUsize>, hits: &Arc<AtomicUsize>) -> Self {
+        CountingCuboid {
+            cuboid: Cuboid::new(Vector3::repeat(0.5)),
+            casts: casts.clone(),
+            hits: hits.clone(),
+        }
+    }
+}
+
+impl RayCast<f64> for CountingCuboid {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry3<f64>,
+        ray: &Ray<f64>,
+        max_toi: f64,
+        solid: bool,
+    ) -> Option<RayIntersection<f64>> {
+        let _ = self.casts.fetch_add(1, Ordering::SeqCst);
+        let res = self.cuboid.toi_and_normal_with_ray(m, ray, max_toi, solid);
+
+        if res.is_some() {
+            let _ = self.hits.fetch_add(1, Ordering::SeqCst);
+        }
+
+        res
+    }
+}
+
+impl Shape<f64> for CountingCuboid {
+    fn aabb(&self, m: &Isometry3<f64>) -> AABB<f64> {
+        self.cuboid.aabb(m)
+    }
+
+    fn as_ray_cast(&self) -> Option<&dyn RayCast<f64>> {
+        Some(self)
+    }
+
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry3<f64>,
+        _: Option<&[f64]>,
+        _: &Unit<Vector3<f64>>,
+    ) -> bool {
+        false
+    }
+}
+
+#[test]
+fn compound_ray_cast_visits_nearest_box_only() {
+    let casts = Arc::new(AtomicUsize::new(0));
+    let hits = Arc::new(AtomicUsize::new(0));
+    let shapes = (0..16)
+        .map(|i| {
+            let shape = CountingCuboid::new(&casts, &hits);
+            let pos = Isometry3::translation(i as f64 * 2.0, 0.0, 0.0);
+            (pos, ShapeHandle::new(shape))
+        })
+        .collect();
+    let compound = Compound::new(shapes);
+
+    let m = Isometry3::translation(1.0, 0.0, 0.0);
+    let ray = Ray::new(Point3::new(-10.0, 0.0, 0.0), Vector3::x());
+
+    let hit = compound
+        .toi_and_normal_with_ray(&m, &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 10.5);
+    assert_relative_eq!(hit.normal, -Vector3::x());
+    assert_eq!(casts.load(Ordering::SeqCst), 1);
+
+    casts.store(0, Ordering::SeqCst);
+    let toi = compound.toi_with_ray(&m, &ray, f64::MAX, true).unwrap();
+    assert_relative_eq!(toi, 10.5);
+    assert_eq!(casts.load(Ordering::SeqCst), 1);
+
+    // The nearest box is out of reach.
+    casts.store(0, Ordering::SeqCst);
+    assert!(compound.toi_with_ray(&m, &ray, 10.0, true).is_none());
+    assert_eq!(casts.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn compound_ray_cast_clamps_children_to_best_hit() {
+    let casts = Arc::new(AtomicUsize::new(0));
+    let hits = Arc::new(AtomicUsize::new(0));
+
+    // The AABB of the rotated box starts before the hit on the axis-aligned box,
+    // but the ray only reaches the rotated box itself after that hit.
+    let rot = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), std::f64::consts::FRAC_PI_4);
+    let near = Isometry3::identity();
+    let rotated = Isometry3::from_parts(Translation3::new(0.1, 0.0, 0.0), rot);
+    let shapes = vec![
+        (near, ShapeHandle::new(CountingCuboid::new(&casts, &hits))),
+        (
+            rotated,
+            ShapeHandle::new(CountingCuboid::new(&casts, &hits)),
+        ),
+    ];
+    let compound = Compound::new(shapes);
+    let ray = Ray::new(Point3::new(-10.0, 0.4, 0.0), Vector3::x());
+
+    let hit = compound
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(hit.toi, 9.5);
+    assert_eq!(casts.load(Ordering::SeqCst), 2);
+    // The rotated box is cast with a max_toi clamped to the first hit, so it misses.
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+    casts.store(0, Ordering::SeqCst);
+    hits.store(0, Ordering::SeqCst);
+    let toi = compound
+        .toi_with_ray(&Isometry3::identity(), &ray, f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(toi, 9.5);
+    assert_eq!(casts.load(Ordering::SeqCst), 2);
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn ray_intersection_point_on_mesh() {
+    let mesh: TriMesh<f64> = Cuboid::new(Vector3::new(1.0, 2.0, 3.0))
+        .to_trimesh(())
+        .into();
+    let m = Isometry3::new(Vector3::new(1.0, -1.0, 2.0), Vector3::new(0.1, 0.2, -0.3));
+    let ray = Ray::new(Point3::new(10.0, -0.5, 2.3), Vector3::new(-2.0, 0.1, 0.0));
+
+    let inter = mesh
+        .toi_and_normal_with_ray(&m, &ray, std::f64::MAX, true)
+        .unwrap();
+    let pt = inter.point(&ray);
+
+    assert_eq!(pt, ray.origin + ray.dir * inter.toi);
+    // The point lies on the surface of the box.
+    let local_pt = m.inverse_transform_point(&pt);
+    let max_ratio = (local_pt.x.abs() / 1.0)
+        .max(local_pt.y.abs() / 2.0)
+        .max(local_pt.z.abs() / 3.0);
+    assert_relative_eq!(max_ratio, 1.0, epsilon = 1.0e-7);
+}
+
+#[test]
+fn ray_cast_on_capsule_side() {
+    let capsule = Capsule::new(1.0f64, 0.5);
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::x() * 0.4);
+
+    let ls_ray = Ray::new(Point3::new(-3.0, 0.7, 0.0), Vector3::new(2.0, 0.0, 0.0));
+    let ray = ls_ray.transform_by(&m);
+
+    let inter = capsule
+        .toi_and_normal_with_ray(&m, &ray, std::f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 1.25, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, m * -Vector3::x(), epsilon = 1.0e-7);
+
+    // From the inside, the non-solid cast exits through the opposite side.
+    let ls_ray = Ray::new(Point3::new(0.0, 0.7, 0.0), Vector3::x());
+    let ray = ls_ray.transform_by(&m);
+    let inter = capsule
+        .toi_and_normal_with_ray(&m, &ray, std::f64::MAX, false)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 0.5, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, m * -Vector3::x(), epsilon = 1.0e-7);
+
+    let toi = capsule.toi_with_ray(&m, &ray, std::f64::MAX, true).unwrap();
+    assert_relative_eq!(toi, 0.0);
+
+    // Parallel to the side but outside of the capsule.
+    let ls_ray = Ray::new(Point3::new(0.6, -3.0, 0.0), Vector3::y());
+    let ray = ls_ray.transform_by(&m);
+    assert!(capsule
+        .toi_with_ray(&m, &ray, std::f64::MAX, true)
+        .is_none());
+}
+
+#[test]
+fn ray_cast_on_capsule_cap() {
+    let capsule = Capsule::new(1.0f64, 0.5);
+
+    // Hits the top hemisphere away from its pole.
+    let dir = Vector3::new(0.0, -1.0, 1.0).normalize();
+    let target = Point3::new(0.0, 1.0, 0.0) + Vector3::new(0.0, 1.0, -1.0).normalize() * 0.5;
+    let ray = Ray::new(target - dir * 2.0, dir);
+
+    let inter = capsule
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, std::f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 2.0, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        inter.normal,
+        Vector3::new(0.0, 1.0, -1.0).normalize(),
+        epsilon = 1.0e-7
+    );
+
+    // Misses the capsule just above the rim of the cylinder.
+    let ray = Ray::new(Point3::new(-2.0, 1.49, 0.49), Vector3::x());
+    assert!(capsule
+        .toi_with_ray(&Isometry3::identity(), &ray, std::f64::MAX, true)
+        .is_none());
+
+    // Limited by `max_toi`.
+    let ray = Ray::new(Point3::new(0.0, 0.0, -3.0), Vector3::z());
+    assert!(capsule
+        .toi_with_ray(&Isometry3::identity(), &ray, 2.0, true)
+        .is_none());
+}
+
+#[test]
+fn ray_cast_on_capsule_along_axis() {
+    let capsule = Capsule::new(1.0f64, 0.5);
+
+    let ray = Ray::new(Point3::new(0.0, 4.0, 0.0), -Vector3::y());
+    let inter = capsule
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, std::f64::MAX, true)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 2.5, epsilon = 1.0e-7);
+    assert_relative_eq!(inter.normal, Vector3::y(), epsilon = 1.0e-7);
+
+    // Off-axis but parallel to it, through the cap.
+    let ray = Ray::new(Point3::new(0.3, -4.0, 0.0), Vector3::y());
+    let inter = capsule
+        .toi_and_normal_with_ray(&Isometry3::identity(), &ray, std::f64::MAX, false)
+        .unwrap();
+    assert_relative_eq!(inter.toi, 2.6, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        inter.normal,
+        Vector3::new(0.3, -0.4, 0.0).normalize(),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn ray_between_points_toi_is_segment_fraction() {
+    let cuboid = Cuboid::new(Vector3::repeat(1.0f64));
+    let m = Isometry3::translation(3.0, 0.0, 0.0);
+    let from = Point3::new(0.0, 0.5, 0.0);
+    let to = Point3::new(8.0, 0.5, 0.0);
+    let ray = Ray::between_points(from, to);
+
+    assert_eq!(ray.point_at(1.0), to);
+
+    // The ray enters the cuboid at `x = 2`, i.e., after a quarter of the segment.
+    let toi = cuboid.toi_with_ray(&m, &ray, 1.0, true).unwrap();
+    assert_relative_eq!(toi, 0.25, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        ray.point_at(toi),
+        Point3::new(2.0, 0.5, 0.0),
+        epsilon = 1.0e-10
+    );
+
+    // The segment stops before reaching the cuboid.
+    let short = Ray::between_points(from, Point3::new(1.5, 0.5, 0.0));
+    assert!(cuboid.toi_with_ray(&m, &short, 1.0, true).is_none());
+}
+
+#[test]
+fn ray_from_origin_dir_is_normalized() {
+    let cuboid = Cuboid::new(Vector3::repeat(1.0f64));
+    let m = Isometry3::translation(3.0, 0.0, 0.0);
+    let ray = Ray::from_origin_dir(Point3::new(0.0, 0.5, 0.0), Vector3::new(8.0, 0.0, 0.0));
+
+    assert_relative_eq!(ray.dir, Vector3::x(), epsilon = 1.0e-10);
+
+    // The time of impact is the distance traveled.
+    let toi = cuboid.toi_with_ray(&m, &ray, 100.0, true).unwrap();
+    assert_relative_eq!(toi, 2.0, epsilon = 1.0e-10);
+}
+
+fn rays() -> Vec<Ray<f64>> {
+    vec![
+        // Inside, pointing toward a face and away from every face.
+        Ray::new(Point3::new(1.0, 2.0, 0.5), Vector3::x()),
+        Ray::new(Point3::new(1.5, 1.5, 0.0), -Vector3::y()),
+        // Outside, pointing toward the box and away from it.
+        Ray::new(Point3::new(5.0, 2.0, 0.0), -Vector3::x()),
+        Ray::new(Point3::new(1.0, -3.0, 0.0), -Vector3::y()),
+    ]
+}
+
+#[test]
+fn ray_origin_inside_cuboid() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 0.0), Vector3::z() * 0.3);
+    let expected = [true, true, false, false];
+
+    for (ray, expected) in rays().iter().zip(expected.iter()) {
+        assert_eq!(cuboid.ray_origin_inside(&m, ray), *expected);
+        assert_eq!(
+            cuboid.ray_origin_inside(&m, ray),
+            cuboid.contains_point(&m, &ray.origin)
+        );
+
+        let shape: &dyn Shape<f64> = &cuboid;
+        assert_eq!(shape.ray_origin_inside(&m, ray), *expected);
+    }
+}
+
+#[test]
+fn ray_origin_inside_compound_uses_ray_cast() {
+    let cuboid = ShapeHandle::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)));
+    let compound = Compound::new(vec![(Isometry3::identity(), cuboid)]);
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 0.0), Vector3::z() * 0.3);
+
+    for ray in rays() {
+        assert_eq!(
+            compound.ray_origin_inside(&m, &ray),
+            compound.contains_point(&m, &ray.origin)
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use na::{Isometry3, Point3, UnitQuaternion, Vector3};
+use ncollide3d::interpolation::{
+    ArcInterpolatedRigidMotion, ConstantLinearVelocityRigidMotion, ConstantVelocityRigidMotion,
+    EasedInterpolatedRigidMotion, InterpolatedRigidMotion, RigidMotion, RigidMotionComposition,
+};
+use ncollide3d::query::{self, DefaultTOIDispatcher};
+use ncollide3d::shape::{Ball, Cuboid};
+use std::f64::consts::FRAC_PI_2;
+
+#[test]
+fn sample_constant_linear_velocity_motion() {
+    let start = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let velocity = Vector3::new(-1.0, 0.5, 2.0);
+    let motion = ConstantLinearVelocityRigidMotion::new(0.5f64, start, velocity);
+
+    let samples: Vec<_> = motion.sample(1.0, 3.0, 5).collect();
+    let expected_times = [1.0, 1.5, 2.0, 2.5, 3.0];
+
+    assert_eq!(samples.len(), 5);
+
+    for ((t, pos), expected_t) in samples.iter().zip(expected_times.iter()) {
+        assert_relative_eq!(*t, *expected_t);
+        assert_relative_eq!(
+            pos.translation.vector,
+            start.translation.vector + velocity * (*expected_t - 0.5),
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(pos.rotation, start.rotation);
+    }
+
+    let single: Vec<_> = motion.sample(1.0, 3.0, 1).collect();
+    assert_eq!(single.len(), 1);
+    assert_eq!(single[0].0, 1.0);
+    assert_eq!(motion.sample(1.0, 3.0, 0).count(), 0);
+}
+
+#[test]
+fn arc_interpolation_stays_on_the_arc() {
+    let pivot = Point3::new(1.0, 2.0, 3.0);
+    let start = Isometry3::new(Vector3::new(4.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let end = Isometry3::new(Vector3::new(1.0, 2.0, 6.0), Vector3::new(-0.3, 0.2, 0.0));
+    let motion = ArcInterpolatedRigidMotion::new(start, end, pivot);
+
+    assert_relative_eq!(motion.position_at_time(0.0), start, epsilon = 1.0e-10);
+    assert_relative_eq!(motion.position_at_time(1.0), end, epsilon = 1.0e-10);
+
+    let mid = motion.position_at_time(0.5);
+    let mid_pt = Point3::from(mid.translation.vector);
+    assert_relative_eq!(na::distance(&mid_pt, &pivot), 3.0, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        mid_pt,
+        pivot + Vector3::new(1.0, 0.0, 1.0).normalize() * 3.0,
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        mid.rotation,
+        start.rotation.slerp(&end.rotation, 0.5),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn arc_interpolation_falls_back_to_lerp() {
+    let pivot = Point3::origin();
+    let start = Isometry3::translation(2.0, 0.0, 0.0);
+    let end = Isometry3::translation(-2.0, 0.0, 0.0);
+
+    // Antipodal positions.
+    let motion = ArcInterpolatedRigidMotion::new(start, end, pivot);
+    assert_relative_eq!(
+        motion.position_at_time(0.25),
+        Isometry3::translation(1.0, 0.0, 0.0),
+        epsilon = 1.0e-10
+    );
+
+    // Start position at the pivot.
+    let motion = ArcInterpolatedRigidMotion::new(Isometry3::identity(), end, pivot);
+    assert_relative_eq!(
+        motion.position_at_time(0.25),
+        Isometry3::translation(-0.5, 0.0, 0.0),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn static_motion_is_time_invariant() {
+    let pose = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+
+    for t in &[-10.0, 0.0, 0.5, 1.0, 1.0e6] {
+        assert_eq!(pose.position_at_time(*t), pose);
+    }
+
+    let shift = Vector3::new(0.0, 1.0, 0.0);
+    let shifted = pose.prepend_translation(shift);
+    for t in &[0.0, 2.0] {
+        assert_relative_eq!(
+            shifted.position_at_time(*t),
+            pose * na::Translation3::from(shift)
+        );
+    }
+}
+
+#[test]
+fn static_motion_nonlinear_toi() {
+    let dispatcher = DefaultTOIDispatcher;
+    let cuboid = Cuboid::new(Vector3::repeat(1.0f64));
+    let ball = Ball::new(0.5f64);
+
+    let static_pose = Isometry3::translation(5.0, 0.0, 0.0);
+    let zero_velocity = ConstantVelocityRigidMotion::new(
+        0.0,
+        static_pose,
+        Point3::origin(),
+        Vector3::zeros(),
+        Vector3::zeros(),
+    );
+    let moving = ConstantVelocityRigidMotion::new(
+        0.0,
+        Isometry3::identity(),
+        Point3::origin(),
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::zeros(),
+    );
+
+    let toi_static = query::nonlinear_time_of_impact(
+        &dispatcher,
+        &moving,
+        &ball,
+        &static_pose,
+        &cuboid,
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .unwrap();
+    let toi_zero_velocity = query::nonlinear_time_of_impact(
+        &dispatcher,
+        &moving,
+        &ball,
+        &zero_velocity,
+        &cuboid,
+        10.0,
+        0.0,
+    )
+    .unwrap()
+    .unwrap();
+
+    // The ball hits the face at x = 4.
+    assert_relative_eq!(toi_static.toi, 1.75, epsilon = 1.0e-3);
+    assert_relative_eq!(toi_static.toi, toi_zero_velocity.toi, epsilon = 1.0e-6);
+}
+
+#[test]
+fn eased_interpolation_with_identity_easing_is_lerp_slerp() {
+    let start = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let end = Isometry3::new(Vector3::new(-2.0, 0.5, 4.0), Vector3::new(-0.3, 0.2, 1.0));
+    let eased = EasedInterpolatedRigidMotion::new(start, end, |t| t, |t| t);
+    let interpolated = InterpolatedRigidMotion::new(start, end);
+
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        assert_relative_eq!(
+            eased.position_at_time(t),
+            interpolated.position_at_time(t),
+            epsilon = 1.0e-10
+        );
+    }
+}
+
+#[test]
+fn eased_interpolation_with_separate_easings() {
+    let start = Isometry3::identity();
+    let end = Isometry3::new(Vector3::new(4.0, 0.0, 0.0), Vector3::z() * FRAC_PI_2);
+    // Quadratic easing for the translation, and a rotation that completes at `t = 1/3`.
+    let eased = EasedInterpolatedRigidMotion::new(start, end, |t| t * t, |t| t * 3.0);
+
+    let pos = eased.position_at_time(0.5);
+    assert_relative_eq!(pos.translation.vector, Vector3::new(1.0, 0.0, 0.0));
+    assert_relative_eq!(pos.rotation, end.rotation, epsilon = 1.0e-10);
+
+    let pos = eased.position_at_time(0.25);
+    let expected_rot = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), FRAC_PI_2 * 0.75);
+    assert_relative_eq!(pos.translation.vector, Vector3::new(0.25, 0.0, 0.0));
+    assert_relative_eq!(pos.rotation, expected_rot, epsilon = 1.0e-10);
+}
+
+#[test]
+fn eased_interpolation_clamps_the_eased_parameters() {
+    let start = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let end = Isometry3::new(Vector3::new(-2.0, 0.5, 4.0), Vector3::new(-0.3, 0.2, 1.0));
+    let eased = EasedInterpolatedRigidMotion::new(start, end, |t| t + 10.0, |t| t - 10.0);
+
+    let pos = eased.position_at_time(0.5);
+    assert_relative_eq!(pos.translation, end.translation, epsilon = 1.0e-10);
+    assert_relative_eq!(pos.rotation, start.rotation, epsilon = 1.0e-10);
+}
+
+#[test]
+fn spinning_motion_max_point_velocity() {
+    let linvel = Vector3::new(1.0, 2.0, -2.0);
+    let spinning = ConstantVelocityRigidMotion::new(
+        0.0,
+        Isometry3::identity(),
+        Point3::origin(),
+        linvel,
+        Vector3::new(0.0, 0.0, 4.0),
+    );
+
+    assert_relative_eq!(spinning.max_point_velocity(0.0), 3.0);
+    assert_relative_eq!(spinning.max_point_velocity(1.0), 7.0);
+    assert!(spinning.max_point_velocity(2.0) > spinning.max_point_velocity(1.0));
+
+    let translating = ConstantVelocityRigidMotion::new(
+        0.0,
+        Isometry3::identity(),
+        Point3::new(1.0, 0.0, 0.0),
+        linvel,
+        Vector3::zeros(),
+    );
+
+    assert_relative_eq!(translating.max_point_velocity(10.0), linvel.norm());
+    assert_eq!(Isometry3::<f64>::identity().max_point_velocity(10.0), 0.0);
+}
+
+#[test]
+fn interpolated_motion_max_point_velocity_bounds_displacements() {
+    let start = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.1, 0.0, 0.0));
+    let end = Isometry3::new(Vector3::new(0.0, 2.0, 1.0), Vector3::new(0.0, 1.2, 0.3));
+    let motion = InterpolatedRigidMotion::new(start, end);
+    let shifted = motion.prepend_translation(Vector3::new(0.0, 0.5, 0.0));
+    let radius = 1.5;
+    let pt = Point3::new(0.0, 0.0, 1.5);
+    let n = 100;
+
+    for m in [&motion as &dyn RigidMotion<f64>, &shifted].iter() {
+        let bound = m.max_point_velocity(radius);
+        let dt = 1.0 / n as f64;
+
+        for i in 0..n {
+            let t = i as f64 * dt;
+            let p0 = m.position_at_time(t) * pt;
+            let p1 = m.position_at_time(t + dt) * pt;
+            assert!(na::distance(&p0, &p1) <= bound * dt + 1.0e-10);
+        }
+    }
+}
+
+#[test]
+fn constant_linear_velocity_reversed() {
+    let start = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let motion = ConstantLinearVelocityRigidMotion::new(0.5, start, Vector3::new(2.0, -1.0, 0.5));
+    let reversed = motion.reversed();
+
+    assert_eq!(reversed.start, motion.start);
+    assert_eq!(reversed.velocity, -motion.velocity);
+
+    for i in 0..=10 {
+        let t = -3.0 + i as f64 * 0.6;
+        assert_relative_eq!(
+            reversed.position_at_time(t),
+            motion.position_at_time(-t),
+            epsilon = 1.0e-10
+        );
+    }
+}
+
+#[test]
+fn rigid_motion_valid_interval_unbounded() {
+    let linear = ConstantLinearVelocityRigidMotion::new(0.0, Isometry3::identity(), Vector3::x());
+    let (tmin, tmax) = linear.valid_interval();
+    assert_eq!(tmin, -std::f64::MAX);
+    assert_eq!(tmax, std::f64::MAX);
+
+    let reversed = linear.reversed();
+    assert_eq!(reversed.valid_interval(), (tmin, tmax));
+
+    let motion = ConstantVelocityRigidMotion::new(
+        0.0,
+        Isometry3::identity(),
+        na::Point3::origin(),
+        Vector3::x(),
+        Vector3::y(),
+    );
+    assert_eq!(motion.valid_interval(), (tmin, tmax));
+}
+
+#[test]
+fn constant_velocity_exact_at_any_time() {
+    let start = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let linvel = Vector3::new(2.0, -1.0, 0.5);
+    let angvel = Vector3::new(-0.3, 0.7, 1.1);
+    let motion =
+        ConstantVelocityRigidMotion::new(0.5, start, Point3::new(0.2, 0.0, -1.0), linvel, angvel);
+    let linear = ConstantLinearVelocityRigidMotion::new(0.5, start, linvel);
+
+    for t in [-3.0, 0.0, 0.5, 7.25].iter() {
+        // The linear velocity is the one of the local origin, which rotates around the center.
+        let h = 1.0e-6;
+        let origin = |t| motion.position_at_time(t).translation.vector;
+        let expected = (origin(*t + h) - origin(*t - h)) / (2.0 * h);
+        let (motion_linvel, motion_angvel) = motion.velocity_at_time(*t);
+        assert_relative_eq!(motion_linvel, expected, epsilon = 1.0e-6);
+        assert_eq!(motion_angvel, angvel);
+    }
+
+    for t in [-1.0e10, -3.0, 0.0, 0.5, 7.25, 1.0e10, std::f64::MAX].iter() {
+        assert_eq!(linear.velocity_at_time(*t), (linvel, Vector3::zeros()));
+        assert_eq!(
+            start.velocity_at_time(*t),
+            (Vector3::zeros(), Vector3::zeros())
+        );
+    }
+}
+
+#[test]
+fn finite_difference_velocity() {
+    let axis = Vector3::new(1.0, 2.0, -2.0) / 3.0;
+    let start = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::zeros());
+    let end = Isometry3::from_parts(
+        Vector3::new(2.0, 0.0, 2.5).into(),
+        UnitQuaternion::from_scaled_axis(axis * 0.8),
+    );
+    let motion = InterpolatedRigidMotion::new(start, end);
+
+    for t in [0.0, 0.3, 1.0].iter() {
+        let (linvel, angvel) = motion.velocity_at_time(*t);
+        assert_relative_eq!(linvel, Vector3::new(1.0, 2.0, -0.5), epsilon = 1.0e-6);
+        assert_relative_eq!(angvel, axis * 0.8, epsilon = 1.0e-6);
+    }
+}
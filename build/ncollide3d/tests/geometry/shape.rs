@@ -0,0 +1,748 @@
+use na::{self, Isometry3, Matrix3, Point3, Unit, Vector3};
+use ncollide3d::bounding_volume::{self, BoundingVolume, HasBoundingVolume, AABB};
+use ncollide3d::pipeline::narrow_phase::{
+    ContactManifoldGenerator, DefaultContactDispatcher, PlaneBallManifoldGenerator,
+};
+use ncollide3d::query::{self, ContactManifold, ContactPrediction, PointQuery, Ray, RayCast};
+use ncollide3d::shape::{
+    Ball, Capsule, Compound, ConvexHull, ConvexPolygonalFeature, ConvexPolyhedron, Cuboid,
+    Cylinder, FeatureId, Plane, Shape, ShapeHandle, SupportMap, Tetrahedron, Triangle,
+};
+use ncollide3d::utils;
+use std::collections::HashSet;
+use std::f64::consts::PI;
+
+#[test]
+fn capsule_world_segment_matches_transformed_endpoints() {
+    let capsule = Capsule::new(1.5f64, 0.3);
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.4, -0.2, 1.1));
+
+    let local_a = Point3::new(0.0, -1.5, 0.0);
+    let local_b = Point3::new(0.0, 1.5, 0.0);
+
+    let segment = capsule.world_segment(&m);
+    assert_relative_eq!(segment.a, m * local_a, epsilon = 1.0e-10);
+    assert_relative_eq!(segment.b, m * local_b, epsilon = 1.0e-10);
+
+    let (a, b) = capsule.world_endpoints(&m);
+    assert_relative_eq!(a, m * local_a, epsilon = 1.0e-10);
+    assert_relative_eq!(b, m * local_b, epsilon = 1.0e-10);
+}
+
+#[test]
+fn feature_id_pack_unpack_round_trip() {
+    let max_subid = (1usize << 62) - 1;
+    let ids = [
+        FeatureId::Unknown,
+        FeatureId::Vertex(0),
+        FeatureId::Vertex(42),
+        FeatureId::Vertex(max_subid),
+        FeatureId::Edge(0),
+        FeatureId::Edge(7),
+        FeatureId::Edge(max_subid),
+        FeatureId::Face(0),
+        FeatureId::Face(1234),
+        FeatureId::Face(max_subid),
+    ];
+
+    for id in ids.iter() {
+        assert_eq!(FeatureId::unpack(id.pack()), *id);
+    }
+
+    assert_eq!(FeatureId::Unknown.pack(), 0);
+
+    // All packed values must be distinct.
+    for (i, id1) in ids.iter().enumerate() {
+        for id2 in &ids[i + 1..] {
+            assert_ne!(id1.pack(), id2.pack());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn feature_id_pack_subid_too_large() {
+    let _ = FeatureId::Face(1usize << 62).pack();
+}
+
+#[test]
+fn downcast_pair() {
+    let ball = Ball::new(1.0f64);
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let ball: &dyn Shape<f64> = &ball;
+    let cuboid: &dyn Shape<f64> = &cuboid;
+
+    let (b, c) = <dyn Shape<f64>>::downcast_pair::<Ball<f64>, Cuboid<f64>>(ball, cuboid).unwrap();
+    assert_eq!(b.radius, 1.0);
+    assert_eq!(c.half_extents, Vector3::new(1.0, 2.0, 3.0));
+
+    assert!(<dyn Shape<f64>>::downcast_pair::<Ball<f64>, Cuboid<f64>>(cuboid, ball).is_none());
+    assert!(<dyn Shape<f64>>::downcast_pair::<Ball<f64>, Ball<f64>>(ball, cuboid).is_none());
+}
+
+#[test]
+fn downcast_pair_unordered() {
+    let ball = Ball::new(1.0f64);
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let ball: &dyn Shape<f64> = &ball;
+    let cuboid: &dyn Shape<f64> = &cuboid;
+
+    let (b, c, swapped) =
+        <dyn Shape<f64>>::downcast_pair_unordered::<Ball<f64>, Cuboid<f64>>(ball, cuboid).unwrap();
+    assert!(!swapped);
+    assert_eq!(b.radius, 1.0);
+    assert_eq!(c.half_extents, Vector3::new(1.0, 2.0, 3.0));
+
+    let (b, c, swapped) =
+        <dyn Shape<f64>>::downcast_pair_unordered::<Ball<f64>, Cuboid<f64>>(cuboid, ball).unwrap();
+    assert!(swapped);
+    assert_eq!(b.radius, 1.0);
+    assert_eq!(c.half_extents, Vector3::new(1.0, 2.0, 3.0));
+
+    assert!(
+        <dyn Shape<f64>>::downcast_pair_unordered::<Ball<f64>, Ball<f64>>(ball, cuboid).is_none()
+    );
+    assert!(
+        <dyn Shape<f64>>::downcast_pair_unordered::<Ball<f64>, Ball<f64>>(cuboid, ball).is_none()
+    );
+}
+
+#[test]
+fn ball_support_point_with_zero_direction() {
+    let ball = Ball::new(2.0f64);
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+
+    for dir in &[Vector3::zeros(), Vector3::new(1.0e-20, 0.0, -1.0e-20)] {
+        let local = ball.local_support_point(dir);
+        assert!(local.coords.iter().all(|e| e.is_finite()));
+        assert_relative_eq!(local, Point3::new(2.0, 0.0, 0.0));
+
+        let world = ball.support_point(&m, dir);
+        assert!(world.coords.iter().all(|e| e.is_finite()));
+        assert_relative_eq!(
+            na::distance(&world, &Point3::from(m.translation.vector)),
+            2.0,
+            epsilon = 1.0e-10
+        );
+    }
+}
+
+fn cuboid() -> Cuboid<f64> {
+    Cuboid::new(Vector3::new(1.0, 2.0, 3.0))
+}
+
+#[test]
+fn cuboid_support_feature_id_face() {
+    let cuboid = cuboid();
+
+    assert_eq!(
+        cuboid.support_feature_id_toward(&Vector3::y_axis()),
+        FeatureId::Face(1)
+    );
+    assert_eq!(
+        cuboid.support_feature_id_toward(&-Vector3::z_axis()),
+        FeatureId::Face(5)
+    );
+    // Signed zeros don't change the result.
+    let dir = Unit::new_unchecked(Vector3::new(-0.0, -1.0, 0.0));
+    assert_eq!(cuboid.support_feature_id_toward(&dir), FeatureId::Face(4));
+}
+
+#[test]
+fn cuboid_support_feature_id_edge() {
+    let cuboid = cuboid();
+    let dir = Unit::new_normalize(Vector3::new(1.0, -1.0, 0.0));
+    let id = cuboid.support_feature_id_toward(&dir);
+    let (a, b, _, _) = cuboid.edge(id);
+
+    assert!(match id {
+        FeatureId::Edge(_) => true,
+        _ => false,
+    });
+    // The edge is the one along the z axis at (1, -2).
+    assert_relative_eq!(a.x, 1.0);
+    assert_relative_eq!(a.y, -2.0);
+    assert_relative_eq!(b.x, 1.0);
+    assert_relative_eq!(b.y, -2.0);
+    assert_relative_eq!((a.z - b.z).abs(), 6.0);
+
+    // Same result when the zero component is negative.
+    let dir = Unit::new_unchecked(Vector3::new(dir.x, dir.y, -0.0));
+    assert_eq!(cuboid.support_feature_id_toward(&dir), id);
+}
+
+#[test]
+fn cuboid_support_feature_id_vertex() {
+    let cuboid = cuboid();
+    let dir = Unit::new_normalize(Vector3::new(-1.0, 1.0, -1.0));
+    let id = cuboid.support_feature_id_toward(&dir);
+
+    assert!(match id {
+        FeatureId::Vertex(_) => true,
+        _ => false,
+    });
+    assert_relative_eq!(cuboid.vertex(id), na::Point3::new(-1.0, 2.0, -3.0));
+}
+
+fn undirected_edges(shape: &dyn ConvexPolyhedron<f64>) -> HashSet<(usize, usize)> {
+    shape
+        .edges()
+        .map(|(a, b)| if a < b { (a, b) } else { (b, a) })
+        .collect()
+}
+
+#[test]
+fn cuboid_wireframe() {
+    let cuboid = Cuboid::new(Vector3::new(1.0, 2.0, 3.0));
+    let vertices = ConvexPolyhedron::vertices(&cuboid);
+    let edges = undirected_edges(&cuboid);
+
+    assert_eq!(vertices.len(), 8);
+    assert_eq!(cuboid.edges().count(), 12);
+    assert_eq!(edges.len(), 12);
+
+    for (i, pt) in vertices.iter().enumerate() {
+        assert_eq!(*pt, cuboid.vertex(FeatureId::Vertex(i)));
+        assert_relative_eq!(pt.x.abs(), 1.0);
+        assert_relative_eq!(pt.y.abs(), 2.0);
+        assert_relative_eq!(pt.z.abs(), 3.0);
+    }
+
+    // Each edge is parallel to one of the local axes.
+    for (a, b) in edges {
+        let dir = vertices[b] - vertices[a];
+        assert_eq!(dir.iter().filter(|e| **e != 0.0).count(), 1);
+    }
+}
+
+#[test]
+fn convex_hull_and_triangle_wireframe() {
+    let points = [
+        Point3::new(-1.0, -1.0, -1.0),
+        Point3::new(1.0, -1.0, -1.0),
+        Point3::new(-1.0, 1.0, -1.0),
+        Point3::new(1.0, 1.0, -1.0),
+        Point3::new(-1.0, -1.0, 1.0),
+        Point3::new(1.0, -1.0, 1.0),
+        Point3::new(-1.0, 1.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+    ];
+    let hull = ConvexHull::try_from_points(&points).unwrap();
+
+    // The diagonals of the triangulated faces are not reported.
+    assert_eq!(ConvexPolyhedron::vertices(&hull).len(), 8);
+    assert_eq!(undirected_edges(&hull).len(), 12);
+
+    let triangle = Triangle::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    assert_eq!(ConvexPolyhedron::vertices(&triangle).len(), 3);
+    assert_eq!(undirected_edges(&triangle).len(), 3);
+}
+
+fn box_corners(center: Point3<f64>, half_extents: Vector3<f64>) -> Vec<Point3<f64>> {
+    (0..8)
+        .map(|i| {
+            let sign = |bit: usize| if i & (1 << bit) == 0 { 1.0 } else { -1.0 };
+            center
+                + Vector3::new(
+                    sign(0) * half_extents.x,
+                    sign(1) * half_extents.y,
+                    sign(2) * half_extents.z,
+                )
+        })
+        .collect()
+}
+
+fn cuboid_inertia(mass: f64, half_extents: Vector3<f64>) -> Matrix3<f64> {
+    let sq = (half_extents * 2.0).component_mul(&(half_extents * 2.0));
+    Matrix3::from_diagonal(&Vector3::new(sq.y + sq.z, sq.x + sq.z, sq.x + sq.y)) * (mass / 12.0)
+}
+
+#[test]
+fn convex_hull_mass_properties_unit_cube() {
+    let points = box_corners(Point3::origin(), Vector3::repeat(0.5));
+    let hull = ConvexHull::try_from_points(&points).unwrap();
+    let props = hull.mass_properties(1.0);
+
+    assert_relative_eq!(props.mass, 1.0, epsilon = 1.0e-10);
+    assert_relative_eq!(props.local_com, Point3::origin(), epsilon = 1.0e-10);
+    assert_relative_eq!(
+        props.angular_inertia,
+        Matrix3::from_diagonal_element(1.0 / 6.0),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn convex_hull_mass_properties_offset_box() {
+    let center = Point3::new(1.0, -2.0, 3.0);
+    let half_extents = Vector3::new(0.5, 1.0, 2.0);
+    // Add a point in the middle of a face: it must not change the result.
+    let mut points = box_corners(center, half_extents);
+    points.push(center + Vector3::new(0.5, 0.0, 0.0));
+
+    let hull = ConvexHull::try_from_points(&points).unwrap();
+    let density = 3.0;
+    let props = hull.mass_properties(density);
+    let mass = 8.0 * density;
+
+    assert_relative_eq!(props.mass, mass, epsilon = 1.0e-10);
+    assert_relative_eq!(props.local_com, center, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        props.angular_inertia,
+        cuboid_inertia(mass, half_extents),
+        epsilon = 1.0e-10
+    );
+}
+
+fn tetrahedron() -> Tetrahedron<f64> {
+    Tetrahedron::new(
+        Point3::new(1.0, 0.0, -1.0),
+        Point3::new(3.0, 0.5, -1.0),
+        Point3::new(1.5, 2.0, -0.5),
+        Point3::new(1.8, 0.7, 2.0),
+    )
+}
+
+#[test]
+fn tetrahedron_barycentric_coordinates_centroid() {
+    let tetra = tetrahedron();
+    let centroid =
+        Point3::from((tetra.a.coords + tetra.b.coords + tetra.c.coords + tetra.d.coords) / 4.0);
+
+    let bcoords = tetra.barycentric_coordinates(&centroid).unwrap();
+    for c in &bcoords {
+        assert_relative_eq!(*c, 0.25, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn tetrahedron_barycentric_coordinates_vertices() {
+    let tetra = tetrahedron();
+    let vertices = [tetra.a, tetra.b, tetra.c, tetra.d];
+
+    for (i, pt) in vertices.iter().enumerate() {
+        let bcoords = tetra.barycentric_coordinates(pt).unwrap();
+
+        for (j, c) in bcoords.iter().enumerate() {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert_relative_eq!(*c, expected, epsilon = 1.0e-10);
+        }
+    }
+}
+
+#[test]
+fn tetrahedron_barycentric_coordinates_exterior() {
+    let tetra = tetrahedron();
+    // Mirror the vertex `a` with respect to the opposite face.
+    let pt = Point3::from(tetra.b.coords + tetra.c.coords + tetra.d.coords) * (2.0 / 3.0)
+        - tetra.a.coords;
+    let bcoords = tetra.barycentric_coordinates(&pt).unwrap();
+
+    assert!(bcoords[0] < 0.0);
+    assert!(bcoords[1..].iter().all(|c| *c > 0.0));
+    assert_relative_eq!(bcoords.iter().sum::<f64>(), 1.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn tetrahedron_barycentric_coordinates_degenerate() {
+    let flat = Tetrahedron::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.3, 0.3, 1.0e-12),
+    );
+
+    assert!(flat.barycentric_coordinates(&Point3::origin()).is_none());
+}
+
+#[test]
+fn scaled_ball_radius_and_aabb() {
+    let ball = Ball::new(0.5f64);
+    let scaled = ball.scaled(3.0);
+    assert_eq!(scaled.radius, 1.5);
+
+    let pos = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+    let aabb = bounding_volume::aabb(&scaled, &pos);
+    assert_relative_eq!(aabb.mins, Point3::new(-0.5, 0.5, 1.5), epsilon = 1.0e-7);
+    assert_relative_eq!(aabb.maxs, Point3::new(2.5, 3.5, 4.5), epsilon = 1.0e-7);
+    assert_relative_eq!(
+        aabb.extents(),
+        bounding_volume::aabb(&ball, &pos).extents() * 3.0,
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn scaled_cuboid_and_capsule() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0)).scaled(0.5);
+    assert_eq!(cuboid.half_extents, Vector3::new(0.5, 1.0, 1.5));
+
+    let capsule = Capsule::new(2.0f64, 0.25).scaled(4.0);
+    assert_eq!(capsule.half_height, 8.0);
+    assert_eq!(capsule.radius, 1.0);
+
+    let aabb = bounding_volume::aabb(&capsule, &Isometry3::identity());
+    assert_relative_eq!(
+        aabb.half_extents(),
+        Vector3::new(1.0, 9.0, 1.0),
+        epsilon = 1.0e-7
+    );
+    assert!(aabb.contains(&bounding_volume::aabb(
+        &Capsule::new(2.0, 0.25),
+        &Isometry3::identity()
+    )));
+}
+
+#[test]
+#[should_panic]
+fn scaling_by_a_non_positive_factor_panics() {
+    let _ = Ball::new(1.0f64).scaled(0.0);
+}
+
+fn offset_plane() -> Plane<f64> {
+    Plane::with_offset(Vector3::y_axis(), 2.0)
+}
+
+#[test]
+fn ball_resting_on_offset_plane() {
+    let plane = offset_plane();
+    let ball = Ball::new(1.0);
+    let m_plane = Isometry3::new(Vector3::new(1.0, 0.5, -2.0), na::zero());
+    let m_ball = Isometry3::new(Vector3::new(3.0, 3.4, 1.0), na::zero());
+
+    // Through the query module.
+    let contact = query::contact(&m_plane, &plane, &m_ball, &ball, 0.0).unwrap();
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-7);
+    assert_relative_eq!(contact.world1, Point3::new(3.0, 2.5, 1.0), epsilon = 1.0e-7);
+    assert_relative_eq!(contact.world2, Point3::new(3.0, 2.4, 1.0), epsilon = 1.0e-7);
+
+    // Through the plane-ball contact generator.
+    let mut generator = PlaneBallManifoldGenerator::new(false);
+    let mut manifold = ContactManifold::new();
+    assert!(generator.generate_contacts(
+        &DefaultContactDispatcher::new(),
+        &m_plane,
+        &plane,
+        None,
+        &m_ball,
+        &ball,
+        None,
+        &ContactPrediction::new(0.0, 0.0, 0.0),
+        &mut manifold,
+    ));
+    let contact = manifold.deepest_contact().unwrap().contact;
+    assert_relative_eq!(contact.depth, 0.1, epsilon = 1.0e-7);
+    assert_relative_eq!(contact.world1, Point3::new(3.0, 2.5, 1.0), epsilon = 1.0e-7);
+}
+
+#[test]
+fn offset_plane_point_and_ray_queries() {
+    let plane = offset_plane();
+    let m = Isometry3::new(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 0.0));
+
+    assert_relative_eq!(
+        plane.distance_to_point(&m, &Point3::new(5.0, 4.0, 2.0), false),
+        1.0
+    );
+    assert!(plane.contains_point(&m, &Point3::new(5.0, 2.9, 2.0)));
+    assert!(!plane.contains_point(&m, &Point3::new(5.0, 3.1, 2.0)));
+
+    let proj = plane.project_point(&m, &Point3::new(5.0, 7.0, 2.0), true);
+    assert_relative_eq!(proj.point, Point3::new(5.0, 3.0, 2.0), epsilon = 1.0e-7);
+
+    let ray = Ray::new(Point3::new(0.0, 10.0, 0.0), -Vector3::y());
+    let toi = plane.toi_with_ray(&m, &ray, std::f64::MAX, true).unwrap();
+    assert_relative_eq!(toi, 7.0, epsilon = 1.0e-7);
+
+    // Cuboid resting on the plane.
+    let cuboid = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m_cuboid = Isometry3::new(Vector3::new(0.0, 4.5, 0.0), na::zero());
+    assert_relative_eq!(
+        query::distance(&m, &plane, &m_cuboid, &cuboid),
+        0.5,
+        epsilon = 1.0e-7
+    );
+}
+
+fn test_hull() -> ConvexHull<f64> {
+    let points = vec![
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, 0.0, -1.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(0.5, 0.5, 0.5),
+        Point3::new(-0.3, 0.4, -0.5),
+        Point3::new(0.1, -0.6, 0.6),
+    ];
+
+    ConvexHull::try_from_points(&points).unwrap()
+}
+
+#[test]
+fn convex_hull_vertices_are_behind_face_planes() {
+    let hull = test_hull();
+    assert!(hull.num_faces() > 0);
+
+    for i in 0..hull.num_faces() {
+        let normal = hull.face_normal(i);
+        let origin = hull.face_point(i);
+
+        for pt in hull.points() {
+            assert!((pt - origin).dot(&normal) <= 1.0e-7);
+        }
+    }
+}
+
+#[test]
+fn convex_hull_face_planes_match_feature_ids() {
+    let hull = test_hull();
+    let mut face = ConvexPolygonalFeature::new();
+
+    for i in 0..hull.num_faces() {
+        let normal = hull.face_normal(i);
+        assert_eq!(hull.feature_normal(FeatureId::Face(i)), normal);
+
+        hull.face(FeatureId::Face(i), &mut face);
+        assert_eq!(face.normal, Some(normal));
+
+        // The face point lies on the plane of the corresponding face.
+        for pt in &face.vertices {
+            assert_relative_eq!(
+                (pt - hull.face_point(i)).dot(&normal),
+                0.0,
+                epsilon = 1.0e-7
+            );
+        }
+    }
+}
+
+#[test]
+fn capsule_mass_properties_unit_capsule() {
+    let capsule = Capsule::new(1.0f64, 1.0);
+    let props = capsule.mass_properties(2.0);
+
+    // Cylinder of mass 2pi and two hemispheres of mass 2pi/3, all scaled by the density.
+    let mass = 2.0 * 10.0 * PI / 3.0;
+    // Each hemisphere contributes 83/320 + (1 + 3/8)^2 around the transverse axes.
+    let transverse = 2.0 * (7.0 * PI / 6.0 + 4.0 * PI / 3.0 * (83.0 / 320.0 + 1.375 * 1.375));
+    let axial = 2.0 * 23.0 * PI / 15.0;
+
+    assert_relative_eq!(props.mass, mass, epsilon = 1.0e-10);
+    assert_eq!(props.local_com, Point3::origin());
+    assert_relative_eq!(
+        props.angular_inertia,
+        Matrix3::from_diagonal(&[transverse, axial, transverse].into()),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn capsule_mass_properties_zero_length_is_ball() {
+    let radius = 0.7;
+    let capsule = Capsule::new(0.0f64, radius);
+    let props = capsule.mass_properties(3.0);
+
+    let mass = 3.0 * 4.0 / 3.0 * PI * radius * radius * radius;
+    assert_relative_eq!(props.mass, mass, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        props.angular_inertia,
+        Matrix3::from_diagonal_element(mass * radius * radius * 2.0 / 5.0),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn symmetric_triangle_center_is_origin() {
+    let s = 3.0f64.sqrt() / 2.0;
+    let tri = Triangle::new(
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(-0.5, s, 0.0),
+        Point3::new(-0.5, -s, 0.0),
+    );
+
+    assert_relative_eq!(tri.center(), Point3::origin(), epsilon = 1.0e-10);
+    assert_eq!(tri.center(), utils::triangle_center(&tri.a, &tri.b, &tri.c));
+}
+
+#[test]
+fn symmetric_tetrahedron_center_is_origin() {
+    let tetra = Tetrahedron::new(
+        Point3::new(1.0f64, 1.0, 1.0),
+        Point3::new(1.0, -1.0, -1.0),
+        Point3::new(-1.0, 1.0, -1.0),
+        Point3::new(-1.0, -1.0, 1.0),
+    );
+
+    assert_relative_eq!(tetra.center(), Point3::origin(), epsilon = 1.0e-10);
+    assert_eq!(
+        tetra.center(),
+        utils::tetrahedron_center(&tetra.a, &tetra.b, &tetra.c, &tetra.d)
+    );
+
+    // Shifting all the vertices shifts the center.
+    let shift = na::Vector3::new(1.0, 2.0, 3.0);
+    let shifted = Tetrahedron::new(
+        tetra.a + shift,
+        tetra.b + shift,
+        tetra.c + shift,
+        tetra.d + shift,
+    );
+    assert_relative_eq!(shifted.center(), Point3::from(shift), epsilon = 1.0e-10);
+}
+
+#[test]
+fn compound_children_in_aabb() {
+    // A row of unit cubes centered at x = 0, 2, 4, 6, 8.
+    let shapes = (0..5)
+        .map(|i| {
+            (
+                Isometry3::translation(i as f64 * 2.0, 0.0, 0.0),
+                ShapeHandle::new(Cuboid::new(Vector3::repeat(0.5f64))),
+            )
+        })
+        .collect();
+    let compound = Compound::new(shapes);
+    let mut out = Vec::new();
+
+    // Touches the second and fourth cubes and contains the third one.
+    let aabb = AABB::new(Point3::new(2.5, -1.0, -1.0), Point3::new(5.5, 1.0, 1.0));
+    compound.children_in_aabb(&aabb, &mut out);
+    out.sort();
+    assert_eq!(out, vec![1, 2, 3]);
+
+    // Within the loosened bounding volumes of the first two cubes but touching none of them.
+    let aabb = AABB::new(Point3::new(0.52, -1.0, -1.0), Point3::new(1.48, 1.0, 1.0));
+    out.clear();
+    compound.children_in_aabb(&aabb, &mut out);
+    assert!(out.is_empty());
+
+    // The results are appended to the buffer.
+    out.push(42);
+    let aabb = AABB::new(Point3::new(7.0, 0.0, 0.0), Point3::new(9.0, 0.2, 0.2));
+    compound.children_in_aabb(&aabb, &mut out);
+    assert_eq!(out, vec![42, 4]);
+}
+
+#[test]
+fn cuboid_corners_of_unit_cube() {
+    let cuboid = Cuboid::new(Vector3::repeat(0.5f64));
+    let corners = cuboid.corners();
+
+    assert_eq!(cuboid.extents(), Vector3::repeat(1.0));
+
+    for (i, corner) in corners.iter().enumerate() {
+        let sign = |k: usize| if i & (1 << k) != 0 { -0.5 } else { 0.5 };
+        assert_eq!(*corner, Point3::new(sign(0), sign(1), sign(2)));
+        assert_eq!(*corner, cuboid.vertex(FeatureId::Vertex(i)));
+    }
+
+    // All the corners are distinct.
+    for i in 0..8 {
+        for j in i + 1..8 {
+            assert!(corners[i] != corners[j]);
+        }
+    }
+
+    let m = Isometry3::identity();
+    let corners_aabb = bounding_volume::point_cloud_aabb(&m, &corners[..]);
+    let aabb: AABB<f64> = cuboid.bounding_volume(&m);
+    assert_eq!(corners_aabb, aabb);
+}
+
+fn sphere_hull(npoints: usize) -> ConvexHull<f64> {
+    // Points evenly distributed on the unit sphere, along a Fibonacci spiral.
+    let golden_angle = std::f64::consts::PI * (3.0 - 5.0f64.sqrt());
+    let points: Vec<_> = (0..npoints)
+        .map(|i| {
+            let y = 1.0 - 2.0 * (i as f64 + 0.5) / (npoints as f64);
+            let r = (1.0 - y * y).sqrt();
+            let theta = golden_angle * i as f64;
+            Point3::new(r * theta.cos(), y, r * theta.sin())
+        })
+        .collect();
+
+    ConvexHull::try_from_points(&points).unwrap()
+}
+
+fn direction(t: f64) -> Vector3<f64> {
+    Vector3::new(
+        t.cos() * (t * 0.5).sin(),
+        (t * 0.5).cos(),
+        t.sin() * (t * 0.5).sin(),
+    )
+}
+
+#[test]
+fn convex_hull_support_vertex_id_is_extreme() {
+    let hull = sphere_hull(64);
+
+    for i in 0..20 {
+        let dir = direction(i as f64 * 0.37);
+        let id = hull.support_vertex_id(&dir);
+        let best = hull.points()[id].coords.dot(&dir);
+
+        assert!(hull.points().iter().all(|pt| pt.coords.dot(&dir) <= best));
+    }
+}
+
+#[test]
+fn convex_hull_support_vertex_id_from_any_start() {
+    let hull = sphere_hull(64);
+
+    for i in 0..20 {
+        let dir = direction(i as f64 * 0.37);
+        let expected = hull.support_vertex_id(&dir);
+
+        for start in 0..hull.points().len() {
+            assert_eq!(hull.support_vertex_id_from(&dir, start), expected);
+        }
+    }
+}
+
+#[test]
+fn convex_hull_support_vertex_id_from_previous_direction() {
+    let hull = sphere_hull(64);
+    let mut hint = 0;
+
+    // Slowly rotating directions, each walk starting from the previous support vertex.
+    for i in 0..200 {
+        let dir = direction(i as f64 * 0.03);
+        hint = hull.support_vertex_id_from(&dir, hint);
+        assert_eq!(hint, hull.support_vertex_id(&dir));
+    }
+}
+
+#[test]
+fn capsule_axis_matches_segment_direction() {
+    let capsule = Capsule::new(1.5, 0.5);
+    let segment = capsule.segment();
+    let dir = (segment.b - segment.a).normalize();
+
+    assert_relative_eq!(*capsule.axis(), dir);
+    assert_relative_eq!(*capsule.axis(), Vector3::y());
+
+    // The axis is in local-space, so it follows the capsule orientation once transformed.
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, -0.2, 0.7));
+    let world_segment = capsule.world_segment(&m);
+    let world_dir = (world_segment.b - world_segment.a).normalize();
+    assert_relative_eq!(m * *capsule.axis(), world_dir, epsilon = 1.0e-12);
+}
+
+#[test]
+fn cylinder_axis_is_local_y() {
+    let cylinder = Cylinder::new(2.0, 0.5);
+    let axis = cylinder.axis();
+
+    assert_relative_eq!(*axis, Vector3::y());
+
+    // The flat caps are orthogonal to the axis.
+    let top = cylinder.local_support_point(&axis);
+    let bottom = cylinder.local_support_point(&-*axis);
+    assert_relative_eq!(axis.dot(&(top - bottom)), 2.0 * cylinder.half_height);
+}
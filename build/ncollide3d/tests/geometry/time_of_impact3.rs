@@ -1,5 +1,8 @@
-use na::{self, Isometry3, Vector3};
-use ncollide3d::query;
+use na::{self, Isometry3, Point3, Vector3};
+use ncollide3d::interpolation::{
+    ConstantLinearVelocityRigidMotion, ConstantVelocityRigidMotion, RigidMotion,
+};
+use ncollide3d::query::{self, PointQuery};
 use ncollide3d::shape::{Ball, Cuboid};
 
 #[test]
@@ -65,3 +68,226 @@ fn ball_cuboid_toi() {
     ));
     assert_eq!(toi_wont_touch, None);
 }
+
+#[test]
+fn shape_cast_ball_against_cuboid_face() {
+    let cuboid = Cuboid::new(Vector3::repeat(1.0f64));
+    let m = Isometry3::identity();
+
+    let toi = query::shape_cast_ball(
+        0.5,
+        &Point3::new(-5.0, 0.0, 0.0),
+        &Vector3::x(),
+        std::f64::MAX,
+        0.0,
+        &m,
+        &cuboid,
+    )
+    .unwrap();
+
+    assert_relative_eq!(toi.toi, 3.5, epsilon = 1.0e-6);
+    assert_relative_eq!(*toi.normal1, Vector3::x(), epsilon = 1.0e-6);
+    assert_relative_eq!(*toi.normal2, -Vector3::x(), epsilon = 1.0e-6);
+    assert_relative_eq!(toi.witness1, Point3::new(0.5, 0.0, 0.0), epsilon = 1.0e-6);
+    assert_relative_eq!(toi.witness2.x, -1.0, epsilon = 1.0e-6);
+
+    // Out of reach.
+    assert!(query::shape_cast_ball(
+        0.5,
+        &Point3::new(-5.0, 0.0, 0.0),
+        &Vector3::x(),
+        3.0,
+        0.0,
+        &m,
+        &cuboid,
+    )
+    .is_none());
+}
+
+#[test]
+fn shape_cast_ball_against_cuboid_edge() {
+    let cuboid = Cuboid::new(Vector3::repeat(1.0f64));
+    let m = Isometry3::translation(1.0, 0.0, 0.0);
+    let dir = -Vector3::new(1.0, 1.0, 0.0).normalize();
+
+    let toi = query::shape_cast_ball(
+        0.5,
+        &Point3::new(6.0, 5.0, 0.0),
+        &dir,
+        std::f64::MAX,
+        0.0,
+        &m,
+        &cuboid,
+    )
+    .unwrap();
+
+    assert_relative_eq!(toi.toi, 4.0 * 2.0f64.sqrt() - 0.5, epsilon = 1.0e-6);
+    assert_relative_eq!(*toi.normal1, dir, epsilon = 1.0e-6);
+    assert_relative_eq!(*toi.normal2, -dir, epsilon = 1.0e-6);
+    assert_relative_eq!(
+        toi.witness2.xy(),
+        Point3::new(1.0, 1.0, 0.0).xy(),
+        epsilon = 1.0e-6
+    );
+}
+
+#[test]
+fn shape_cast_ball_grazing_cuboid_face() {
+    let cuboid = Cuboid::new(Vector3::repeat(1.0f64));
+
+    let toi = query::shape_cast_ball(
+        0.5,
+        &Point3::new(-5.0, 1.5, 0.0),
+        &Vector3::x(),
+        std::f64::MAX,
+        0.0,
+        &Isometry3::identity(),
+        &cuboid,
+    )
+    .unwrap();
+
+    assert_relative_eq!(toi.toi, 4.0, epsilon = 1.0e-4);
+    assert_relative_eq!(toi.normal1.norm(), 1.0, epsilon = 1.0e-6);
+    assert!(toi.normal1.x >= 0.0 && toi.normal1.y <= 0.0);
+}
+
+fn check_trace_matches_linear_toi(target_distance: f64) {
+    let cuboid = Cuboid::new(Vector3::repeat(0.5f64));
+    let m1 = Isometry3::translation(-5.0, 0.1, 0.0);
+    let m2 = Isometry3::new(Vector3::zeros(), Vector3::new(0.2, 0.3, 0.4));
+    let vel1 = Vector3::new(2.0, 0.0, 0.0);
+    let vel2 = na::zero();
+    let max_toi = 10.0;
+
+    let mut trace = Vec::new();
+    let toi = query::time_of_impact_with_trace(
+        &m1,
+        &vel1,
+        &cuboid,
+        &m2,
+        &vel2,
+        &cuboid,
+        max_toi,
+        target_distance,
+        &mut |t, pos1, pos2| trace.push((t, *pos1, *pos2)),
+    )
+    .unwrap();
+
+    // The trace has no effect on the result of the linear TOI solver.
+    let expected = query::time_of_impact(
+        &query::DefaultTOIDispatcher,
+        &m1,
+        &vel1,
+        &cuboid,
+        &m2,
+        &vel2,
+        &cuboid,
+        max_toi,
+        target_distance,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(toi.toi, expected.toi);
+    assert_eq!(toi.witness1, expected.witness1);
+    assert_eq!(toi.witness2, expected.witness2);
+    assert!(toi.toi > 0.0);
+
+    // The traced times are the successive lower bounds of the TOI.
+    assert!(trace.len() > 1);
+    assert_eq!(trace[0].0, 0.0);
+    assert_relative_eq!(trace.last().unwrap().0, toi.toi);
+
+    for w in trace.windows(2) {
+        assert!(w[0].0 <= w[1].0);
+    }
+
+    for (t, pos1, pos2) in &trace {
+        assert!(*t >= 0.0 && *t <= toi.toi);
+        assert_relative_eq!(
+            pos1.translation.vector,
+            m1.translation.vector + vel1 * *t,
+            epsilon = 1.0e-10
+        );
+        assert_eq!(*pos2, m2);
+    }
+}
+
+#[test]
+fn time_of_impact_trace_translating_cuboid() {
+    check_trace_matches_linear_toi(0.0);
+}
+
+#[test]
+fn time_of_impact_trace_translating_cuboid_with_target_distance() {
+    check_trace_matches_linear_toi(0.25);
+}
+
+#[test]
+fn min_distance_point_to_swinging_ball() {
+    let ball = Ball::new(0.5f64);
+    let point = Point3::new(0.0, 2.5, 1.0);
+    // The ball swings around the world origin while drifting along the x axis.
+    let motion = ConstantVelocityRigidMotion::new(
+        0.0,
+        Isometry3::translation(3.0, 0.0, 0.0),
+        Point3::new(-3.0, 0.0, 0.0),
+        Vector3::new(0.3, 0.0, 0.0),
+        Vector3::z(),
+    );
+    let (t0, t1) = (0.0, std::f64::consts::PI);
+
+    let (t, dist) =
+        query::min_distance_point_to_swept_shape(&point, &ball, &motion, t0, t1, 1.0e-8);
+
+    let nsamples = 100_000;
+    let reference = motion
+        .sample(t0, t1, nsamples)
+        .map(|(_, pos)| ball.distance_to_point(&pos, &point, true))
+        .fold(std::f64::MAX, f64::min);
+
+    assert!(t >= t0 && t <= t1);
+    assert_relative_eq!(
+        dist,
+        ball.distance_to_point(&motion.position_at_time(t), &point, true)
+    );
+    assert!(dist <= reference + 1.0e-12);
+    assert_relative_eq!(dist, reference, epsilon = 1.0e-6);
+}
+
+#[test]
+fn point_in_swept_sliding_cuboid() {
+    let cuboid = Cuboid::new(Vector3::repeat(0.5f64));
+    // The cuboid slides along the x axis and is centered at the origin at `t = 2`.
+    let motion = ConstantLinearVelocityRigidMotion::new(
+        0.0,
+        Isometry3::translation(-2.0, 0.0, 0.0),
+        Vector3::x(),
+    );
+
+    assert!(query::point_in_swept_shape(
+        &Point3::new(0.1, 0.2, -0.3),
+        &cuboid,
+        &motion,
+        0.0,
+        10.0,
+        1.0e-6
+    ));
+    assert!(!query::point_in_swept_shape(
+        &Point3::new(0.1, 0.6, -0.3),
+        &cuboid,
+        &motion,
+        0.0,
+        10.0,
+        1.0e-6
+    ));
+
+    // The cuboid does not reach the origin before `t = 1.5`.
+    assert!(!query::point_in_swept_shape(
+        &Point3::origin(),
+        &cuboid,
+        &motion,
+        0.0,
+        1.4,
+        1.0e-6
+    ));
+}
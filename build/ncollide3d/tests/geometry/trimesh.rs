@@ -0,0 +1,302 @@
+use na::{self, Isometry3, Point3, Unit, Vector3};
+use ncollide3d::bounding_volume::PolyhedralCone;
+use ncollide3d::pipeline::narrow_phase::{
+    CompositeShapeShapeManifoldGenerator, ContactManifoldGenerator, DefaultContactDispatcher,
+};
+use ncollide3d::procedural;
+use ncollide3d::query::ContactPrediction;
+use ncollide3d::shape::{Ball, Capsule, Cuboid, Cylinder, DeformableShape, FeatureId, TriMesh};
+use ncollide3d::transformation::ToTriMesh;
+use std::collections::HashMap;
+
+// Two triangles sharing the edge along the `y` axis, with a slight convex fold.
+fn folded_mesh() -> TriMesh<f64> {
+    let points = vec![
+        Point3::new(-3.0, 0.0, 0.0),
+        Point3::new(0.0, -2.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(3.0, 0.0, -0.03),
+    ];
+    let indices = vec![Point3::new(0, 1, 2), Point3::new(2, 1, 3)];
+
+    TriMesh::new(points, indices, None)
+}
+
+// Slides a ball across the shared edge and returns the world-space normals of all the contacts,
+// oriented from the mesh toward the ball, for each position of the ball.
+fn sliding_ball_normals(smoothing: bool, flip: bool) -> Vec<Vec<Unit<Vector3<f64>>>> {
+    let mesh = folded_mesh();
+    let ball = Ball::new(1.0);
+    let m_mesh = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.2, -0.1, 0.3));
+    let prediction = ContactPrediction::new(0.0, 0.0, 0.0);
+    let dispatcher = DefaultContactDispatcher::new();
+    let mut generator = CompositeShapeShapeManifoldGenerator::new(flip);
+    generator.set_normal_smoothing(smoothing);
+    let mut manifold = generator.init_manifold();
+    let mut result = Vec::new();
+
+    for i in 0..=40 {
+        let x = -0.6 + 1.2 * (i as f64) / 40.0;
+        let m_ball = Isometry3::new((m_mesh * Point3::new(x, 0.0, 0.9)).coords, na::zero());
+
+        manifold.save_cache_and_clear();
+
+        let generated = if !flip {
+            generator.generate_contacts(
+                &dispatcher,
+                &m_mesh,
+                &mesh,
+                None,
+                &m_ball,
+                &ball,
+                None,
+                &prediction,
+                &mut manifold,
+            )
+        } else {
+            generator.generate_contacts(
+                &dispatcher,
+                &m_ball,
+                &ball,
+                None,
+                &m_mesh,
+                &mesh,
+                None,
+                &prediction,
+                &mut manifold,
+            )
+        };
+        assert!(generated);
+
+        let normals: Vec<_> = manifold
+            .contacts()
+            .map(|c| {
+                // Check the depth is consistent with the contact points and normal.
+                let depth = -c.contact.normal.dot(&(c.contact.world2 - c.contact.world1));
+                assert_relative_eq!(c.contact.depth, depth, epsilon = 1.0e-10);
+
+                if flip {
+                    -c.contact.normal
+                } else {
+                    c.contact.normal
+                }
+            })
+            .collect();
+        assert!(!normals.is_empty());
+        result.push(normals);
+    }
+
+    result
+}
+
+fn face_normals() -> (Vector3<f64>, Vector3<f64>) {
+    let m_mesh = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.2, -0.1, 0.3));
+    let n1 = m_mesh * Vector3::z();
+    let n2 = m_mesh * Vector3::new(0.01, 0.0, 1.0).normalize();
+    (n1, n2)
+}
+
+#[test]
+fn smoothed_normals_stay_in_the_normal_cone_of_the_edge() {
+    let (n1, n2) = face_normals();
+    let fold_angle = n1.angle(&n2);
+
+    for flip in [false, true].iter() {
+        for normals in sliding_ball_normals(true, *flip) {
+            for n in normals {
+                assert!(n.angle(&n1) <= fold_angle + 1.0e-7);
+                assert!(n.angle(&n2) <= fold_angle + 1.0e-7);
+            }
+        }
+    }
+}
+
+#[test]
+fn smoothed_normals_vary_smoothly_across_the_edge() {
+    let (n1, _) = face_normals();
+
+    for flip in [false, true].iter() {
+        let normals = sliding_ball_normals(true, *flip);
+        let mut prev: Option<Unit<Vector3<f64>>> = None;
+
+        for ns in normals {
+            for n in &ns {
+                if let Some(prev) = prev {
+                    assert!(n.angle(&prev) <= 0.011);
+                }
+            }
+
+            prev = ns.last().cloned();
+        }
+
+        // Without smoothing, the contacts with the edge have normals pointing far out
+        // of the neighboring faces.
+        let raw = sliding_ball_normals(false, *flip);
+        assert!(raw.iter().flatten().any(|n| n.angle(&n1) > 0.1));
+    }
+}
+
+// Checks that every edge of the mesh is shared by exactly two triangles.
+fn is_closed(mesh: &procedural::TriMesh<f64>) -> bool {
+    let mut counts = HashMap::new();
+
+    for tri in mesh.flat_indices().chunks(3) {
+        for k in 0..3 {
+            let (a, b) = (tri[k], tri[(k + 1) % 3]);
+            *counts.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+        }
+    }
+
+    counts.values().all(|n| *n == 2)
+}
+
+#[test]
+fn cuboid_to_trimesh() {
+    let mesh = Cuboid::new(Vector3::new(1.0, 2.0, 3.0)).to_trimesh(());
+    assert_eq!(mesh.num_triangles(), 12);
+    assert!(is_closed(&mesh));
+}
+
+#[test]
+fn ball_to_trimesh_is_closed() {
+    for subdivs in &[(3, 2), (4, 3), (16, 8)] {
+        let mesh = Ball::new(1.5).to_trimesh(*subdivs);
+        assert!(is_closed(&mesh));
+
+        for pt in &mesh.coords {
+            assert_relative_eq!(pt.coords.norm(), 1.5, epsilon = 1.0e-10);
+        }
+    }
+}
+
+#[test]
+fn ball_to_trimesh_with_uvs() {
+    let ball = Ball::new(1.5);
+    let mesh = ball.to_trimesh_with_uvs(16, 8);
+    let uvs = mesh
+        .uvs
+        .as_ref()
+        .expect("The mesh should have texture coordinates.");
+    assert_eq!(uvs.len(), mesh.coords.len());
+    assert!(ball.to_trimesh((16, 8)).uvs.is_none());
+}
+
+#[test]
+fn capsule_and_cylinder_to_trimesh_are_closed() {
+    assert!(is_closed(&Capsule::new(1.0, 0.5).to_trimesh((4, 3))));
+    assert!(is_closed(&Cylinder::new(1.0, 0.5).to_trimesh(5)));
+}
+
+// A square pyramid with its apex, the vertex 4, along `+y`.
+fn pyramid() -> TriMesh<f64> {
+    let points = vec![
+        Point3::new(-1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(-1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    let indices = vec![
+        Point3::new(0, 4, 1),
+        Point3::new(1, 4, 2),
+        Point3::new(2, 4, 3),
+        Point3::new(3, 4, 0),
+        Point3::new(0, 1, 2),
+        Point3::new(0, 2, 3),
+    ];
+
+    TriMesh::new(points, indices, None)
+}
+
+#[test]
+fn trimesh_vertex_normal_cone_contains_adjacent_normals() {
+    let mesh = pyramid();
+    let cone = mesh.vertex_normal_cone(4);
+    assert_eq!(cone.generators().len(), 4);
+
+    for face in &mesh.faces()[..4] {
+        let normal = face.normal.unwrap();
+        assert!(normal.y > 0.0);
+        assert!(cone.contains_dir(&normal, 1.0e-10));
+    }
+
+    assert!(cone.contains_dir(&Vector3::y_axis(), 1.0e-10));
+    assert!(!cone.contains_dir(&Vector3::x_axis(), 1.0e-10));
+    assert!(!cone.contains_dir(&-Vector3::y_axis(), 1.0e-10));
+}
+
+#[test]
+fn trimesh_vertex_normal_clamping() {
+    let mesh = pyramid();
+    let cone = mesh.vertex_normal_cone(4);
+
+    // A direction inside of the cone is left unchanged.
+    let inside = Unit::new_normalize(Vector3::new(0.1, 1.0, -0.2));
+    let clamped = mesh.clamp_dir_to_normal_cone(FeatureId::Vertex(4), &inside);
+    assert_relative_eq!(clamped, inside, epsilon = 1.0e-10);
+
+    // A direction outside of the cone is projected on its boundary.
+    let outside = Unit::new_normalize(Vector3::new(1.0, 0.2, 0.1));
+    let clamped = mesh.clamp_dir_to_normal_cone(FeatureId::Vertex(4), &outside);
+    assert!(cone.contains_dir(&clamped, 1.0e-10));
+    assert!(clamped.dot(&outside) < 1.0 - 1.0e-3);
+
+    // The projection is the closest direction of the cone.
+    for face in &mesh.faces()[..4] {
+        assert!(face.normal.unwrap().dot(&outside) <= clamped.dot(&outside) + 1.0e-10);
+    }
+}
+
+#[test]
+fn polyhedral_cone_keeps_only_its_edges() {
+    let edges = [
+        Vector3::new(1.0, 1.0, 0.0),
+        Vector3::new(0.0, 1.0, 1.0),
+        Vector3::new(-1.0, 1.0, 0.0),
+        Vector3::new(0.0, 1.0, -1.0),
+    ];
+    let mut generators: Vec<_> = edges.iter().map(|e| Unit::new_normalize(*e)).collect();
+    generators.insert(2, Vector3::y_axis());
+    generators.swap(0, 3);
+    let cone = PolyhedralCone::new(generators);
+
+    // The interior generator is removed, and the edges are sorted around the axis.
+    let gens = cone.generators();
+    assert_eq!(gens.len(), 4);
+
+    for i in 0..4 {
+        let n = gens[i].cross(&gens[(i + 1) % 4]);
+        assert!(n.dot(&Vector3::y()) > 0.0);
+    }
+
+    assert!(cone.contains_dir(&Vector3::y_axis(), 1.0e-10));
+    assert_relative_eq!(
+        cone.project(&Vector3::new(2.0, 1.0, 0.0)),
+        Vector3::new(1.5, 1.5, 0.0),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        cone.project(&Vector3::new(1.0, 0.0, 1.0)),
+        Vector3::new(1.0, 2.0, 1.0) / 3.0,
+        epsilon = 1.0e-10
+    );
+    assert_eq!(cone.project(&-Vector3::y()), Vector3::zeros());
+}
+
+#[test]
+fn trimesh_deformation_updates_vertex_normal_cones() {
+    let mut mesh = pyramid();
+    let mut coords: Vec<f64> = mesh
+        .points()
+        .iter()
+        .flat_map(|p| p.coords.iter().cloned())
+        .collect();
+
+    // Flatten the pyramid: the normal cone of its apex becomes the single direction `+y`.
+    coords[4 * 3 + 1] = 0.0;
+    mesh.set_deformations(&coords);
+
+    let cone = mesh.vertex_normal_cone(4);
+    assert!(cone.contains_dir(&Vector3::y_axis(), 1.0e-10));
+    assert!(!cone.contains_dir(&Unit::new_normalize(Vector3::new(0.1, 1.0, 0.0)), 1.0e-10));
+}
@@ -0,0 +1,86 @@
+use na::{Isometry3, Point3, Vector3};
+use ncollide3d::utils;
+
+#[derive(Debug, PartialEq)]
+struct Item {
+    key: u32,
+    id: usize,
+}
+
+#[test]
+fn sort3_by_custom_key() {
+    let a = Item { key: 7, id: 0 };
+    let b = Item { key: 2, id: 1 };
+    let c = Item { key: 5, id: 2 };
+
+    let (s1, s2, s3) = utils::sort3_by(&a, &b, &c, |x, y| x.key.cmp(&y.key));
+    assert_eq!((s1.id, s2.id, s3.id), (1, 2, 0));
+}
+
+#[test]
+fn sort3_by_is_stable() {
+    // All the key assignments with values in {0, 1, 2}.
+    for code in 0..27 {
+        let keys = [code % 3, (code / 3) % 3, code / 9];
+        let items: Vec<_> = keys
+            .iter()
+            .enumerate()
+            .map(|(id, key)| Item { key: *key, id })
+            .collect();
+
+        let (s1, s2, s3) =
+            utils::sort3_by(&items[0], &items[1], &items[2], |x, y| x.key.cmp(&y.key));
+        let sorted = [s1, s2, s3];
+
+        for w in sorted.windows(2) {
+            assert!(w[0].key < w[1].key || (w[0].key == w[1].key && w[0].id < w[1].id));
+        }
+    }
+}
+
+#[test]
+fn sort3_floats() {
+    assert_eq!(utils::sort3(&3.0, &-1.0, &2.0), (&-1.0, &2.0, &3.0));
+}
+
+#[test]
+fn transform_points_matches_transform_point() {
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, 0.2, -0.7));
+    let points: Vec<_> = (0..100)
+        .map(|i| {
+            let i = i as f64;
+            Point3::new(i.sin(), i * 0.5, -i.cos() * 3.0)
+        })
+        .collect();
+    let mut out = vec![Point3::origin(); points.len()];
+
+    utils::transform_points(&m, &points, &mut out);
+
+    for (pt, res) in points.iter().zip(out.iter()) {
+        assert_relative_eq!(*res, m.transform_point(pt), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn transform_points_large_cloud() {
+    let m = Isometry3::new(Vector3::new(0.5, 0.0, -1.0), Vector3::new(0.0, 1.2, 0.0));
+    let n = 1_000_000;
+    let points: Vec<_> = (0..n)
+        .map(|i| Point3::new((i % 1000) as f64, (i % 7) as f64, (i % 13) as f64))
+        .collect();
+    let mut out = vec![Point3::origin(); n];
+
+    utils::transform_points(&m, &points, &mut out);
+
+    for i in (0..n).step_by(9973) {
+        assert_relative_eq!(out[i], m * points[i], epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+#[should_panic]
+fn transform_points_length_mismatch() {
+    let points = [Point3::origin(); 3];
+    let mut out = [Point3::origin(); 2];
+    utils::transform_points(&Isometry3::<f64>::identity(), &points, &mut out);
+}
@@ -0,0 +1,329 @@
+use na::{Point3, Vector3};
+use ncollide3d::bounding_volume::AABB;
+use ncollide3d::pipeline::{
+    BroadPhase, BroadPhaseInterferenceHandler, BroadPhaseProxyHandle, DBVTBroadPhase,
+};
+use ncollide3d::query::{Ray, RayIntersection};
+use ncollide3d::utils::SortedPair;
+use std::collections::HashMap;
+
+struct NoopHandler;
+
+impl BroadPhaseInterferenceHandler<usize> for NoopHandler {
+    fn is_interference_allowed(&mut self, _: &usize, _: &usize) -> bool {
+        true
+    }
+
+    fn interference_started(&mut self, _: &usize, _: &usize) {}
+
+    fn interference_stopped(&mut self, _: &usize, _: &usize) {}
+}
+
+#[test]
+fn broad_phase_ray_interferences_are_sorted() {
+    let mut broad_phase = DBVTBroadPhase::new(0.01f64);
+
+    // A line of boxes along the x axis, inserted out of order.
+    for i in &[3usize, 0, 4, 1, 2] {
+        let center = Point3::new(*i as f64 * 2.0, 0.0, 0.0);
+        let half_extents = Vector3::repeat(0.5);
+        let aabb = AABB::new(center - half_extents, center + half_extents);
+        let _ = broad_phase.create_proxy(aabb, *i);
+    }
+
+    broad_phase.update(&mut NoopHandler);
+
+    let ray = Ray::new(Point3::new(-5.0, 0.1, 0.0), Vector3::x());
+    let mut out = Vec::new();
+    broad_phase.interferences_with_ray(&ray, std::f64::MAX, &mut out);
+    assert_eq!(out, vec![&0, &1, &2, &3, &4]);
+
+    let ray = Ray::new(Point3::new(15.0, 0.1, 0.0), -Vector3::x());
+    let mut out = Vec::new();
+    broad_phase.interferences_with_ray(&ray, std::f64::MAX, &mut out);
+    assert_eq!(out, vec![&4, &3, &2, &1, &0]);
+
+    let mut out = Vec::new();
+    broad_phase.interferences_with_ray(&ray, 12.0, &mut out);
+    assert_eq!(out, vec![&4, &3, &2]);
+}
+
+#[derive(Default)]
+struct CountingHandler {
+    started: HashMap<SortedPair<usize>, usize>,
+}
+
+impl BroadPhaseInterferenceHandler<usize> for CountingHandler {
+    fn is_interference_allowed(&mut self, _: &usize, _: &usize) -> bool {
+        true
+    }
+
+    fn interference_started(&mut self, a: &usize, b: &usize) {
+        *self.started.entry(SortedPair::new(*a, *b)).or_insert(0) += 1;
+    }
+
+    fn interference_stopped(&mut self, a: &usize, b: &usize) {
+        let _ = self.started.remove(&SortedPair::new(*a, *b));
+    }
+}
+
+fn cube_at(x: f64) -> AABB<f64> {
+    let center = Point3::new(x, 0.0, 0.0);
+    let half_extents = Vector3::repeat(0.5);
+    AABB::new(center - half_extents, center + half_extents)
+}
+
+fn rod(shift: f64) -> AABB<f64> {
+    AABB::new(
+        Point3::new(-1.0 + shift, -0.1, -0.1),
+        Point3::new(9.0 + shift, 0.1, 0.1),
+    )
+}
+
+#[test]
+fn broad_phase_reports_each_pair_once() {
+    let mut broad_phase = DBVTBroadPhase::new(0.01f64);
+    let mut handler = CountingHandler::default();
+
+    // An elongated object overlapping a line of five boxes that do not overlap each other.
+    let rod_handle = broad_phase.create_proxy(rod(0.0), 0);
+    let mut cube_handles = Vec::new();
+    for i in 1..=5 {
+        cube_handles.push(broad_phase.create_proxy(cube_at((i - 1) as f64 * 2.0), i));
+    }
+
+    broad_phase.update(&mut handler);
+
+    // Move everything a bit so the pairs are detected again from both sides.
+    for k in 1..4 {
+        let shift = k as f64 * 0.01;
+        broad_phase.deferred_set_bounding_volume(rod_handle, rod(shift));
+        for (i, handle) in cube_handles.iter().enumerate() {
+            broad_phase.deferred_set_bounding_volume(*handle, cube_at(i as f64 * 2.0 + shift));
+        }
+
+        broad_phase.update(&mut handler);
+    }
+
+    assert_eq!(handler.started.len(), 5);
+
+    for i in 1..=5 {
+        assert_eq!(handler.started[&SortedPair::new(0, i)], 1);
+        assert_eq!(handler.started[&SortedPair::new(i, 0)], 1);
+    }
+}
+
+fn cube_at_xy(x: f64, y: f64) -> AABB<f64> {
+    let center = Point3::new(x, y, 0.0);
+    let half_extents = Vector3::repeat(0.5);
+    AABB::new(center - half_extents, center + half_extents)
+}
+
+#[test]
+fn broad_phase_pairs_within_distance() {
+    let mut broad_phase = DBVTBroadPhase::new(0.0f64);
+    let d = 0.5;
+
+    // Gaps of `d - 0.01` between the first two cubes, and `d + 0.01` between the next two.
+    let h0 = broad_phase.create_proxy(cube_at_xy(0.0, 0.0), 0);
+    let h1 = broad_phase.create_proxy(cube_at_xy(1.0 + d - 0.01, 0.0), 1);
+    let _ = broad_phase.create_proxy(cube_at_xy(2.0 * (1.0 + d), 0.0), 2);
+    // Far away from everything.
+    let _ = broad_phase.create_proxy(cube_at_xy(0.0, 10.0), 3);
+
+    broad_phase.update(&mut NoopHandler);
+
+    let mut pairs = Vec::new();
+    broad_phase.pairs_within_distance(d, &mut pairs);
+    assert_eq!(pairs, vec![(h0, h1)]);
+
+    // None of the cubes overlap.
+    pairs.clear();
+    broad_phase.pairs_within_distance(0.0, &mut pairs);
+    assert!(pairs.is_empty());
+}
+
+#[test]
+fn broad_phase_pairs_within_distance_ignores_margin() {
+    let mut broad_phase = DBVTBroadPhase::new(0.4f64);
+    let d = 0.5;
+
+    let h0 = broad_phase.create_proxy(cube_at_xy(0.0, 0.0), 0);
+    let h1 = broad_phase.create_proxy(cube_at_xy(1.0 + d - 0.01, 0.0), 1);
+    let h2 = broad_phase.create_proxy(cube_at_xy(2.0 * (1.0 + d), 0.0), 2);
+
+    broad_phase.update(&mut NoopHandler);
+
+    let mut pairs = Vec::new();
+    broad_phase.pairs_within_distance(d, &mut pairs);
+    assert_eq!(pairs, vec![(h0, h1)]);
+
+    // Moved to a gap of `d + 0.3` to the first cube, i.e., within `d + margin` but not within `d`.
+    broad_phase.deferred_set_bounding_volume(h2, cube_at_xy(-1.3 - d, 0.0));
+    broad_phase.update(&mut NoopHandler);
+
+    pairs.clear();
+    broad_phase.pairs_within_distance(d, &mut pairs);
+    assert_eq!(pairs, vec![(h0, h1)]);
+}
+
+// A broad phase relying on the default implementations of `pairs_within_distance` and
+// `initial_pairs`.
+struct BruteForceBroadPhase(DBVTBroadPhase<f64, AABB<f64>, usize>);
+
+impl BroadPhase<f64, AABB<f64>, usize> for BruteForceBroadPhase {
+    fn create_proxy(&mut self, bv: AABB<f64>, data: usize) -> BroadPhaseProxyHandle {
+        self.0.create_proxy(bv, data)
+    }
+
+    fn proxy(&self, handle: BroadPhaseProxyHandle) -> Option<(&AABB<f64>, &usize)> {
+        self.0.proxy(handle)
+    }
+
+    fn remove(
+        &mut self,
+        handles: &[BroadPhaseProxyHandle],
+        removal_handler: &mut dyn FnMut(&usize, &usize),
+    ) {
+        self.0.remove(handles, removal_handler)
+    }
+
+    fn deferred_set_bounding_volume(&mut self, handle: BroadPhaseProxyHandle, bv: AABB<f64>) {
+        self.0.deferred_set_bounding_volume(handle, bv)
+    }
+
+    fn deferred_recompute_all_proximities_with(&mut self, handle: BroadPhaseProxyHandle) {
+        self.0.deferred_recompute_all_proximities_with(handle)
+    }
+
+    fn deferred_recompute_all_proximities(&mut self) {
+        self.0.deferred_recompute_all_proximities()
+    }
+
+    fn update(&mut self, handler: &mut dyn BroadPhaseInterferenceHandler<usize>) {
+        self.0.update(handler)
+    }
+
+    fn interferences_with_bounding_volume<'a>(&'a self, bv: &AABB<f64>, out: &mut Vec<&'a usize>) {
+        self.0.interferences_with_bounding_volume(bv, out)
+    }
+
+    fn interferences_with_ray<'a>(
+        &'a self,
+        ray: &Ray<f64>,
+        max_toi: f64,
+        out: &mut Vec<&'a usize>,
+    ) {
+        self.0.interferences_with_ray(ray, max_toi, out)
+    }
+
+    fn interferences_with_point<'a>(&'a self, point: &Point3<f64>, out: &mut Vec<&'a usize>) {
+        self.0.interferences_with_point(point, out)
+    }
+
+    fn proxy_handles(&self, out: &mut Vec<BroadPhaseProxyHandle>) {
+        self.0.proxy_handles(out)
+    }
+
+    fn first_interference_with_ray<'a, 'b>(
+        &'a self,
+        ray: &'b Ray<f64>,
+        max_toi: f64,
+        cost_fn: &'a dyn Fn(usize, &'b Ray<f64>, f64) -> Option<(usize, RayIntersection<f64>)>,
+    ) -> Option<(usize, RayIntersection<f64>)> {
+        self.0.first_interference_with_ray(ray, max_toi, cost_fn)
+    }
+}
+
+#[test]
+fn broad_phase_default_pairs_within_distance() {
+    let mut broad_phase = BruteForceBroadPhase(DBVTBroadPhase::new(0.4));
+    let d = 0.5;
+
+    let h0 = broad_phase.create_proxy(cube_at_xy(0.0, 0.0), 0);
+    let h1 = broad_phase.create_proxy(cube_at_xy(1.0 + d - 0.01, 0.0), 1);
+    let h2 = broad_phase.create_proxy(cube_at_xy(0.0, 1.0 + d + 0.01), 2);
+    let _ = broad_phase.create_proxy(cube_at_xy(0.0, 10.0), 3);
+    broad_phase.update(&mut NoopHandler);
+
+    let mut pairs = Vec::new();
+    broad_phase.pairs_within_distance(d, &mut pairs);
+    assert_eq!(pairs, vec![(h0, h1)]);
+
+    pairs.clear();
+    broad_phase.pairs_within_distance(d + 0.02, &mut pairs);
+    assert_eq!(pairs, vec![(h0, h1), (h0, h2), (h1, h2)]);
+}
+
+#[test]
+fn broad_phase_default_initial_pairs() {
+    let mut broad_phase = BruteForceBroadPhase(DBVTBroadPhase::new(0.0));
+    let h0 = broad_phase.create_proxy(cube_at_xy(2.0, 0.0), 0);
+    let h1 = broad_phase.create_proxy(cube_at_xy(0.0, 0.0), 1);
+    let h2 = broad_phase.create_proxy(cube_at_xy(1.0, 0.5), 2);
+    let _ = broad_phase.create_proxy(cube_at_xy(0.0, 10.0), 3);
+    broad_phase.update(&mut NoopHandler);
+
+    assert_eq!(broad_phase.initial_pairs(), vec![(h0, h2), (h1, h2)]);
+    assert_eq!(broad_phase.initial_pairs(), broad_phase.0.initial_pairs());
+}
+
+fn grid_cube(i: usize) -> AABB<f64> {
+    // A grid of cubes where consecutive cubes along `x` overlap.
+    let center = Point3::new((i % 5) as f64 * 0.9, (i / 5) as f64 * 2.0, 0.0);
+    let half_extents = Vector3::repeat(0.5);
+    AABB::new(center - half_extents, center + half_extents)
+}
+
+fn initial_object_pairs(order: &[usize]) -> Vec<(usize, usize)> {
+    let mut broad_phase = DBVTBroadPhase::new(0.0f64);
+
+    let mut objects = HashMap::new();
+
+    for i in order {
+        let handle = broad_phase.create_proxy(grid_cube(*i), *i);
+        let _ = objects.insert(handle, *i);
+    }
+
+    let pairs = broad_phase.initial_pairs();
+    let mut sorted = pairs.clone();
+    sorted.sort();
+    assert_eq!(pairs, sorted);
+
+    // Handles depend on the insertion order so compare the pairs of objects instead.
+    let mut object_pairs: Vec<_> = pairs
+        .into_iter()
+        .map(|(h1, h2)| {
+            let (o1, o2) = (objects[&h1], objects[&h2]);
+            (o1.min(o2), o1.max(o2))
+        })
+        .collect();
+    object_pairs.sort();
+    object_pairs
+}
+
+#[test]
+fn broad_phase_initial_pairs_independent_of_insertion_order() {
+    let forward: Vec<_> = (0..20).collect();
+    let shuffled = vec![
+        7, 13, 0, 19, 4, 11, 2, 16, 9, 5, 18, 1, 14, 6, 10, 3, 17, 12, 8, 15,
+    ];
+
+    let expected: Vec<_> = (0..20).filter(|i| i % 5 != 4).map(|i| (i, i + 1)).collect();
+
+    assert_eq!(initial_object_pairs(&forward), expected);
+    assert_eq!(initial_object_pairs(&shuffled), expected);
+}
+
+#[test]
+fn broad_phase_initial_pairs_use_pending_bounding_volumes() {
+    let mut broad_phase = DBVTBroadPhase::new(0.0f64);
+    let h0 = broad_phase.create_proxy(grid_cube(0), 0);
+    let h1 = broad_phase.create_proxy(grid_cube(1), 1);
+    broad_phase.update(&mut NoopHandler);
+    assert_eq!(broad_phase.initial_pairs(), vec![(h0, h1)]);
+
+    // Move the second cube away without updating the broad phase.
+    broad_phase.deferred_set_bounding_volume(h1, grid_cube(3));
+    assert!(broad_phase.initial_pairs().is_empty());
+}
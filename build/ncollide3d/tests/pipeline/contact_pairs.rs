@@ -1,8 +1,11 @@
-use nalgebra::{zero, Isometry3, Translation, Vector3};
-use ncollide3d::{
-    pipeline::{CollisionGroups, CollisionWorld, GeometricQueryType},
-    shape::{Cuboid, ShapeHandle},
+use na::{zero, Isometry3, Point3, Translation, Vector3};
+use ncollide3d::pipeline::{
+    BallBallProximityDetector, CollisionGroups, CollisionObject, CollisionWorld, ContactDispatcher,
+    DefaultContactDispatcher, DefaultProximityDispatcher, GeometricQueryType, NarrowPhase,
+    ProximityDetector, SupportMapSupportMapProximityDetector,
 };
+use ncollide3d::query::{ContactManifold, Proximity};
+use ncollide3d::shape::{Ball, Cuboid, ShapeHandle};
 use std::collections::HashMap;
 
 #[test]
@@ -83,3 +86,133 @@ fn two_colliding_cuboids() {
         }
     }
 }
+
+fn ball_at(x: f64) -> CollisionObject<f64, ()> {
+    CollisionObject::new(
+        None,
+        None,
+        Isometry3::new(Vector3::new(x, 0.0, 0.0), na::zero()),
+        ShapeHandle::new(Ball::new(1.0)),
+        CollisionGroups::new(),
+        GeometricQueryType::Contacts(0.1, 0.0),
+        (),
+    )
+}
+
+#[test]
+fn contact_persists_beyond_prediction() {
+    let mut narrow_phase: NarrowPhase<f64, usize> = NarrowPhase::new(
+        Box::new(DefaultContactDispatcher::new()),
+        Box::new(DefaultProximityDispatcher::new()),
+    );
+    let co1 = ball_at(0.0);
+    let mut detector = DefaultContactDispatcher::new()
+        .get_contact_algorithm(co1.shape().as_ref(), co1.shape().as_ref())
+        .unwrap();
+    let mut manifold = ContactManifold::new();
+    manifold.set_persistence_distance(0.3);
+
+    // Within the prediction distance.
+    narrow_phase.update_contact(&co1, &ball_at(2.05), 0, 1, &mut *detector, &mut manifold);
+    assert_eq!(manifold.len(), 1);
+    let id = manifold.contacts().next().unwrap().id;
+
+    // Past the prediction distance but within the persistence distance.
+    narrow_phase.update_contact(&co1, &ball_at(2.2), 0, 1, &mut *detector, &mut manifold);
+    assert_eq!(manifold.len(), 1);
+    assert_eq!(manifold.contacts().next().unwrap().id, id);
+    assert_relative_eq!(manifold.deepest_contact().unwrap().contact.depth, -0.2);
+
+    // Past both distances.
+    narrow_phase.update_contact(&co1, &ball_at(2.4), 0, 1, &mut *detector, &mut manifold);
+    assert_eq!(manifold.len(), 0);
+
+    // A contact which was not tracked is not created beyond the prediction distance.
+    narrow_phase.update_contact(&co1, &ball_at(2.2), 0, 1, &mut *detector, &mut manifold);
+    assert_eq!(manifold.len(), 0);
+}
+
+#[test]
+fn cuboid_cuboid_proximity_around_margin() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let dispatcher = DefaultProximityDispatcher::new();
+    let mut detector = SupportMapSupportMapProximityDetector::new();
+    let margin = 0.1;
+    let m1 = Isometry3::identity();
+
+    let mut proximity_at = |gap: f64| {
+        let m2 = Isometry3::translation(2.0 + gap, 0.3, 0.0);
+        detector.update(&dispatcher, &m1, &cuboid, &m2, &cuboid, margin)
+    };
+
+    // The detector is reused so that the separating axis of each update seeds the next one.
+    assert_eq!(proximity_at(0.15), Some(Proximity::Disjoint));
+    assert_eq!(proximity_at(0.05), Some(Proximity::WithinMargin));
+    assert_eq!(proximity_at(-0.2), Some(Proximity::Intersecting));
+    assert_eq!(proximity_at(0.09), Some(Proximity::WithinMargin));
+    assert_eq!(proximity_at(0.11), Some(Proximity::Disjoint));
+}
+
+#[test]
+fn ball_ball_witnesses_within_margin() {
+    let ball1 = Ball::new(1.0f64);
+    let ball2 = Ball::new(0.5f64);
+    let dispatcher = DefaultProximityDispatcher::new();
+    let mut detector = BallBallProximityDetector::new();
+    let m1 = Isometry3::translation(1.0, 2.0, 3.0);
+    let dir = Vector3::new(1.0, 2.0, 2.0) / 3.0;
+    let m2 = Isometry3::from_parts((m1.translation.vector + dir * 1.6).into(), na::one());
+
+    // Witnesses are not computed unless enabled.
+    let proximity = detector.update(&dispatcher, &m1, &ball1, &m2, &ball2, 0.2);
+    assert_eq!(proximity, Some(Proximity::WithinMargin));
+    assert!(ProximityDetector::<f64>::witnesses(&detector).is_none());
+
+    ProximityDetector::<f64>::enable_witnesses(&mut detector, true);
+    let proximity = detector.update(&dispatcher, &m1, &ball1, &m2, &ball2, 0.2);
+    assert_eq!(proximity, Some(Proximity::WithinMargin));
+
+    let (pt1, pt2) = ProximityDetector::<f64>::witnesses(&detector).unwrap();
+    let center1 = Point3::from(m1.translation.vector);
+    let center2 = Point3::from(m2.translation.vector);
+    assert_relative_eq!(pt1, center1 + dir * 1.0, epsilon = 1.0e-7);
+    assert_relative_eq!(pt2, center2 - dir * 0.5, epsilon = 1.0e-7);
+
+    let m2 = Isometry3::from_parts((m1.translation.vector + dir * 1.4).into(), na::one());
+    let proximity = detector.update(&dispatcher, &m1, &ball1, &m2, &ball2, 0.2);
+    assert_eq!(proximity, Some(Proximity::Intersecting));
+    assert!(ProximityDetector::<f64>::witnesses(&detector).is_none());
+}
+
+#[test]
+fn support_map_witnesses_within_margin() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let ball = Ball::new(0.5f64);
+    let dispatcher = DefaultProximityDispatcher::new();
+    let mut detector = SupportMapSupportMapProximityDetector::new();
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::translation(1.55, 0.2, -0.3);
+
+    let proximity = detector.update(&dispatcher, &m1, &cuboid, &m2, &ball, 0.1);
+    assert_eq!(proximity, Some(Proximity::WithinMargin));
+    assert!(detector.witnesses().is_none());
+
+    detector.enable_witnesses(true);
+    let proximity = detector.update(&dispatcher, &m1, &cuboid, &m2, &ball, 0.1);
+    assert_eq!(proximity, Some(Proximity::WithinMargin));
+
+    let (pt1, pt2) = detector.witnesses().unwrap();
+    assert_relative_eq!(pt1, Point3::new(1.0, 0.2, -0.3), epsilon = 1.0e-7);
+    assert_relative_eq!(pt2, Point3::new(1.05, 0.2, -0.3), epsilon = 1.0e-7);
+
+    let m2 = Isometry3::translation(1.2, 0.2, -0.3);
+    let proximity = detector.update(&dispatcher, &m1, &cuboid, &m2, &ball, 0.1);
+    assert_eq!(proximity, Some(Proximity::Intersecting));
+    assert!(detector.witnesses().is_none());
+
+    // No witnesses beyond the margin.
+    let m2 = Isometry3::translation(2.0, 0.2, -0.3);
+    let proximity = detector.update(&dispatcher, &m1, &cuboid, &m2, &ball, 0.1);
+    assert_eq!(proximity, Some(Proximity::Disjoint));
+    assert!(detector.witnesses().is_none());
+}
@@ -1,3 +1,7 @@
+use ncollide3d::query::{
+    ClosestPoints, Contact, ContactManifold, PointProjection, RayIntersection, TOI,
+};
+
 // NOTE: the following tests will compile iff. the tested structs are `Send + Sync + 'static`.
 use ncollide3d::world::CollisionWorld;
 
@@ -10,3 +14,15 @@ fn world_is_send_sync() {
     let world = CollisionWorld::<f32, ()>::new(0.0f32);
     assert!(is_send_sync(world));
 }
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn query_results_are_send_sync() {
+    assert_send_sync::<Contact<f32>>();
+    assert_send_sync::<ContactManifold<f32>>();
+    assert_send_sync::<TOI<f32>>();
+    assert_send_sync::<RayIntersection<f32>>();
+    assert_send_sync::<PointProjection<f32>>();
+    assert_send_sync::<ClosestPoints<f32>>();
+}
@@ -1,3 +1,4 @@
+mod broad_phase;
 mod contact_pairs;
 mod duplicate_trimesh_on_world;
 mod is_send_sync;
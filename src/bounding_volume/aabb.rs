@@ -1,6 +1,8 @@
 //! Axis Aligned Bounding Box.
 
-use crate::bounding_volume::{BoundingSphere, BoundingVolume, HasBoundingVolume};
+use crate::bounding_volume::{
+    BoundingSphere, BoundingVolume, BoundingVolumeMeasures, HasBoundingVolume,
+};
 use crate::math::{Isometry, Point, Vector, DIM};
 use crate::utils::IsometryOps;
 use na::{self, RealField};
@@ -76,6 +78,24 @@ impl<N: RealField> AABB<N> {
         super::aabb_utils::local_point_cloud_aabb(pts)
     }
 
+    /// Creates a new AABB from the points yielded by an iterator, in a single pass.
+    ///
+    /// Returns `None` if the iterator is empty.
+    pub fn try_from_points<I>(pts: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Point<N>>,
+    {
+        let mut it = pts.into_iter();
+        let first = it.next()?;
+        let mut result = AABB::new(first, first);
+
+        for pt in it {
+            result.take_point(pt);
+        }
+
+        Some(result)
+    }
+
     /// Reference to the AABB point with the smallest components along each axis.
     #[inline]
     #[deprecated(note = "use the `.mins` public field instead.")]
@@ -115,6 +135,27 @@ impl<N: RealField> AABB<N> {
         self.maxs = self.maxs.coords.sup(&pt.coords).into();
     }
 
+    /// Splits this AABB along the given axis at the coordinate `coord`.
+    ///
+    /// Returns the lower and upper halves, i.e., the parts of `self` with a coordinate along `axis`
+    /// respectively smaller and greater than `coord`. The coordinate is clamped into the extent of
+    /// `self` along `axis` so splitting outside of this AABB yields one flat AABB and one AABB
+    /// equal to `self`.
+    ///
+    /// # Panics
+    /// Panics if `axis` is not smaller than the dimension of the space.
+    #[inline]
+    pub fn split_at(&self, axis: usize, coord: N) -> (AABB<N>, AABB<N>) {
+        let coord = na::clamp(coord, self.mins[axis], self.maxs[axis]);
+
+        let mut lower = *self;
+        let mut upper = *self;
+        lower.maxs[axis] = coord;
+        upper.mins[axis] = coord;
+
+        (lower, upper)
+    }
+
     /// Computes the AABB bounding `self` transformed by `m`.
     #[inline]
     pub fn transform_by(&self, m: &Isometry<N>) -> Self {
@@ -144,6 +185,49 @@ impl<N: RealField> AABB<N> {
 
         true
     }
+
+    /// The point of this AABB closest to `point`.
+    ///
+    /// This is `point` itself if it is inside of this AABB. Otherwise, it is obtained by clamping
+    /// each coordinate of `point` to the AABB bounds.
+    #[inline]
+    pub fn closest_local_point(&self, point: &Point<N>) -> Point<N> {
+        point.sup(&self.mins).inf(&self.maxs)
+    }
+
+    /// The distance between `point` and this AABB.
+    ///
+    /// This is zero if `point` is inside of this AABB.
+    #[inline]
+    pub fn distance_to_local_point(&self, point: &Point<N>) -> N {
+        na::distance(point, &self.closest_local_point(point))
+    }
+
+    /// The intersection of this AABB and `other`, if they intersect.
+    ///
+    /// AABBs touching each other yield a flat intersection instead of `None`.
+    #[inline]
+    pub fn intersection(&self, other: &AABB<N>) -> Option<AABB<N>> {
+        let result = AABB::new(self.mins.sup(&other.mins), self.maxs.inf(&other.maxs));
+
+        for i in 0..DIM {
+            if result.mins[i] > result.maxs[i] {
+                return None;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// The volume (area in 2D) of the intersection of this AABB and `other`.
+    ///
+    /// This is zero if the AABBs do not intersect or only touch each other.
+    #[inline]
+    pub fn intersection_volume(&self, other: &AABB<N>) -> N {
+        self.intersection(other)
+            .map(|inter| inter.extents().iter().fold(N::one(), |acc, e| acc * *e))
+            .unwrap_or_else(N::zero)
+    }
 }
 
 impl<N: RealField> BoundingVolume<N> for AABB<N> {
@@ -225,3 +309,25 @@ impl<N: RealField> BoundingVolume<N> for AABB<N> {
         )
     }
 }
+
+impl<N: RealField> BoundingVolumeMeasures<N> for AABB<N> {
+    #[inline]
+    fn surface_area(&self) -> N {
+        let extents = self.extents();
+
+        #[cfg(feature = "dim2")]
+        {
+            (extents.x + extents.y) * na::convert(2.0)
+        }
+        #[cfg(feature = "dim3")]
+        {
+            (extents.x * extents.y + extents.y * extents.z + extents.z * extents.x)
+                * na::convert(2.0)
+        }
+    }
+
+    #[inline]
+    fn volume(&self) -> N {
+        self.extents().iter().fold(N::one(), |acc, e| acc * *e)
+    }
+}
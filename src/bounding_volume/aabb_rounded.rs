@@ -0,0 +1,18 @@
+use crate::bounding_volume::{BoundingVolume, HasBoundingVolume, AABB};
+use crate::math::Isometry;
+use crate::shape::Rounded;
+use na::RealField;
+
+impl<S: HasBoundingVolume<N, AABB<N>>, N: RealField> HasBoundingVolume<N, AABB<N>>
+    for Rounded<S, N>
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> AABB<N> {
+        self.shape.bounding_volume(m).loosened(self.radius)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> AABB<N> {
+        self.shape.local_bounding_volume().loosened(self.radius)
+    }
+}
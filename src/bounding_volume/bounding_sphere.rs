@@ -1,6 +1,6 @@
 //! Bounding sphere.
 
-use crate::bounding_volume::{BoundingVolume, HasBoundingVolume};
+use crate::bounding_volume::{BoundingVolume, BoundingVolumeMeasures, HasBoundingVolume};
 use crate::math::{Isometry, Point};
 use na::{self, RealField};
 
@@ -165,3 +165,29 @@ impl<N: RealField> BoundingVolume<N> for BoundingSphere<N> {
         BoundingSphere::new(self.center, self.radius - amount)
     }
 }
+
+impl<N: RealField> BoundingVolumeMeasures<N> for BoundingSphere<N> {
+    #[inline]
+    fn surface_area(&self) -> N {
+        #[cfg(feature = "dim2")]
+        {
+            N::two_pi() * self.radius
+        }
+        #[cfg(feature = "dim3")]
+        {
+            N::pi() * self.radius * self.radius * na::convert(4.0)
+        }
+    }
+
+    #[inline]
+    fn volume(&self) -> N {
+        #[cfg(feature = "dim2")]
+        {
+            N::pi() * self.radius * self.radius
+        }
+        #[cfg(feature = "dim3")]
+        {
+            N::pi() * self.radius * self.radius * self.radius * na::convert(4.0 / 3.0)
+        }
+    }
+}
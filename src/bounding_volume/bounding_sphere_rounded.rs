@@ -0,0 +1,18 @@
+use crate::bounding_volume::{BoundingSphere, BoundingVolume, HasBoundingVolume};
+use crate::math::Isometry;
+use crate::shape::Rounded;
+use na::RealField;
+
+impl<S: HasBoundingVolume<N, BoundingSphere<N>>, N: RealField>
+    HasBoundingVolume<N, BoundingSphere<N>> for Rounded<S, N>
+{
+    #[inline]
+    fn bounding_volume(&self, m: &Isometry<N>) -> BoundingSphere<N> {
+        self.shape.bounding_volume(m).loosened(self.radius)
+    }
+
+    #[inline]
+    fn local_bounding_volume(&self) -> BoundingSphere<N> {
+        self.shape.local_bounding_volume().loosened(self.radius)
+    }
+}
@@ -1,5 +1,5 @@
-use crate::bounding_volume::{BoundingSphere, HasBoundingVolume};
-use crate::math::Isometry;
+use crate::bounding_volume::{BoundingSphere, BoundingVolume, HasBoundingVolume};
+use crate::math::{Isometry, Point};
 use crate::shape::Shape;
 use na::RealField;
 
@@ -14,3 +14,18 @@ impl<N: RealField> HasBoundingVolume<N, BoundingSphere<N>> for dyn Shape<N> {
         self.local_bounding_sphere()
     }
 }
+
+/// Computes a bounding sphere of a set of shapes, each transformed by its own isometry.
+///
+/// This merges the world-space bounding spheres of all the shapes. Returns a sphere with a zero
+/// radius centered at the origin if `shapes` is empty.
+pub fn bounding_sphere_of_shapes<N: RealField>(
+    shapes: &[(&dyn Shape<N>, Isometry<N>)],
+) -> BoundingSphere<N> {
+    let mut it = shapes.iter().map(|(g, m)| g.bounding_sphere(m));
+
+    match it.next() {
+        Some(first) => it.fold(first, |acc, bs| acc.merged(&bs)),
+        None => BoundingSphere::new(Point::origin(), N::zero()),
+    }
+}
@@ -46,3 +46,14 @@ pub trait BoundingVolume<N: RealField>: std::fmt::Debug {
     /// Creates a new, tightened version, of this bounding volume.
     fn tightened(&self, _: N) -> Self;
 }
+
+/// Measures of a bounding volume.
+///
+/// This is typically used by surface area heuristics when building bounding volume trees.
+pub trait BoundingVolumeMeasures<N: RealField>: BoundingVolume<N> {
+    /// The surface area of this bounding volume, i.e., its perimeter in 2D.
+    fn surface_area(&self) -> N;
+
+    /// The volume of this bounding volume, i.e., its area in 2D.
+    fn volume(&self) -> N;
+}
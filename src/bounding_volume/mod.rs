@@ -1,6 +1,8 @@
 //! Bounding volumes.
 
 pub use self::circular_cone::CircularCone;
+#[cfg(feature = "dim3")]
+pub use self::polyhedral_cone::PolyhedralCone;
 pub use self::spatialized_normal_cone::SpatializedNormalCone;
 #[doc(inline)]
 pub use crate::bounding_volume::aabb::{aabb, local_aabb, AABB};
@@ -12,11 +14,16 @@ pub use crate::bounding_volume::aabb_utils::{
 pub use crate::bounding_volume::bounding_sphere::{
     bounding_sphere, local_bounding_sphere, BoundingSphere,
 };
+pub use crate::bounding_volume::bounding_sphere_shape::bounding_sphere_of_shapes;
 pub use crate::bounding_volume::bounding_sphere_utils::{
     point_cloud_bounding_sphere, point_cloud_bounding_sphere_with_center,
 };
 #[doc(inline)]
-pub use crate::bounding_volume::bounding_volume::{BoundingVolume, HasBoundingVolume};
+pub use crate::bounding_volume::bounding_volume::{
+    BoundingVolume, BoundingVolumeMeasures, HasBoundingVolume,
+};
+#[doc(inline)]
+pub use crate::bounding_volume::obb::{bounding_obb, OBB};
 
 #[doc(hidden)]
 pub mod bounding_volume;
@@ -33,6 +40,7 @@ mod aabb_cuboid;
 mod aabb_heightfield;
 mod aabb_plane;
 mod aabb_polyline;
+mod aabb_rounded;
 mod aabb_shape;
 mod aabb_support_map;
 mod aabb_triangle;
@@ -57,6 +65,7 @@ mod bounding_sphere_cylinder;
 mod bounding_sphere_heightfield;
 mod bounding_sphere_plane;
 mod bounding_sphere_polyline;
+mod bounding_sphere_rounded;
 mod bounding_sphere_segment;
 mod bounding_sphere_shape;
 mod bounding_sphere_triangle;
@@ -64,5 +73,10 @@ mod bounding_sphere_triangle;
 mod bounding_sphere_trimesh;
 mod bounding_sphere_utils;
 
+#[doc(hidden)]
+pub mod obb;
+
 pub(crate) mod circular_cone;
+#[cfg(feature = "dim3")]
+mod polyhedral_cone;
 mod spatialized_normal_cone;
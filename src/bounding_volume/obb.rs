@@ -0,0 +1,262 @@
+//! Oriented Bounding Box.
+
+use crate::bounding_volume::{BoundingVolume, BoundingVolumeMeasures, AABB};
+use crate::math::{Isometry, Point, Rotation, Vector, DIM};
+use crate::shape::SupportMap;
+use na::{self, RealField};
+
+/// Computes the oriented bounding box of a support-mapped shape `g` transformed by `m`.
+///
+/// The box axes are the axes of the shape local frame, i.e., the columns of the rotation part of
+/// `m`. No principal component analysis is performed, so this is the tightest OBB only for
+/// shapes aligned with their local frame, e.g., cuboids, capsules, cylinders, and cones.
+pub fn bounding_obb<N, G>(g: &G, m: &Isometry<N>) -> OBB<N>
+where
+    N: RealField,
+    G: SupportMap<N>,
+{
+    let local_aabb = crate::bounding_volume::local_support_map_aabb(g);
+    OBB::from_aabb(&local_aabb, m)
+}
+
+/// An Oriented Bounding Box.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct OBB<N: RealField> {
+    /// The center of the box.
+    pub center: Point<N>,
+    /// The orientation of the box axes.
+    pub rotation: Rotation<N>,
+    /// The half-width of the box along each of its axes.
+    pub half_extents: Vector<N>,
+}
+
+impl<N: RealField> OBB<N> {
+    /// Creates a new OBB from its center, orientation, and half-extents.
+    #[inline]
+    pub fn new(center: Point<N>, rotation: Rotation<N>, half_extents: Vector<N>) -> Self {
+        OBB {
+            center,
+            rotation,
+            half_extents,
+        }
+    }
+
+    /// Creates the OBB resulting from the transformation of `aabb` by `m`.
+    #[inline]
+    pub fn from_aabb(aabb: &AABB<N>, m: &Isometry<N>) -> Self {
+        OBB::new(m * aabb.center(), m.rotation, aabb.half_extents())
+    }
+
+    /// The position and orientation of this OBB.
+    #[inline]
+    pub fn position(&self) -> Isometry<N> {
+        Isometry::from_parts(self.center.coords.into(), self.rotation)
+    }
+
+    /// The world-space unit vectors of the OBB axes.
+    #[inline]
+    pub fn axes(&self) -> [Vector<N>; DIM] {
+        let mut axes = [Vector::zeros(); DIM];
+
+        for i in 0..DIM {
+            let mut basis = Vector::zeros();
+            basis[i] = N::one();
+            axes[i] = self.rotation * basis;
+        }
+
+        axes
+    }
+
+    /// The vertices of this OBB.
+    pub fn vertices(&self) -> Vec<Point<N>> {
+        let axes = self.axes();
+
+        (0..1usize << DIM)
+            .map(|id| {
+                let mut pt = self.center;
+
+                for (i, (axis, extent)) in axes.iter().zip(self.half_extents.iter()).enumerate() {
+                    if id & (1 << i) == 0 {
+                        pt += axis * *extent;
+                    } else {
+                        pt -= axis * *extent;
+                    }
+                }
+
+                pt
+            })
+            .collect()
+    }
+
+    /// The smallest AABB containing this OBB.
+    #[inline]
+    pub fn aabb(&self) -> AABB<N> {
+        AABB::new(
+            Point::origin() + (-self.half_extents),
+            Point::from(self.half_extents),
+        )
+        .transform_by(&self.position())
+    }
+
+    /// Checks if the given point is inside of this OBB.
+    #[inline]
+    pub fn contains_point(&self, point: &Point<N>) -> bool {
+        let local = self
+            .rotation
+            .inverse_transform_vector(&(point - self.center));
+
+        for i in 0..DIM {
+            if local[i].abs() > self.half_extents[i] {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Half-length of the projection of this OBB on the (not necessarily normalized) `axis`.
+    #[inline]
+    fn projected_radius(&self, axes: &[Vector<N>; DIM], axis: &Vector<N>) -> N {
+        axes.iter()
+            .zip(self.half_extents.iter())
+            .fold(N::zero(), |acc, (a, e)| acc + a.dot(axis).abs() * *e)
+    }
+
+    /// Checks if `axis` separates `self` from `other`.
+    #[inline]
+    fn is_separating_axis(
+        &self,
+        axes1: &[Vector<N>; DIM],
+        other: &OBB<N>,
+        axes2: &[Vector<N>; DIM],
+        axis: &Vector<N>,
+    ) -> bool {
+        let dist = (other.center - self.center).dot(axis).abs();
+        dist > self.projected_radius(axes1, axis) + other.projected_radius(axes2, axis)
+    }
+
+    /// Computes a separating axis between `self` and `other`, if any.
+    ///
+    /// The returned axis is not normalized.
+    pub fn separating_axis(&self, other: &OBB<N>) -> Option<Vector<N>> {
+        let axes1 = self.axes();
+        let axes2 = other.axes();
+
+        for axis in axes1.iter().chain(axes2.iter()) {
+            if self.is_separating_axis(&axes1, other, &axes2, axis) {
+                return Some(*axis);
+            }
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            // Edge-edge axes. Those are close to zero when the edges are nearly parallel, in
+            // which case the test above already covered them, and the projections on those
+            // axes would be dominated by rounding errors.
+            let eps = N::default_epsilon().sqrt();
+
+            for a1 in axes1.iter() {
+                for a2 in axes2.iter() {
+                    let axis = a1.cross(a2);
+
+                    if axis.norm_squared() > eps
+                        && self.is_separating_axis(&axes1, other, &axes2, &axis)
+                    {
+                        return Some(axis);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<N: RealField> BoundingVolume<N> for OBB<N> {
+    #[inline]
+    fn center(&self) -> Point<N> {
+        self.center
+    }
+
+    #[inline]
+    fn intersects(&self, other: &OBB<N>) -> bool {
+        self.separating_axis(other).is_none()
+    }
+
+    #[inline]
+    fn contains(&self, other: &OBB<N>) -> bool {
+        other.vertices().iter().all(|pt| self.contains_point(pt))
+    }
+
+    #[inline]
+    fn merge(&mut self, other: &OBB<N>) {
+        *self = self.merged(other)
+    }
+
+    /// Merges this OBB with another one.
+    ///
+    /// The result keeps the orientation of `self` so it is not the smallest OBB containing both.
+    #[inline]
+    fn merged(&self, other: &OBB<N>) -> OBB<N> {
+        let pos = self.position();
+        let mut local_aabb = AABB::new(
+            Point::origin() + (-self.half_extents),
+            Point::from(self.half_extents),
+        );
+
+        for pt in other.vertices() {
+            local_aabb.take_point(pos.inverse_transform_point(&pt));
+        }
+
+        OBB::from_aabb(&local_aabb, &pos)
+    }
+
+    #[inline]
+    fn loosen(&mut self, amount: N) {
+        assert!(
+            amount >= na::zero(),
+            "The loosening margin must be positive."
+        );
+        self.half_extents += Vector::repeat(amount);
+    }
+
+    #[inline]
+    fn loosened(&self, amount: N) -> OBB<N> {
+        let mut res = *self;
+        res.loosen(amount);
+        res
+    }
+
+    #[inline]
+    fn tighten(&mut self, amount: N) {
+        assert!(
+            amount >= na::zero(),
+            "The tightening margin must be positive."
+        );
+        self.half_extents -= Vector::repeat(amount);
+        assert!(
+            self.half_extents.iter().all(|e| *e >= N::zero()),
+            "The tightening margin is to large."
+        );
+    }
+
+    #[inline]
+    fn tightened(&self, amount: N) -> OBB<N> {
+        let mut res = *self;
+        res.tighten(amount);
+        res
+    }
+}
+
+impl<N: RealField> BoundingVolumeMeasures<N> for OBB<N> {
+    #[inline]
+    fn surface_area(&self) -> N {
+        AABB::from_half_extents(Point::origin(), self.half_extents).surface_area()
+    }
+
+    #[inline]
+    fn volume(&self) -> N {
+        AABB::from_half_extents(Point::origin(), self.half_extents).volume()
+    }
+}
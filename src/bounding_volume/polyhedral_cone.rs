@@ -0,0 +1,269 @@
+use crate::math::Vector;
+use na::{Matrix3, Point2, RealField, Unit};
+
+/// A convex cone with its apex at the origin, spanned by a finite set of directions.
+///
+/// The cone contains all the nonnegative linear combinations of its generators. It is usually
+/// used to represent the normal cone of a polyhedral feature, e.g., the set of normals of the
+/// faces adjacent to a mesh vertex.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PolyhedralCone<N: RealField> {
+    generators: Vec<Unit<Vector<N>>>,
+    // Whether all the generators lie strictly on the same side of a plane. If so, `generators`
+    // contains only the edges of this cone, ordered counterclockwise around its axis.
+    pointed: bool,
+}
+
+impl<N: RealField> PolyhedralCone<N> {
+    /// Creates the cone spanned by the given directions.
+    ///
+    /// If `generators` is empty, the cone contains only the zero vector.
+    pub fn new(generators: Vec<Unit<Vector<N>>>) -> Self {
+        let mut res = PolyhedralCone {
+            generators,
+            pointed: false,
+        };
+        res.order_generators();
+        res
+    }
+
+    /// Replaces the directions spanning this cone, reusing its allocated storage.
+    pub fn set_generators(&mut self, generators: impl IntoIterator<Item = Unit<Vector<N>>>) {
+        self.generators.clear();
+        self.generators.extend(generators);
+        self.order_generators();
+    }
+
+    /// The directions spanning this cone.
+    ///
+    /// If all the generators lie strictly on the same side of a plane, only the edges of this
+    /// cone are kept, and they are ordered counterclockwise around its axis.
+    #[inline]
+    pub fn generators(&self) -> &[Unit<Vector<N>>] {
+        &self.generators
+    }
+
+    // Sorts the edges of this cone around its axis and removes the generators that are not
+    // edges, using the convex hull of the generators projected on a plane orthogonal to the
+    // axis.
+    fn order_generators(&mut self) {
+        self.pointed = false;
+
+        let sum = self
+            .generators
+            .iter()
+            .fold(Vector::zeros(), |sum, g| sum + **g);
+        let axis = match Unit::try_new(sum, N::default_epsilon().sqrt()) {
+            Some(axis) => axis,
+            None => return,
+        };
+
+        if self
+            .generators
+            .iter()
+            .any(|g| g.dot(&axis) <= N::default_epsilon().sqrt())
+        {
+            return;
+        }
+
+        self.pointed = true;
+
+        if self.generators.len() < 3 {
+            return;
+        }
+
+        // A direct orthonormal basis of the plane orthogonal to the axis, so that the
+        // counterclockwise order of the projections matches the order around the axis.
+        let mut u = Vector::zeros();
+        Vector::orthonormal_subspace_basis(&[*axis], |e| {
+            u = *e;
+            false
+        });
+        let basis = [u, axis.cross(&u)];
+
+        let mut projs: Vec<(Point2<N>, Unit<Vector<N>>)> = self
+            .generators
+            .iter()
+            .map(|g| {
+                let scale = g.dot(&axis);
+                let proj = Point2::new(g.dot(&basis[0]) / scale, g.dot(&basis[1]) / scale);
+                (proj, *g)
+            })
+            .collect();
+
+        // Andrew's monotone chain, keeping only the strictly convex vertices.
+        projs.sort_by(|a, b| {
+            (a.0.x, a.0.y)
+                .partial_cmp(&(b.0.x, b.0.y))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let eps = N::default_epsilon();
+        let turns_left = |a: &Point2<N>, b: &Point2<N>, c: &Point2<N>| (b - a).perp(&(c - a)) > eps;
+        let mut hull: Vec<(Point2<N>, Unit<Vector<N>>)> = Vec::with_capacity(projs.len() + 1);
+
+        for pass in 0..2 {
+            let start = hull.len();
+
+            for p in projs.iter() {
+                while hull.len() >= start + 2
+                    && !turns_left(&hull[hull.len() - 2].0, &hull[hull.len() - 1].0, &p.0)
+                {
+                    let _ = hull.pop();
+                }
+
+                hull.push(*p);
+            }
+
+            // The last point of each chain is the first point of the other one.
+            let _ = hull.pop();
+
+            if pass == 0 {
+                projs.reverse();
+            }
+        }
+
+        if hull.is_empty() {
+            // All the generators are equal.
+            hull.push(projs[0]);
+        }
+
+        self.generators.clear();
+        self.generators.extend(hull.into_iter().map(|p| p.1));
+    }
+
+    /// Computes the point of this cone closest to `v`.
+    ///
+    /// This returns `v` itself if it is contained by this cone. If all the generators lie strictly
+    /// on the same side of a plane, this takes a time linear wrt. the number of generators.
+    pub fn project(&self, v: &Vector<N>) -> Vector<N> {
+        let gens = &self.generators;
+
+        if !self.pointed {
+            return self.project_unordered(v);
+        }
+
+        let n = gens.len();
+
+        if n >= 3 && (0..n).all(|i| gens[i].cross(&gens[(i + 1) % n]).dot(v) >= N::zero()) {
+            return *v;
+        }
+
+        // The projection lies on the boundary of the cone: either on its apex, on an edge, or on
+        // a face spanned by two consecutive edges.
+        let mut best = Vector::zeros();
+        let mut best_sq_dist = v.norm_squared();
+        let npairs = if n >= 3 { n } else { n.saturating_sub(1) };
+
+        for g in gens.iter() {
+            Self::project_on_half_line(v, g, &mut best, &mut best_sq_dist);
+        }
+
+        for i in 0..npairs {
+            Self::project_on_sector(
+                v,
+                &gens[i],
+                &gens[(i + 1) % n],
+                &mut best,
+                &mut best_sq_dist,
+            );
+        }
+
+        best
+    }
+
+    // Projection on a cone with generators in arbitrary positions.
+    fn project_unordered(&self, v: &Vector<N>) -> Vector<N> {
+        let gens = &self.generators;
+        let eps = N::default_epsilon();
+
+        // By Caratheodory's theorem, any vector of the cone is a nonnegative combination of at
+        // most three generators.
+        for i in 0..gens.len() {
+            for j in i + 1..gens.len() {
+                for k in j + 1..gens.len() {
+                    let mat = Matrix3::from_columns(&[*gens[i], *gens[j], *gens[k]]);
+
+                    if mat.determinant().abs() > eps {
+                        if let Some(inv) = mat.try_inverse() {
+                            if (inv * v).iter().all(|c| *c >= N::zero()) {
+                                return *v;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Otherwise, the projection lies on the boundary of the cone: either on its apex, on a
+        // half-line spanned by one generator, or on a sector spanned by two generators.
+        let mut best = Vector::zeros();
+        let mut best_sq_dist = v.norm_squared();
+
+        for (i, gi) in gens.iter().enumerate() {
+            Self::project_on_half_line(v, gi, &mut best, &mut best_sq_dist);
+
+            for gj in &gens[i + 1..] {
+                Self::project_on_sector(v, gi, gj, &mut best, &mut best_sq_dist);
+            }
+        }
+
+        best
+    }
+
+    fn project_on_half_line(
+        v: &Vector<N>,
+        g: &Unit<Vector<N>>,
+        best: &mut Vector<N>,
+        best_sq_dist: &mut N,
+    ) {
+        let d = g.dot(v);
+
+        if d > N::zero() && v.norm_squared() - d * d < *best_sq_dist {
+            *best = **g * d;
+            *best_sq_dist = v.norm_squared() - d * d;
+        }
+    }
+
+    fn project_on_sector(
+        v: &Vector<N>,
+        gi: &Unit<Vector<N>>,
+        gj: &Unit<Vector<N>>,
+        best: &mut Vector<N>,
+        best_sq_dist: &mut N,
+    ) {
+        let di = gi.dot(v);
+        let dj = gj.dot(v);
+        let c = gi.dot(gj);
+        let det = N::one() - c * c;
+
+        if det > N::default_epsilon() {
+            let a = (di - c * dj) / det;
+            let b = (dj - c * di) / det;
+
+            if a >= N::zero() && b >= N::zero() {
+                let proj = **gi * a + **gj * b;
+                let sq_dist = (v - proj).norm_squared();
+
+                if sq_dist < *best_sq_dist {
+                    *best = proj;
+                    *best_sq_dist = sq_dist;
+                }
+            }
+        }
+    }
+
+    /// Tests if the direction `dir` is at a distance smaller than `eps` from this cone.
+    pub fn contains_dir(&self, dir: &Unit<Vector<N>>, eps: N) -> bool {
+        (**dir - self.project(dir)).norm() <= eps
+    }
+
+    /// The direction of the point of this cone closest to `dir`.
+    ///
+    /// Returns `None` if the closest point is the apex of this cone, i.e., if `dir` forms an
+    /// obtuse angle with every generator.
+    pub fn clamp_dir(&self, dir: &Unit<Vector<N>>) -> Option<Unit<Vector<N>>> {
+        Unit::try_new(self.project(dir), N::default_epsilon())
+    }
+}
@@ -1,8 +1,9 @@
 //! Interpolation of the motion of an object.
 
 pub use self::rigid_motion::{
-    ConstantLinearVelocityRigidMotion, ConstantVelocityRigidMotion, InterpolatedRigidMotion,
-    RigidMotion, RigidMotionComposition,
+    ArcInterpolatedRigidMotion, ConstantLinearVelocityRigidMotion, ConstantVelocityRigidMotion,
+    EasedInterpolatedRigidMotion, InterpolatedRigidMotion, RigidMotion, RigidMotionComposition,
+    RigidMotionSamples,
 };
 
 mod rigid_motion;
@@ -1,6 +1,6 @@
-use na::RealField;
+use na::{self, RealField, Unit};
 
-use crate::math::{Isometry, Point, Translation, Vector};
+use crate::math::{AngularVector, Isometry, Point, Translation, Vector};
 
 /// A continuous rigid motion.
 ///
@@ -10,12 +10,133 @@ use crate::math::{Isometry, Point, Translation, Vector};
 pub trait RigidMotion<N: RealField> {
     /// Get a position at the time `t`.
     fn position_at_time(&self, t: N) -> Isometry<N>;
+
+    /// An upper bound of the speed of any point at a distance smaller than `shape_radius` from
+    /// the local-space origin of a shape following this motion.
+    ///
+    /// This is `linear_speed + angular_speed * shape_radius` for the built-in motions and can be
+    /// used to size the steps of a conservative advancement. The default implementation does not
+    /// know any bound and returns `N::max_value()`.
+    fn max_point_velocity(&self, shape_radius: N) -> N {
+        let _ = shape_radius;
+        N::max_value()
+    }
+
+    /// The linear and angular velocities of this motion at the time `t`.
+    ///
+    /// The linear velocity is the one of the local-space origin of the moving frame. The default implementation uses central finite
+    /// differences of `self.position_at_time` with a step `h = cbrt(N::default_epsilon()) *
+    /// max(1, |t|)`, i.e., relative to `t` for large times. The evaluation times `t - h` and
+    /// `t + h` are clamped to `self.valid_interval()` so the difference becomes one-sided at
+    /// the ends of this interval.
+    fn velocity_at_time(&self, t: N) -> (Vector<N>, AngularVector<N>) {
+        let (tmin, tmax) = self.valid_interval();
+        let h = N::default_epsilon().cbrt() * t.abs().max(N::one());
+        let t0 = (t - h).max(tmin);
+        let t1 = (t + h).min(tmax);
+        let dt = t1 - t0;
+
+        if dt <= N::zero() {
+            return (Vector::zeros(), na::zero());
+        }
+
+        let pos0 = self.position_at_time(t0);
+        let pos1 = self.position_at_time(t1);
+        let linvel = (pos1.translation.vector - pos0.translation.vector) / dt;
+        let delta_rot = pos1.rotation * pos0.rotation.inverse();
+
+        #[cfg(feature = "dim2")]
+        let angvel = delta_rot.angle() / dt;
+        #[cfg(feature = "dim3")]
+        let angvel = delta_rot.scaled_axis() / dt;
+
+        (linvel, angvel)
+    }
+
+    /// The interval of times `(tmin, tmax)` for which this motion is defined.
+    ///
+    /// The default implementation returns `(-N::max_value(), N::max_value())`, i.e., the motion
+    /// is defined for any time, which is the case for all the built-in motions.
+    fn valid_interval(&self) -> (N, N) {
+        (-N::max_value(), N::max_value())
+    }
+
+    /// Iterates through `n` positions of this motion at evenly spaced times between `t0` and `t1`.
+    ///
+    /// Both `t0` and `t1` are included. If `n == 1` then only the position at `t0` is yielded.
+    fn sample(&self, t0: N, t1: N, n: usize) -> RigidMotionSamples<N, Self>
+    where
+        Self: Sized,
+    {
+        RigidMotionSamples {
+            motion: self,
+            t0,
+            t1,
+            n,
+            curr: 0,
+        }
+    }
 }
 
+/// An iterator through evenly spaced positions of a rigid motion.
+///
+/// This is created by `RigidMotion::sample`.
+pub struct RigidMotionSamples<'a, N: RealField, M: ?Sized> {
+    motion: &'a M,
+    t0: N,
+    t1: N,
+    n: usize,
+    curr: usize,
+}
+
+impl<'a, N: RealField, M: ?Sized + RigidMotion<N>> Iterator for RigidMotionSamples<'a, N, M> {
+    type Item = (N, Isometry<N>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.curr >= self.n {
+            return None;
+        }
+
+        let t = if self.curr == 0 {
+            self.t0
+        } else if self.curr == self.n - 1 {
+            self.t1
+        } else {
+            let ratio = na::convert::<f64, N>(self.curr as f64 / (self.n - 1) as f64);
+            self.t0 + (self.t1 - self.t0) * ratio
+        };
+
+        self.curr += 1;
+        Some((t, self.motion.position_at_time(t)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.n - self.curr;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, N: RealField, M: ?Sized + RigidMotion<N>> ExactSizeIterator
+    for RigidMotionSamples<'a, N, M>
+{
+}
+
+/// A static rigid motion: the position is `self` at any time.
+///
+/// This is the motion to use for static objects. It is cheaper than a
+/// `ConstantVelocityRigidMotion` with zero velocities.
 impl<N: RealField> RigidMotion<N> for Isometry<N> {
     fn position_at_time(&self, _: N) -> Isometry<N> {
         *self
     }
+
+    fn max_point_velocity(&self, _: N) -> N {
+        N::zero()
+    }
+
+    fn velocity_at_time(&self, _: N) -> (Vector<N>, AngularVector<N>) {
+        (Vector::zeros(), na::zero())
+    }
 }
 
 /// Interpolation between two isometries using LERP for the translation part and SLERP for the rotation.
@@ -40,6 +161,113 @@ impl<N: RealField> RigidMotion<N> for InterpolatedRigidMotion<N> {
     fn position_at_time(&self, t: N) -> Isometry<N> {
         self.start.lerp_slerp(&self.end, t)
     }
+
+    fn max_point_velocity(&self, shape_radius: N) -> N {
+        let linvel = (self.end.translation.vector - self.start.translation.vector).norm();
+        let angvel = self.start.rotation.angle_to(&self.end.rotation).abs();
+
+        linvel + angvel * shape_radius
+    }
+}
+
+/// Interpolation between two isometries using LERP for the translation part and SLERP for the
+/// rotation, with a separate easing function applied to the interpolation parameter of each part.
+///
+/// At the time `t`, the translation part is interpolated with the parameter
+/// `translation_easing(t)` and the rotation part with the parameter `rotation_easing(t)`. Both
+/// eased parameters are clamped to `[0, 1]`.
+pub struct EasedInterpolatedRigidMotion<'a, N: RealField> {
+    /// The transformation at `t = 0.0`.
+    pub start: Isometry<N>,
+    /// The transformation at `t = 1.0`.
+    pub end: Isometry<N>,
+    translation_easing: Box<dyn Fn(N) -> N + 'a>,
+    rotation_easing: Box<dyn Fn(N) -> N + 'a>,
+}
+
+impl<'a, N: RealField> EasedInterpolatedRigidMotion<'a, N> {
+    /// Initialize an eased lerp-slerp interpolation with the given start and end transformations,
+    /// and the easing functions of the translation and rotation parts.
+    ///
+    /// The `start` is the transformation at the time `t = 0.0` and `end` is the transformation at
+    /// the time `t = 1.0`.
+    pub fn new(
+        start: Isometry<N>,
+        end: Isometry<N>,
+        translation_easing: impl Fn(N) -> N + 'a,
+        rotation_easing: impl Fn(N) -> N + 'a,
+    ) -> Self {
+        EasedInterpolatedRigidMotion {
+            start,
+            end,
+            translation_easing: Box::new(translation_easing),
+            rotation_easing: Box::new(rotation_easing),
+        }
+    }
+}
+
+impl<'a, N: RealField> RigidMotion<N> for EasedInterpolatedRigidMotion<'a, N> {
+    fn position_at_time(&self, t: N) -> Isometry<N> {
+        let t_translation = na::clamp((self.translation_easing)(t), N::zero(), N::one());
+        let t_rotation = na::clamp((self.rotation_easing)(t), N::zero(), N::one());
+
+        let translation = self
+            .start
+            .translation
+            .vector
+            .lerp(&self.end.translation.vector, t_translation);
+        let rotation = self.start.lerp_slerp(&self.end, t_rotation).rotation;
+
+        Isometry::from_parts(translation.into(), rotation)
+    }
+}
+
+/// Interpolation between two isometries where the translation part follows a circular arc around a
+/// pivot point, and the rotation part uses SLERP.
+///
+/// The distance to the pivot is linearly interpolated between the start and end positions. If
+/// the start or end position coincides with the pivot, or if they are diametrically opposite wrt.
+/// the pivot, the arc is not well-defined and the translation part uses LERP instead.
+pub struct ArcInterpolatedRigidMotion<N: RealField> {
+    /// The transformation at `t = 0.0`.
+    pub start: Isometry<N>,
+    /// The transformation at `t = 1.0`.
+    pub end: Isometry<N>,
+    /// The center of the arc followed by the translation part.
+    pub pivot: Point<N>,
+}
+
+impl<N: RealField> ArcInterpolatedRigidMotion<N> {
+    /// Initialize an arc-slerp interpolation with the given start and end transformations, and
+    /// the given arc center.
+    ///
+    /// The `start` is the transformation at the time `t = 0.0` and `end` is the transformation at
+    /// the time `t = 1.0`.
+    pub fn new(start: Isometry<N>, end: Isometry<N>, pivot: Point<N>) -> Self {
+        ArcInterpolatedRigidMotion { start, end, pivot }
+    }
+}
+
+impl<N: RealField> RigidMotion<N> for ArcInterpolatedRigidMotion<N> {
+    fn position_at_time(&self, t: N) -> Isometry<N> {
+        let lerp_slerp = self.start.lerp_slerp(&self.end, t);
+        let eps = N::default_epsilon();
+        let arm0 = self.start.translation.vector - self.pivot.coords;
+        let arm1 = self.end.translation.vector - self.pivot.coords;
+
+        if let (Some((dir0, radius0)), Some((dir1, radius1))) = (
+            Unit::try_new_and_get(arm0, eps),
+            Unit::try_new_and_get(arm1, eps),
+        ) {
+            if let Some(dir) = dir0.try_slerp(&dir1, t, eps) {
+                let radius = radius0 + (radius1 - radius0) * t;
+                let translation = self.pivot.coords + dir.into_inner() * radius;
+                return Isometry::from_parts(translation.into(), lerp_slerp.rotation);
+            }
+        }
+
+        lerp_slerp
+    }
 }
 
 /// A linear motion from a starting isometry traveling at constant translational velocity.
@@ -61,6 +289,14 @@ impl<N: RealField> ConstantLinearVelocityRigidMotion<N> {
             velocity,
         }
     }
+
+    /// The same motion traveled backward in time.
+    ///
+    /// The returned motion has the same starting isometry, the opposite velocity, and starts at
+    /// `-self.t0`, so that its position at time `t` is the position of `self` at time `-t`.
+    pub fn reversed(&self) -> Self {
+        ConstantLinearVelocityRigidMotion::new(-self.t0, self.start, -self.velocity)
+    }
 }
 
 impl<N: RealField> RigidMotion<N> for ConstantLinearVelocityRigidMotion<N> {
@@ -70,6 +306,14 @@ impl<N: RealField> RigidMotion<N> for ConstantLinearVelocityRigidMotion<N> {
             self.start.rotation,
         )
     }
+
+    fn max_point_velocity(&self, _: N) -> N {
+        self.velocity.norm()
+    }
+
+    fn velocity_at_time(&self, _: N) -> (Vector<N>, AngularVector<N>) {
+        (self.velocity, na::zero())
+    }
 }
 
 /// A linear motion from a starting isometry traveling at constant translational velocity.
@@ -140,6 +384,28 @@ impl<N: RealField> RigidMotion<N> for ConstantVelocityRigidMotion<N> {
 
         lhs * Isometry::new(scaled_linvel, scaled_angvel) * rhs
     }
+
+    fn max_point_velocity(&self, shape_radius: N) -> N {
+        #[cfg(feature = "dim2")]
+        let angvel = self.angvel.abs();
+        #[cfg(feature = "dim3")]
+        let angvel = self.angvel.norm();
+
+        // The rotation is applied around `self.local_center` instead of the local origin.
+        self.linvel.norm() + angvel * (shape_radius + self.local_center.coords.norm())
+    }
+
+    /// The angular velocity is `self.angvel` at any time, and the linear velocity is the one of
+    /// the local-space origin rotating around `self.local_center`.
+    fn velocity_at_time(&self, t: N) -> (Vector<N>, AngularVector<N>) {
+        let center_to_origin = self.position_at_time(t).rotation * -self.local_center.coords;
+        #[cfg(feature = "dim2")]
+        let origin_vel = Vector::new(-center_to_origin.y, center_to_origin.x) * self.angvel;
+        #[cfg(feature = "dim3")]
+        let origin_vel = self.angvel.cross(&center_to_origin);
+
+        (self.linvel + origin_vel, self.angvel)
+    }
 }
 
 /*
@@ -181,6 +447,11 @@ impl<'a, N: RealField, M: ?Sized + RigidMotion<N>> RigidMotion<N> for PrependTra
         let m = self.motion.position_at_time(t);
         m * Translation::from(self.translation)
     }
+
+    fn max_point_velocity(&self, shape_radius: N) -> N {
+        self.motion
+            .max_point_velocity(shape_radius + self.translation.norm())
+    }
 }
 
 /// The result of prepending an isometric transformation to a rigid motion.
@@ -196,4 +467,9 @@ impl<'a, N: RealField, M: ?Sized + RigidMotion<N>> RigidMotion<N>
         let m = self.motion.position_at_time(t);
         m * self.transformation
     }
+
+    fn max_point_velocity(&self, shape_radius: N) -> N {
+        self.motion
+            .max_point_velocity(shape_radius + self.transformation.translation.vector.norm())
+    }
 }
@@ -133,6 +133,9 @@ pub mod math {
 
     /// The translation type.
     pub type Translation<N> = Translation3<N>;
+
+    /// The angular inertia type.
+    pub type AngularInertia<N> = Matrix3<N>;
 }
 
 /// Compilation flags dependent aliases for mathematical types.
@@ -166,4 +169,10 @@ pub mod math {
 
     /// The translation type.
     pub type Translation<N> = Translation2<N>;
+
+    /// The angular vector type.
+    pub type AngularVector<N> = N;
+
+    /// The angular inertia type.
+    pub type AngularInertia<N> = N;
 }
@@ -1,6 +1,7 @@
 use na::RealField;
 use std::any::Any;
 
+use crate::bounding_volume::BoundingVolume;
 use crate::math::Point;
 use crate::query::{Ray, RayIntersection};
 
@@ -77,6 +78,50 @@ pub trait BroadPhase<N: RealField, BV, T>: Any + Sync + Send {
     /// Collects every object which might contain a given point.
     fn interferences_with_point<'a>(&'a self, point: &Point<N>, out: &mut Vec<&'a T>);
 
+    /// Collects the handles of every proxy inserted by the last `update`.
+    ///
+    /// The default implementation does not report any proxy. Broad phases should override it so
+    /// the default implementations of `pairs_within_distance` and `initial_pairs` take their
+    /// proxies into account.
+    fn proxy_handles(&self, _out: &mut Vec<BroadPhaseProxyHandle>) {}
+
+    /// Collects every pair of proxies with bounding volumes closer than `distance`.
+    ///
+    /// Two proxies are reported if their bounding volumes, without the margin added by the broad
+    /// phase, intersect once both are enlarged by `distance / 2`. For AABBs, this is a per-axis
+    /// test rather than a Euclidean distance test so the reported proxies may be up to
+    /// `sqrt(DIM) * distance` apart. Each unordered pair is reported only once. Only the proxies
+    /// inserted by the last `update` are taken into account.
+    ///
+    /// The default implementation tests every pair of proxies given by `proxy_handles`, using
+    /// the bounding volumes returned by `proxy`.
+    fn pairs_within_distance(
+        &self,
+        distance: N,
+        out: &mut Vec<(BroadPhaseProxyHandle, BroadPhaseProxyHandle)>,
+    ) where
+        BV: BoundingVolume<N>,
+    {
+        out.extend(brute_force_pairs(self, distance * na::convert(0.5)));
+    }
+
+    /// Computes, in one batch, every pair of proxies with intersecting bounding volumes.
+    ///
+    /// Unlike `update`, this takes into account the proxies and bounding volumes that are still
+    /// pending and does not modify the broad phase nor notify any interference handler. Each
+    /// unordered pair is reported only once, and the result is sorted so it does not depend on
+    /// the internal structure of the broad phase.
+    ///
+    /// The default implementation tests every pair of proxies given by `proxy_handles`, using
+    /// the bounding volumes returned by `proxy`. Thus, it does not take the pending proxies and
+    /// bounding volumes into account.
+    fn initial_pairs(&self) -> Vec<(BroadPhaseProxyHandle, BroadPhaseProxyHandle)>
+    where
+        BV: BoundingVolume<N>,
+    {
+        brute_force_pairs(self, N::zero())
+    }
+
     fn first_interference_with_ray<'a, 'b>(
         &'a self,
         ray: &'b Ray<N>,
@@ -84,3 +129,39 @@ pub trait BroadPhase<N: RealField, BV, T>: Any + Sync + Send {
         cost_fn: &'a dyn Fn(T, &'b Ray<N>, N) -> Option<(T, RayIntersection<N>)>,
     ) -> Option<(T, RayIntersection<N>)>;
 }
+
+// Tests every pair of proxies of `broad_phase`, with their bounding volumes loosened by `margin`.
+// Each unordered pair is reported once, and the result is sorted.
+fn brute_force_pairs<N, BV, T, B>(
+    broad_phase: &B,
+    margin: N,
+) -> Vec<(BroadPhaseProxyHandle, BroadPhaseProxyHandle)>
+where
+    N: RealField,
+    BV: BoundingVolume<N>,
+    B: BroadPhase<N, BV, T> + ?Sized,
+{
+    let mut handles = Vec::new();
+    broad_phase.proxy_handles(&mut handles);
+    handles.sort();
+
+    let bvs: Vec<_> = handles
+        .iter()
+        .filter_map(|h| {
+            broad_phase
+                .proxy(*h)
+                .map(|(bv, _)| (*h, bv.loosened(margin)))
+        })
+        .collect();
+    let mut pairs = Vec::new();
+
+    for (i, (h1, bv1)) in bvs.iter().enumerate() {
+        for (h2, bv2) in &bvs[i + 1..] {
+            if bv1.intersects(bv2) {
+                pairs.push((*h1, *h2));
+            }
+        }
+    }
+
+    pairs
+}
@@ -1,6 +1,6 @@
 use crate::bounding_volume::BoundingVolume;
-use crate::math::Point;
-use crate::partitioning::{DBVTLeaf, DBVTLeafId, BVH, DBVT};
+use crate::math::{Isometry, Point};
+use crate::partitioning::{DBVTLeaf, DBVTLeafId, SimultaneousVisitor, VisitStatus, BVH, BVT, DBVT};
 use crate::pipeline::broad_phase::{
     BroadPhase, BroadPhaseInterferenceHandler, BroadPhaseProxyHandle,
 };
@@ -13,8 +13,9 @@ use crate::utils::{DeterministicState, SortedPair};
 use na::RealField;
 use slab::Slab;
 use std::any::Any;
+use std::cmp::Ordering;
 use std::collections::hash_map::Entry;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum ProxyStatus {
@@ -29,6 +30,8 @@ struct DBVTBroadPhaseProxy<T> {
     data: T,
     status: ProxyStatus,
     updated: bool,
+    // Whether the bounding volume of the leaf of this proxy was loosened by the margin.
+    loosened: bool,
 }
 
 impl<T> DBVTBroadPhaseProxy<T> {
@@ -37,6 +40,7 @@ impl<T> DBVTBroadPhaseProxy<T> {
             data,
             status: ProxyStatus::Detached(None),
             updated: true,
+            loosened: false,
         }
     }
 
@@ -54,6 +58,10 @@ const DEACTIVATION_THRESHOLD: usize = 100;
 ///
 /// It uses two separate trees: one for static objects and which is never updated, and one for
 /// moving objects.
+///
+/// Interferences are tracked per unordered pair of proxies so
+/// `BroadPhaseInterferenceHandler::interference_started` is called only once for a given pair
+/// until the corresponding `interference_stopped`, even if the pair is detected several times.
 pub struct DBVTBroadPhase<N: RealField, BV, T> {
     proxies: Slab<DBVTBroadPhaseProxy<T>>,
     // DBVT for moving objects.
@@ -69,7 +77,8 @@ pub struct DBVTBroadPhase<N: RealField, BV, T> {
     // Just to avoid dynamic allocations.
     collector: Vec<BroadPhaseProxyHandle>,
     leaves_to_update: Vec<DBVTLeaf<N, BroadPhaseProxyHandle, BV>>,
-    proxies_to_update: VecDeque<(BroadPhaseProxyHandle, BV)>,
+    // The pending bounding volumes, and whether they were loosened by the margin.
+    proxies_to_update: VecDeque<(BroadPhaseProxyHandle, BV, bool)>,
 }
 
 impl<N, BV, T> DBVTBroadPhase<N, BV, T>
@@ -175,9 +184,10 @@ where
         /*
          * Remove from the trees all nodes that have been deleted or modified.
          */
-        for (handle, bv) in self.proxies_to_update.drain(..) {
+        for (handle, bv, loosened) in self.proxies_to_update.drain(..) {
             if let Some(proxy) = self.proxies.get_mut(handle.uid()) {
                 let mut set_status = true;
+                proxy.loosened = loosened;
                 match proxy.status {
                     ProxyStatus::OnStaticTree(leaf) => {
                         let mut leaf = self.stree.remove(leaf);
@@ -275,7 +285,7 @@ where
     fn create_proxy(&mut self, bv: BV, data: T) -> BroadPhaseProxyHandle {
         let proxy = DBVTBroadPhaseProxy::new(data);
         let handle = BroadPhaseProxyHandle(self.proxies.insert(proxy));
-        self.proxies_to_update.push_back((handle, bv));
+        self.proxies_to_update.push_back((handle, bv, false));
         handle
     }
 
@@ -339,7 +349,7 @@ where
 
             if needs_update {
                 let new_bv = bounding_volume.loosened(self.margin);
-                self.proxies_to_update.push_back((handle, new_bv));
+                self.proxies_to_update.push_back((handle, new_bv, true));
             }
         } else {
             panic!("Attempting to set the bounding volume of an object that does not exist.");
@@ -357,7 +367,8 @@ where
                 }
             };
 
-            self.proxies_to_update.push_front((handle, bv));
+            self.proxies_to_update
+                .push_front((handle, bv, proxy.loosened));
         }
     }
 
@@ -378,7 +389,7 @@ where
             }
 
             self.proxies_to_update
-                .push_front((BroadPhaseProxyHandle(handle), bv));
+                .push_front((BroadPhaseProxyHandle(handle), bv, proxy.loosened));
         }
 
         self.purge_all = true;
@@ -399,6 +410,9 @@ where
         }
     }
 
+    /// Collects every object which might intersect a given ray.
+    ///
+    /// The objects are sorted by the time the ray enters their bounding volume.
     fn interferences_with_ray<'a>(&'a self, ray: &Ray<N>, max_toi: N, out: &mut Vec<&'a T>) {
         let mut collector = Vec::new();
 
@@ -409,7 +423,21 @@ where
             self.stree.visit(&mut visitor);
         }
 
-        for l in collector.into_iter() {
+        // Sort the results by the time the ray enters their bounding volume.
+        let id = Isometry::identity();
+        let mut hits: Vec<_> = collector
+            .into_iter()
+            .map(|l| {
+                let toi = self
+                    .proxy(l)
+                    .and_then(|(bv, _)| bv.toi_with_ray(&id, ray, max_toi, true))
+                    .unwrap_or(max_toi);
+                (toi, l)
+            })
+            .collect();
+        hits.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+        for (_, l) in hits.into_iter() {
             out.push(&self.proxies[l.uid()].data)
         }
     }
@@ -429,6 +457,85 @@ where
         }
     }
 
+    fn proxy_handles(&self, out: &mut Vec<BroadPhaseProxyHandle>) {
+        for (uid, proxy) in self.proxies.iter() {
+            match proxy.status {
+                ProxyStatus::OnStaticTree(_) | ProxyStatus::OnDynamicTree(..) => {
+                    out.push(BroadPhaseProxyHandle(uid))
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Collects every pair of proxies with bounding volumes closer than `distance`.
+    ///
+    /// The bounding volumes loosened by the margin of this broad phase, i.e., the ones given to
+    /// `deferred_set_bounding_volume`, are tightened by this margin before being compared. The
+    /// ones given to `create_proxy` are compared as-is. A proxy which new bounding volume is still contained in its loosened bounding
+    /// volume is not re-inserted so, for such proxies, the bounding volume used here is the one
+    /// given at the time of their last re-insertion.
+    fn pairs_within_distance(
+        &self,
+        distance: N,
+        out: &mut Vec<(BroadPhaseProxyHandle, BroadPhaseProxyHandle)>,
+    ) {
+        let mut visitor = LoosenedInterferencesCollector {
+            margin: distance * na::convert(0.5),
+            leaf_margin: self.margin,
+            proxies: &self.proxies,
+            pairs: HashSet::with_hasher(DeterministicState::new()),
+        };
+
+        self.tree.visit_bvtt(&self.tree, &mut visitor);
+        self.tree.visit_bvtt(&self.stree, &mut visitor);
+        self.stree.visit_bvtt(&self.stree, &mut visitor);
+
+        let mut pairs: Vec<_> = visitor.pairs.into_iter().map(|p| *p).collect();
+        pairs.sort();
+        out.extend(pairs);
+    }
+
+    fn initial_pairs(&self) -> Vec<(BroadPhaseProxyHandle, BroadPhaseProxyHandle)> {
+        // The pending bounding volumes are applied in order so the last one wins.
+        let mut pending = HashMap::with_hasher(DeterministicState::new());
+        for (handle, bv, _) in self.proxies_to_update.iter() {
+            let _ = pending.insert(*handle, bv);
+        }
+
+        let mut leaves = Vec::with_capacity(self.proxies.len());
+        for (uid, proxy) in self.proxies.iter() {
+            let handle = BroadPhaseProxyHandle(uid);
+            let bv = match (pending.get(&handle), proxy.status) {
+                // Only the proxies created since the last update are detached outside of `update`.
+                (_, ProxyStatus::Detached(Some(_))) | (_, ProxyStatus::Deleted) => continue,
+                (Some(bv), _) => *bv,
+                (None, ProxyStatus::OnStaticTree(leaf)) => &self.stree[leaf].bounding_volume,
+                (None, ProxyStatus::OnDynamicTree(leaf, _)) => &self.tree[leaf].bounding_volume,
+                (None, ProxyStatus::Detached(None)) => continue,
+            };
+
+            leaves.push((handle, bv.clone()));
+        }
+
+        if leaves.len() < 2 {
+            return Vec::new();
+        }
+
+        let bvt = BVT::new_balanced(leaves);
+        let mut visitor = LoosenedInterferencesCollector {
+            margin: N::zero(),
+            leaf_margin: N::zero(),
+            proxies: &self.proxies,
+            pairs: HashSet::with_hasher(DeterministicState::new()),
+        };
+        bvt.visit_bvtt(&bvt, &mut visitor);
+
+        let mut pairs: Vec<_> = visitor.pairs.into_iter().map(|p| *p).collect();
+        pairs.sort();
+        pairs
+    }
+
     /// Returns the first object that interferes with a ray.
     fn first_interference_with_ray<'a, 'b>(
         &'a self,
@@ -459,3 +566,57 @@ where
         }
     }
 }
+
+// Collects the pairs of distinct leaves with bounding volumes that intersect once both are
+// loosened by `margin`. The leaf bounding volumes that were loosened by the margin of the broad
+// phase when they were inserted into the tree are first tightened by `leaf_margin`.
+struct LoosenedInterferencesCollector<'a, N, T> {
+    margin: N,
+    leaf_margin: N,
+    proxies: &'a Slab<DBVTBroadPhaseProxy<T>>,
+    pairs: HashSet<SortedPair<BroadPhaseProxyHandle>, DeterministicState>,
+}
+
+impl<'a, N: RealField, T> LoosenedInterferencesCollector<'a, N, T> {
+    fn leaf_margin(&self, handle: &BroadPhaseProxyHandle) -> N {
+        match self.proxies.get(handle.uid()) {
+            Some(proxy) if proxy.loosened => self.leaf_margin,
+            _ => N::zero(),
+        }
+    }
+}
+
+impl<'a, N, BV, T> SimultaneousVisitor<BroadPhaseProxyHandle, BV>
+    for LoosenedInterferencesCollector<'a, N, T>
+where
+    N: RealField,
+    BV: BoundingVolume<N>,
+{
+    fn visit(
+        &mut self,
+        left_bv: &BV,
+        left_data: Option<&BroadPhaseProxyHandle>,
+        right_bv: &BV,
+        right_data: Option<&BroadPhaseProxyHandle>,
+    ) -> VisitStatus {
+        if left_bv
+            .loosened(self.margin)
+            .intersects(&right_bv.loosened(self.margin))
+        {
+            if let (Some(a), Some(b)) = (left_data, right_data) {
+                let left_bv = left_bv.tightened(self.leaf_margin(a)).loosened(self.margin);
+                let right_bv = right_bv
+                    .tightened(self.leaf_margin(b))
+                    .loosened(self.margin);
+
+                if a != b && left_bv.intersects(&right_bv) {
+                    let _ = self.pairs.insert(SortedPair::new(*a, *b));
+                }
+            }
+
+            VisitStatus::Continue
+        } else {
+            VisitStatus::Stop
+        }
+    }
+}
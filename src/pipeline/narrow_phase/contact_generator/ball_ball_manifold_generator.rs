@@ -37,7 +37,7 @@ impl<N: RealField> ContactManifoldGenerator<N> for BallBallManifoldGenerator<N>
         prediction: &ContactPrediction<N>,
         manifold: &mut ContactManifold<N>,
     ) -> bool {
-        if let (Some(a), Some(b)) = (a.as_shape::<Ball<N>>(), b.as_shape::<Ball<N>>()) {
+        if let Some((a, b)) = <dyn Shape<N>>::downcast_pair::<Ball<N>, Ball<N>>(a, b) {
             let center_a = Point::from(ma.translation.vector);
             let center_b = Point::from(mb.translation.vector);
             if let Some(contact) =
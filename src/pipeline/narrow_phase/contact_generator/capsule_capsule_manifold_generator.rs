@@ -2,9 +2,12 @@ use crate::math::Isometry;
 use crate::pipeline::narrow_phase::{
     ContactDispatcher, ContactManifoldGenerator, ConvexPolyhedronConvexPolyhedronManifoldGenerator,
 };
-use crate::query::{ContactManifold, ContactPrediction, ContactPreprocessor};
-use crate::shape::{Capsule, Shape};
-use na::{self, RealField};
+use crate::query::{
+    Contact, ContactKinematic, ContactManifold, ContactNormalMode, ContactPrediction,
+    ContactPreprocessor, PointQuery,
+};
+use crate::shape::{Capsule, Segment, Shape};
+use na::{self, RealField, Unit};
 
 /// Collision detector between a concave shape and another shape.
 pub struct CapsuleCapsuleManifoldGenerator<N: RealField> {
@@ -39,18 +42,76 @@ impl<N: RealField> CapsuleCapsuleManifoldGenerator<N> {
         let new_linear_prediction = prediction.linear() + g1.radius + g2.radius;
         prediction.set_linear(new_linear_prediction);
 
+        let capsule_proc1 = (proc1, &g1.contact_preprocessor());
+        let capsule_proc2 = (proc2, &g2.contact_preprocessor());
+
         // Update all collisions
-        self.sub_detector.generate_contacts(
-            dispatcher,
-            m1,
-            &segment1,
-            Some(&(proc1, &g1.contact_preprocessor())),
-            m2,
-            &segment2,
-            Some(&(proc2, &g2.contact_preprocessor())),
-            &prediction,
-            manifold,
-        )
+        if manifold.normal_mode() == ContactNormalMode::PerContact {
+            let normal_proc = PerContactNormalPreprocessor {
+                segment2: g2.world_segment(m2),
+            };
+
+            self.sub_detector.generate_contacts(
+                dispatcher,
+                m1,
+                &segment1,
+                Some(&(Some(&normal_proc), &capsule_proc1)),
+                m2,
+                &segment2,
+                Some(&capsule_proc2),
+                &prediction,
+                manifold,
+            )
+        } else {
+            self.sub_detector.generate_contacts(
+                dispatcher,
+                m1,
+                &segment1,
+                Some(&capsule_proc1),
+                m2,
+                &segment2,
+                Some(&capsule_proc2),
+                &prediction,
+                manifold,
+            )
+        }
+    }
+}
+
+/// Replaces the normal shared by all the contacts between the two capsule segments by the
+/// direction from each contact point on the first segment to its projection on the second one.
+///
+/// This must be applied before the contacts are dilated by the capsule radii.
+struct PerContactNormalPreprocessor<N: RealField> {
+    segment2: Segment<N>,
+}
+
+impl<N: RealField> ContactPreprocessor<N> for PerContactNormalPreprocessor<N> {
+    fn process_contact(
+        &self,
+        c: &mut Contact<N>,
+        _: &mut ContactKinematic<N>,
+        is_first: bool,
+    ) -> bool {
+        if is_first {
+            let proj = self
+                .segment2
+                .project_point(&Isometry::identity(), &c.world1, true)
+                .point;
+
+            // Keep the shared normal if the segments intersect at this contact.
+            if let Some((normal, dist)) =
+                Unit::try_new_and_get(proj - c.world1, N::default_epsilon())
+            {
+                if normal.dot(&c.normal) > N::zero() {
+                    c.world2 = proj;
+                    c.normal = normal;
+                    c.depth = -dist;
+                }
+            }
+        }
+
+        true
     }
 }
 
@@ -67,10 +128,14 @@ impl<N: RealField> ContactManifoldGenerator<N> for CapsuleCapsuleManifoldGenerat
         prediction: &ContactPrediction<N>,
         manifold: &mut ContactManifold<N>,
     ) -> bool {
-        if let (Some(cs1), Some(cs2)) = (a.as_shape::<Capsule<N>>(), b.as_shape::<Capsule<N>>()) {
+        if let Some((cs1, cs2)) = <dyn Shape<N>>::downcast_pair::<Capsule<N>, Capsule<N>>(a, b) {
             self.do_update(d, ma, cs1, proc1, mb, cs2, proc2, prediction, manifold)
         } else {
             false
         }
     }
+
+    fn reset(&mut self) {
+        self.sub_detector.reset();
+    }
 }
@@ -99,4 +99,8 @@ impl<N: RealField> ContactManifoldGenerator<N> for CapsuleShapeManifoldGenerator
 
         return false;
     }
+
+    fn reset(&mut self) {
+        self.sub_detector = None;
+    }
 }
@@ -142,4 +142,9 @@ impl<N: RealField> ContactManifoldGenerator<N>
             false
         }
     }
+
+    fn reset(&mut self) {
+        self.sub_detectors.clear();
+        self.interferences.clear();
+    }
 }
@@ -7,7 +7,11 @@ use crate::query::{
     visitors::BoundingVolumeInterferencesCollector, ContactManifold, ContactPrediction,
     ContactPreprocessor, ContactTrackingMode,
 };
-use crate::shape::{CompositeShape, Shape};
+#[cfg(feature = "dim3")]
+use crate::query::{Contact, ContactKinematic};
+use crate::shape::Shape;
+#[cfg(feature = "dim3")]
+use crate::shape::TriMesh;
 use crate::utils::DeterministicState;
 use na::{self, RealField};
 use std::collections::{hash_map::Entry, HashMap};
@@ -18,6 +22,8 @@ pub struct CompositeShapeShapeManifoldGenerator<N: RealField> {
     interferences: Vec<usize>,
     flip: bool,
     timestamp: usize,
+    #[cfg(feature = "dim3")]
+    normal_smoothing: bool,
 }
 
 impl<N: RealField> CompositeShapeShapeManifoldGenerator<N> {
@@ -28,14 +34,35 @@ impl<N: RealField> CompositeShapeShapeManifoldGenerator<N> {
             interferences: Vec::new(),
             flip,
             timestamp: 0,
+            #[cfg(feature = "dim3")]
+            normal_smoothing: false,
         }
     }
 
+    /// Whether the contact normals on triangle meshes are clamped into the normal cones of
+    /// the mesh features.
+    #[cfg(feature = "dim3")]
+    pub fn normal_smoothing(&self) -> bool {
+        self.normal_smoothing
+    }
+
+    /// Enables or disables the clamping of contact normals on triangle meshes.
+    ///
+    /// When enabled, the normal of a contact on an edge or a vertex of a `TriMesh` is clamped
+    /// into the normal cone of this feature, i.e., between the normals of the adjacent faces,
+    /// and the normal of a contact on a face is set to the face normal. This prevents contacts
+    /// with normals pointing out of the neighboring faces at internal edges, which cause
+    /// objects sliding on the mesh to bump on those edges. Disabled by default.
+    #[cfg(feature = "dim3")]
+    pub fn set_normal_smoothing(&mut self, enabled: bool) {
+        self.normal_smoothing = enabled
+    }
+
     fn do_update(
         &mut self,
         dispatcher: &dyn ContactDispatcher<N>,
         m1: &Isometry<N>,
-        g1: &dyn CompositeShape<N>,
+        g1_shape: &dyn Shape<N>,
         proc1: Option<&dyn ContactPreprocessor<N>>,
         m2: &Isometry<N>,
         g2: &dyn Shape<N>,
@@ -46,6 +73,16 @@ impl<N: RealField> CompositeShapeShapeManifoldGenerator<N> {
     ) {
         self.timestamp += 1;
 
+        let g1 = g1_shape
+            .as_composite_shape()
+            .expect("The shape must be a composite shape.");
+        #[cfg(feature = "dim3")]
+        let smoothing_mesh = if self.normal_smoothing {
+            g1_shape.as_shape::<TriMesh<N>>()
+        } else {
+            None
+        };
+
         // Find new collisions
         let ls_m2 = m1.inverse() * m2.clone();
         let ls_aabb2 = bounding_volume::aabb(g2, &ls_m2).loosened(prediction.linear());
@@ -87,6 +124,21 @@ impl<N: RealField> CompositeShapeShapeManifoldGenerator<N> {
             } else {
                 let mut keep = false;
                 g1.map_part_and_preprocessor_at(*key, m1, prediction, &mut |m1, g1, part_proc1| {
+                    #[cfg(feature = "dim3")]
+                    let smoothing;
+                    #[cfg(feature = "dim3")]
+                    let part_proc1: &dyn ContactPreprocessor<N> = match smoothing_mesh {
+                        Some(mesh) => {
+                            smoothing = NormalSmoothingPreprocessor {
+                                mesh,
+                                pos: m1,
+                                inner: part_proc1,
+                            };
+                            &smoothing
+                        }
+                        None => part_proc1,
+                    };
+
                     keep = if flip {
                         detector.0.generate_contacts(
                             dispatcher,
@@ -134,13 +186,13 @@ impl<N: RealField> ContactManifoldGenerator<N> for CompositeShapeShapeManifoldGe
         manifold: &mut ContactManifold<N>,
     ) -> bool {
         if !self.flip {
-            if let Some(cs) = a.as_composite_shape() {
-                self.do_update(d, ma, cs, proc1, mb, b, proc2, prediction, manifold, false);
+            if a.is_composite_shape() {
+                self.do_update(d, ma, a, proc1, mb, b, proc2, prediction, manifold, false);
                 return true;
             }
         } else {
-            if let Some(cs) = b.as_composite_shape() {
-                self.do_update(d, mb, cs, proc2, ma, a, proc1, prediction, manifold, true);
+            if b.is_composite_shape() {
+                self.do_update(d, mb, b, proc2, ma, a, proc1, prediction, manifold, true);
                 return true;
             }
         }
@@ -153,4 +205,46 @@ impl<N: RealField> ContactManifoldGenerator<N> for CompositeShapeShapeManifoldGe
         res.set_tracking_mode(ContactTrackingMode::FeatureBased);
         res
     }
+
+    fn reset(&mut self) {
+        self.sub_detectors.clear();
+        self.interferences.clear();
+    }
+}
+
+// Clamps the normals of the contacts on a triangle mesh into the normal cones of the mesh features.
+#[cfg(feature = "dim3")]
+struct NormalSmoothingPreprocessor<'a, N: RealField> {
+    mesh: &'a TriMesh<N>,
+    pos: &'a Isometry<N>,
+    inner: &'a dyn ContactPreprocessor<N>,
+}
+
+#[cfg(feature = "dim3")]
+impl<'a, N: RealField> ContactPreprocessor<N> for NormalSmoothingPreprocessor<'a, N> {
+    fn process_contact(
+        &self,
+        c: &mut Contact<N>,
+        kinematic: &mut ContactKinematic<N>,
+        is_first: bool,
+    ) -> bool {
+        // The inner preprocessor converts the features into features of the whole mesh.
+        if !self.inner.process_contact(c, kinematic, is_first) {
+            return false;
+        }
+
+        let (feature, outward_normal) = if is_first {
+            (kinematic.feature1(), c.normal)
+        } else {
+            (kinematic.feature2(), -c.normal)
+        };
+
+        let local_normal = self.pos.inverse_transform_unit_vector(&outward_normal);
+        let clamped = self.pos * self.mesh.clamp_dir_to_normal_cone(feature, &local_normal);
+
+        c.normal = if is_first { clamped } else { -clamped };
+        c.depth = -c.normal.dot(&(c.world2 - c.world1));
+
+        true
+    }
 }
@@ -29,6 +29,13 @@ pub trait ContactManifoldGenerator<N: RealField>: Any + Send + Sync {
         manifold: &mut ContactManifold<N>,
     ) -> bool;
 
+    /// Clears the persistent state this generator keeps for the pair of objects it was used with.
+    ///
+    /// This must be called before re-using this generator for another pair of objects so that
+    /// no cached data (e.g. warm-start directions or sub-detectors) is carried over. The
+    /// configuration of the generator is left unchanged.
+    fn reset(&mut self) {}
+
     /// Generate an empty contact manifold configured as required by this contact manifold generator.
     fn init_manifold(&self) -> ContactManifold<N> {
         ContactManifold::new()
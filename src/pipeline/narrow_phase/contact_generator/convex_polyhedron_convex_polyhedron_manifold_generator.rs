@@ -9,6 +9,22 @@ use crate::shape::ConvexPolygonalFeature;
 use crate::shape::{FeatureId, Shape};
 use na::{self, RealField, Unit};
 
+/// The features of two convex polyhedra used to generate their contact manifold.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ManifoldFeatures {
+    /// The shape providing the reference feature: `0` for the first shape and `1` for the second.
+    ///
+    /// Both features are clipped against each other symmetrically, so this is only a label chosen
+    /// once the contacts are generated: the reference shape is the one whose feature normal is the
+    /// most aligned with the contact normal. The first shape is chosen if both are aligned up to a
+    /// small tolerance, or if neither feature has a normal.
+    pub reference_shape: usize,
+    /// The feature of the reference shape.
+    pub reference_feature: FeatureId,
+    /// The feature of the other shape.
+    pub incident_feature: FeatureId,
+}
+
 #[cfg(feature = "dim2")]
 #[derive(Clone)]
 pub struct ConvexPolyhedronConvexPolyhedronManifoldGenerator<N: RealField> {
@@ -18,6 +34,8 @@ pub struct ConvexPolyhedronConvexPolyhedronManifoldGenerator<N: RealField> {
     new_contacts: Vec<(Contact<N>, FeatureId, FeatureId)>,
     manifold1: ConvexPolygonalFeature<N>,
     manifold2: ConvexPolygonalFeature<N>,
+    features: Option<ManifoldFeatures>,
+    max_contacts: Option<usize>,
 }
 
 #[cfg(feature = "dim3")]
@@ -30,6 +48,8 @@ pub struct ConvexPolyhedronConvexPolyhedronManifoldGenerator<N: RealField> {
     new_contacts: Vec<(Contact<N>, FeatureId, FeatureId)>,
     manifold1: ConvexPolygonalFeature<N>,
     manifold2: ConvexPolygonalFeature<N>,
+    features: Option<ManifoldFeatures>,
+    max_contacts: Option<usize>,
 }
 
 impl<N: RealField> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
@@ -43,6 +63,8 @@ impl<N: RealField> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
             new_contacts: Vec::new(),
             manifold1: ConvexPolygonalFeature::new(),
             manifold2: ConvexPolygonalFeature::new(),
+            features: None,
+            max_contacts: None,
         }
     }
 
@@ -55,9 +77,67 @@ impl<N: RealField> ConvexPolyhedronConvexPolyhedronManifoldGenerator<N> {
             new_contacts: Vec::new(),
             manifold1: ConvexPolygonalFeature::new(),
             manifold2: ConvexPolygonalFeature::new(),
+            features: None,
+            max_contacts: None,
+        }
+    }
+
+    /// The features used to generate the current contact manifold.
+    ///
+    /// Returns `None` if the last call to `generate_contacts` did not generate any contact by
+    /// clipping the features of the two shapes, e.g., if the shapes are disjoint or if their
+    /// single contact comes directly from the GJK algorithm.
+    pub fn manifold_features(&self) -> Option<ManifoldFeatures> {
+        self.features
+    }
+
+    /// The maximum number of contacts generated by each call to `generate_contacts`, if any.
+    pub fn max_contacts(&self) -> Option<usize> {
+        self.max_contacts
+    }
+
+    /// Sets the maximum number of contacts generated by each call to `generate_contacts`.
+    ///
+    /// When the clipping of the two polyhedra features yields more contacts, the deepest one is
+    /// kept and the others are selected one by one as the contact farthest from all the contacts
+    /// selected so far. Set to `None` (the default) to keep all the contacts.
+    pub fn set_max_contacts(&mut self, max_contacts: Option<usize>) {
+        self.max_contacts = max_contacts
+    }
+
+    fn limit_new_contacts(&mut self) {
+        if let Some(max_contacts) = self.max_contacts {
+            query::select_contacts(&mut self.new_contacts, max_contacts, |c| &c.0);
+            self.new_contacts.truncate(max_contacts);
         }
     }
 
+    fn select_reference(&mut self, normal: &Unit<Vector<N>>) {
+        let alignment1 = self.manifold1.normal.map(|n1| n1.dot(normal));
+        let alignment2 = self.manifold2.normal.map(|n2| -n2.dot(normal));
+        let eps = N::default_epsilon().sqrt();
+
+        let second_is_reference = match (alignment1, alignment2) {
+            (Some(a1), Some(a2)) => a2 > a1 + eps,
+            (None, Some(_)) => true,
+            _ => false,
+        };
+
+        self.features = if second_is_reference {
+            Some(ManifoldFeatures {
+                reference_shape: 1,
+                reference_feature: self.manifold2.feature_id,
+                incident_feature: self.manifold1.feature_id,
+            })
+        } else {
+            Some(ManifoldFeatures {
+                reference_shape: 0,
+                reference_feature: self.manifold1.feature_id,
+                incident_feature: self.manifold2.feature_id,
+            })
+        };
+    }
+
     fn clip_polyfaces(&mut self, prediction: &ContactPrediction<N>, normal: &Unit<Vector<N>>) {
         #[cfg(feature = "dim2")]
         {
@@ -107,6 +187,7 @@ impl<N: RealField> ContactManifoldGenerator<N>
             self.new_contacts.clear();
             self.manifold1.clear();
             self.manifold2.clear();
+            self.features = None;
 
             match contact {
                 GJKResult::ClosestPoints(world1, world2, dir) => {
@@ -134,6 +215,10 @@ impl<N: RealField> ContactManifoldGenerator<N>
                         self.clip_polyfaces(prediction, &contact.normal);
                     }
 
+                    if !self.new_contacts.is_empty() {
+                        self.select_reference(&contact.normal);
+                    }
+
                     if self.new_contacts.len() == 0 {
                         self.new_contacts.push((
                             contact.clone(),
@@ -146,6 +231,8 @@ impl<N: RealField> ContactManifoldGenerator<N>
                 _ => {}
             }
 
+            self.limit_new_contacts();
+
             for (c, f1, f2) in self.new_contacts.drain(..) {
                 self.manifold1.add_contact_to_manifold(
                     &self.manifold2,
@@ -165,4 +252,11 @@ impl<N: RealField> ContactManifoldGenerator<N>
             false
         }
     }
+
+    fn reset(&mut self) {
+        self.simplex = VoronoiSimplex::new();
+        self.last_gjk_dir = None;
+        self.last_optimal_dir = None;
+        self.features = None;
+    }
 }
@@ -0,0 +1,189 @@
+use crate::math::{Isometry, Point, Vector, DIM};
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{
+    Contact, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor,
+    NeighborhoodGeometry,
+};
+#[cfg(feature = "dim3")]
+use crate::shape::ConvexPolyhedron;
+use crate::shape::{Ball, Cuboid, FeatureId, Shape};
+use na::{self, RealField, Unit};
+use std::marker::PhantomData;
+
+/// Collision detector between a cuboid and a ball.
+#[derive(Clone)]
+pub struct CuboidBallManifoldGenerator<N: RealField> {
+    flip: bool,
+    phantom: PhantomData<N>,
+}
+
+impl<N: RealField> CuboidBallManifoldGenerator<N> {
+    /// Creates a new persistent collision detector between a cuboid and a ball.
+    ///
+    /// If `flip` is `true`, the ball is expected to be the first shape.
+    #[inline]
+    pub fn new(flip: bool) -> CuboidBallManifoldGenerator<N> {
+        CuboidBallManifoldGenerator {
+            flip,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn do_update_to(
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+        flip: bool,
+    ) -> bool {
+        if let Some((cuboid, ball)) = <dyn Shape<N>>::downcast_pair::<Cuboid<N>, Ball<N>>(g1, g2) {
+            let ball_center = Point::from(m2.translation.vector);
+            let local_center = m1.inverse_transform_point(&ball_center);
+            let half_extents = cuboid.half_extents;
+
+            // Project the ball center on the cuboid by clamping its coordinates.
+            let mut local1 = local_center;
+            let mut clamped = 0usize;
+            let mut negative = 0usize;
+
+            for i in 0..DIM {
+                if local_center[i] > half_extents[i] {
+                    local1[i] = half_extents[i];
+                    clamped |= 1 << i;
+                } else if local_center[i] < -half_extents[i] {
+                    local1[i] = -half_extents[i];
+                    clamped |= 1 << i;
+                    negative |= 1 << i;
+                }
+            }
+
+            let outside = if clamped != 0 {
+                Unit::try_new_and_get(local_center - local1, N::default_epsilon())
+            } else {
+                None
+            };
+
+            let local_normal;
+            let depth;
+            let f1;
+
+            if let Some((dir, dist)) = outside {
+                local_normal = dir;
+                depth = ball.radius - dist;
+                f1 = match clamped.count_ones() {
+                    1 => {
+                        let i = clamped.trailing_zeros() as usize;
+
+                        if negative != 0 {
+                            FeatureId::Face(i + DIM)
+                        } else {
+                            FeatureId::Face(i)
+                        }
+                    }
+                    #[cfg(feature = "dim3")]
+                    2 => {
+                        let i = (!clamped & 0b111).trailing_zeros() as usize;
+                        FeatureId::Edge(i | (negative << 2))
+                    }
+                    _ => FeatureId::Vertex(negative),
+                };
+            } else {
+                // The ball center is inside of the cuboid: push it out of the closest face.
+                let mut best = 0;
+                let mut best_dist = half_extents[0] - local_center[0].abs();
+
+                for i in 1..DIM {
+                    let dist = half_extents[i] - local_center[i].abs();
+
+                    if dist < best_dist {
+                        best = i;
+                        best_dist = dist;
+                    }
+                }
+
+                let mut normal = Vector::zeros();
+                local1 = local_center;
+
+                if local_center[best] < N::zero() {
+                    normal[best] = -N::one();
+                    local1[best] = -half_extents[best];
+                    f1 = FeatureId::Face(best + DIM);
+                } else {
+                    normal[best] = N::one();
+                    local1[best] = half_extents[best];
+                    f1 = FeatureId::Face(best);
+                }
+
+                local_normal = Unit::new_unchecked(normal);
+                depth = ball.radius + best_dist;
+            }
+
+            if depth >= -prediction.linear() {
+                let normal = m1 * local_normal;
+                let world1 = m1 * local1;
+                let world2 = ball_center + *normal * (-ball.radius);
+
+                let approx_cuboid = match f1 {
+                    FeatureId::Face(_) => NeighborhoodGeometry::Plane(local_normal),
+                    #[cfg(feature = "dim3")]
+                    FeatureId::Edge(_) => {
+                        let edge = cuboid.edge(f1);
+                        NeighborhoodGeometry::Line(Unit::new_normalize(edge.1 - edge.0))
+                    }
+                    _ => NeighborhoodGeometry::Point,
+                };
+                let approx_ball = NeighborhoodGeometry::Point;
+
+                let f2 = FeatureId::Face(0);
+                let local2 = Point::origin();
+                let mut kinematic = ContactKinematic::new();
+                let contact;
+
+                if !flip {
+                    contact = Contact::new(world1, world2, normal, depth);
+                    kinematic.set_approx1(f1, local1, approx_cuboid);
+                    kinematic.set_approx2(f2, local2, approx_ball);
+                    kinematic.set_dilation2(ball.radius);
+                    let _ = manifold.push(contact, kinematic, Point::origin(), proc1, proc2);
+                } else {
+                    contact = Contact::new(world2, world1, -normal, depth);
+                    kinematic.set_approx1(f2, local2, approx_ball);
+                    kinematic.set_dilation1(ball.radius);
+                    kinematic.set_approx2(f1, local1, approx_cuboid);
+                    let _ = manifold.push(contact, kinematic, Point::origin(), proc2, proc1);
+                }
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<N: RealField> ContactManifoldGenerator<N> for CuboidBallManifoldGenerator<N> {
+    #[inline]
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+    ) -> bool {
+        if !self.flip {
+            Self::do_update_to(m1, g1, proc1, m2, g2, proc2, prediction, manifold, false)
+        } else {
+            Self::do_update_to(m2, g2, proc2, m1, g1, proc1, prediction, manifold, true)
+        }
+    }
+}
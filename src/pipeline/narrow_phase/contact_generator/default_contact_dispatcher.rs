@@ -1,16 +1,18 @@
 #[cfg(feature = "dim3")]
-use crate::pipeline::narrow_phase::TriMeshTriMeshManifoldGenerator;
+use crate::pipeline::narrow_phase::{
+    TriMeshTriMeshManifoldGenerator, TriangleBallManifoldGenerator,
+};
 use crate::pipeline::{
     BallBallManifoldGenerator, BallConvexPolyhedronManifoldGenerator,
     CapsuleCapsuleManifoldGenerator, CapsuleShapeManifoldGenerator,
     CompositeShapeCompositeShapeManifoldGenerator, CompositeShapeShapeManifoldGenerator,
     ContactAlgorithm, ContactDispatcher, ConvexPolyhedronConvexPolyhedronManifoldGenerator,
-    HeightFieldShapeManifoldGenerator, PlaneBallManifoldGenerator,
+    CuboidBallManifoldGenerator, HeightFieldShapeManifoldGenerator, PlaneBallManifoldGenerator,
     PlaneConvexPolyhedronManifoldGenerator,
 };
+use crate::shape::{Ball, Capsule, Cuboid, HeightField, Plane, Shape};
 #[cfg(feature = "dim3")]
-use crate::shape::TriMesh;
-use crate::shape::{Ball, Capsule, HeightField, Plane, Shape};
+use crate::shape::{TriMesh, Triangle};
 use na::RealField;
 
 /// Collision dispatcher for shapes defined by `ncollide_entities`.
@@ -46,6 +48,12 @@ impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
             if a_is_trimesh && b_is_trimesh {
                 return Some(Box::new(TriMeshTriMeshManifoldGenerator::<N>::new()));
             }
+
+            if a.is_shape::<Triangle<N>>() && b_is_ball {
+                return Some(Box::new(TriangleBallManifoldGenerator::<N>::new(false)));
+            } else if a_is_ball && b.is_shape::<Triangle<N>>() {
+                return Some(Box::new(TriangleBallManifoldGenerator::<N>::new(true)));
+            }
         }
 
         if a_is_heightfield || b_is_heightfield {
@@ -70,6 +78,10 @@ impl<N: RealField> ContactDispatcher<N> for DefaultContactDispatcher {
         } else if b_is_plane && a.is_support_map() {
             let gen = PlaneConvexPolyhedronManifoldGenerator::<N>::new(true);
             Some(Box::new(gen))
+        } else if a.is_shape::<Cuboid<N>>() && b_is_ball {
+            Some(Box::new(CuboidBallManifoldGenerator::<N>::new(false)))
+        } else if a_is_ball && b.is_shape::<Cuboid<N>>() {
+            Some(Box::new(CuboidBallManifoldGenerator::<N>::new(true)))
         } else if a_is_ball && b.is_convex_polyhedron() {
             let gen = BallConvexPolyhedronManifoldGenerator::<N>::new(false);
             Some(Box::new(gen))
@@ -156,4 +156,8 @@ impl<N: RealField> ContactManifoldGenerator<N> for HeightFieldShapeManifoldGener
     //        res.set_tracking_mode(ContactTrackingMode::FeatureBased);
     //        res
     //    }
+
+    fn reset(&mut self) {
+        self.sub_detectors.clear();
+    }
 }
@@ -9,12 +9,17 @@ pub use self::composite_shape_shape_manifold_generator::CompositeShapeShapeManif
 pub use self::contact_manifold_generator::{
     ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
 };
-pub use self::convex_polyhedron_convex_polyhedron_manifold_generator::ConvexPolyhedronConvexPolyhedronManifoldGenerator;
+pub use self::convex_polyhedron_convex_polyhedron_manifold_generator::{
+    ConvexPolyhedronConvexPolyhedronManifoldGenerator, ManifoldFeatures,
+};
+pub use self::cuboid_ball_manifold_generator::CuboidBallManifoldGenerator;
 pub use self::default_contact_dispatcher::DefaultContactDispatcher;
 pub use self::heightfield_shape_manifold_generator::HeightFieldShapeManifoldGenerator;
 pub use self::plane_ball_manifold_generator::PlaneBallManifoldGenerator;
 pub use self::plane_convex_polyhedron_manifold_generator::PlaneConvexPolyhedronManifoldGenerator;
 #[cfg(feature = "dim3")]
+pub use self::triangle_ball_manifold_generator::TriangleBallManifoldGenerator;
+#[cfg(feature = "dim3")]
 pub use self::trimesh_trimesh_manifold_generator::TriMeshTriMeshManifoldGenerator;
 
 // // FIXME: un-hide this and move everything to a folder.
@@ -27,9 +32,12 @@ mod composite_shape_shape_manifold_generator;
 #[doc(hidden)]
 pub mod contact_manifold_generator;
 mod convex_polyhedron_convex_polyhedron_manifold_generator;
+mod cuboid_ball_manifold_generator;
 mod default_contact_dispatcher;
 mod heightfield_shape_manifold_generator;
 mod plane_ball_manifold_generator;
 mod plane_convex_polyhedron_manifold_generator;
 #[cfg(feature = "dim3")]
+mod triangle_ball_manifold_generator;
+#[cfg(feature = "dim3")]
 mod trimesh_trimesh_manifold_generator;
@@ -40,7 +40,7 @@ impl<N: RealField> PlaneBallManifoldGenerator<N> {
     ) -> bool {
         if let (Some(plane), Some(ball)) = (g1.as_shape::<Plane<N>>(), g2.as_shape::<Ball<N>>()) {
             let plane_normal = m1 * plane.normal;
-            let plane_center = Point::from(m1.translation.vector);
+            let plane_center = m1 * plane.center();
 
             let ball_center = Point::from(m2.translation.vector);
             let dist = (ball_center - plane_center).dot(plane_normal.as_ref());
@@ -1,4 +1,4 @@
-use crate::math::{Isometry, Point};
+use crate::math::Isometry;
 use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
 use crate::query::{
     Contact, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor,
@@ -40,7 +40,7 @@ impl<N: RealField> PlaneConvexPolyhedronManifoldGenerator<N> {
     ) -> bool {
         if let (Some(plane), Some(cp)) = (g1.as_shape::<Plane<N>>(), g2.as_convex_polyhedron()) {
             let plane_normal = m1 * plane.normal;
-            let plane_center = Point::from(m1.translation.vector);
+            let plane_center = m1 * plane.center();
 
             cp.support_face_toward(m2, &-plane_normal, poly_feature);
 
@@ -0,0 +1,142 @@
+use crate::math::{Isometry, Point};
+use crate::pipeline::narrow_phase::{ContactDispatcher, ContactManifoldGenerator};
+use crate::query::{
+    Contact, ContactKinematic, ContactManifold, ContactPrediction, ContactPreprocessor,
+    NeighborhoodGeometry, PointQueryWithLocation,
+};
+use crate::shape::{Ball, ConvexPolyhedron, FeatureId, Shape, Triangle, TrianglePointLocation};
+use na::{RealField, Unit};
+use std::marker::PhantomData;
+
+/// Collision detector between a triangle and a ball.
+///
+/// This is typically used for each triangle of a triangle mesh by the composite shape collision
+/// detectors. The closest point of the triangle to the ball center is computed analytically and
+/// the contact kinematic is set with the triangle face, edge, or vertex this point lies on.
+/// Contacts on edges and vertices can thus be recognized as contacts on internal edges of the
+/// mesh, e.g., by the normal smoothing of `CompositeShapeShapeManifoldGenerator`.
+#[derive(Clone)]
+pub struct TriangleBallManifoldGenerator<N: RealField> {
+    flip: bool,
+    phantom: PhantomData<N>,
+}
+
+impl<N: RealField> TriangleBallManifoldGenerator<N> {
+    /// Creates a new persistent collision detector between a triangle and a ball.
+    ///
+    /// If `flip` is `true`, the ball is expected to be the first shape.
+    #[inline]
+    pub fn new(flip: bool) -> TriangleBallManifoldGenerator<N> {
+        TriangleBallManifoldGenerator {
+            flip,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn do_update_to(
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+        flip: bool,
+    ) -> bool {
+        if let Some((triangle, ball)) =
+            <dyn Shape<N>>::downcast_pair::<Triangle<N>, Ball<N>>(g1, g2)
+        {
+            let tri_normal = match triangle.normal() {
+                Some(n) => n,
+                // Degenerate triangles don't generate any contact.
+                None => return true,
+            };
+
+            let ball_center = Point::from(m2.translation.vector);
+            let local_center = m1.inverse_transform_point(&ball_center);
+            let (proj, location) =
+                triangle.project_point_with_location(&Isometry::identity(), &local_center, true);
+            let local1 = proj.point;
+
+            let (local_normal, depth) =
+                match Unit::try_new_and_get(local_center - local1, N::default_epsilon()) {
+                    Some((dir, dist)) => (dir, ball.radius - dist),
+                    // The ball center lies on the triangle.
+                    None => (tri_normal, ball.radius),
+                };
+
+            if depth >= -prediction.linear() {
+                let (f1, approx_triangle) = match location {
+                    TrianglePointLocation::OnVertex(i) => {
+                        (FeatureId::Vertex(i), NeighborhoodGeometry::Point)
+                    }
+                    TrianglePointLocation::OnEdge(i, _) => {
+                        let f1 = FeatureId::Edge(i);
+                        let edge = triangle.edge(f1);
+                        let dir = Unit::new_normalize(edge.1 - edge.0);
+                        (f1, NeighborhoodGeometry::Line(dir))
+                    }
+                    TrianglePointLocation::OnFace(..) | TrianglePointLocation::OnSolid => {
+                        if local_normal.dot(&tri_normal) >= N::zero() {
+                            (FeatureId::Face(0), NeighborhoodGeometry::Plane(tri_normal))
+                        } else {
+                            (FeatureId::Face(1), NeighborhoodGeometry::Plane(-tri_normal))
+                        }
+                    }
+                };
+
+                let normal = m1 * local_normal;
+                let world1 = m1 * local1;
+                let world2 = ball_center + *normal * (-ball.radius);
+                let approx_ball = NeighborhoodGeometry::Point;
+
+                let f2 = FeatureId::Face(0);
+                let local2 = Point::origin();
+                let mut kinematic = ContactKinematic::new();
+                let contact;
+
+                if !flip {
+                    contact = Contact::new(world1, world2, normal, depth);
+                    kinematic.set_approx1(f1, local1, approx_triangle);
+                    kinematic.set_approx2(f2, local2, approx_ball);
+                    kinematic.set_dilation2(ball.radius);
+                    let _ = manifold.push(contact, kinematic, Point::origin(), proc1, proc2);
+                } else {
+                    contact = Contact::new(world2, world1, -normal, depth);
+                    kinematic.set_approx1(f2, local2, approx_ball);
+                    kinematic.set_dilation1(ball.radius);
+                    kinematic.set_approx2(f1, local1, approx_triangle);
+                    let _ = manifold.push(contact, kinematic, Point::origin(), proc2, proc1);
+                }
+            }
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<N: RealField> ContactManifoldGenerator<N> for TriangleBallManifoldGenerator<N> {
+    #[inline]
+    fn generate_contacts(
+        &mut self,
+        _: &dyn ContactDispatcher<N>,
+        m1: &Isometry<N>,
+        g1: &dyn Shape<N>,
+        proc1: Option<&dyn ContactPreprocessor<N>>,
+        m2: &Isometry<N>,
+        g2: &dyn Shape<N>,
+        proc2: Option<&dyn ContactPreprocessor<N>>,
+        prediction: &ContactPrediction<N>,
+        manifold: &mut ContactManifold<N>,
+    ) -> bool {
+        if !self.flip {
+            Self::do_update_to(m1, g1, proc1, m2, g2, proc2, prediction, manifold, false)
+        } else {
+            Self::do_update_to(m2, g2, proc2, m1, g1, proc1, prediction, manifold, true)
+        }
+    }
+}
@@ -528,8 +528,8 @@ impl<N: RealField> ContactManifoldGenerator<N> for TriMeshTriMeshManifoldGenerat
         prediction: &ContactPrediction<N>,
         manifold: &mut ContactManifold<N>,
     ) -> bool {
-        if let (Some(mesh1), Some(mesh2)) =
-            (g1.as_shape::<TriMesh<N>>(), g2.as_shape::<TriMesh<N>>())
+        if let Some((mesh1, mesh2)) =
+            <dyn Shape<N>>::downcast_pair::<TriMesh<N>, TriMesh<N>>(g1, g2)
         {
             // Find new collisions
             let m12 = m1.inverse() * m2;
@@ -1,17 +1,17 @@
 //! Persistent collision detection algorithms to compute contact points.
 
-#[cfg(feature = "dim3")]
-pub use self::contact_generator::TriMeshTriMeshManifoldGenerator;
 #[doc(inline)]
 pub use self::contact_generator::{
     BallBallManifoldGenerator, BallConvexPolyhedronManifoldGenerator,
     CapsuleCapsuleManifoldGenerator, CapsuleShapeManifoldGenerator,
     CompositeShapeCompositeShapeManifoldGenerator, CompositeShapeShapeManifoldGenerator,
     ContactAlgorithm, ContactDispatcher, ContactManifoldGenerator,
-    ConvexPolyhedronConvexPolyhedronManifoldGenerator, DefaultContactDispatcher,
-    HeightFieldShapeManifoldGenerator, PlaneBallManifoldGenerator,
-    PlaneConvexPolyhedronManifoldGenerator,
+    ConvexPolyhedronConvexPolyhedronManifoldGenerator, CuboidBallManifoldGenerator,
+    DefaultContactDispatcher, HeightFieldShapeManifoldGenerator, ManifoldFeatures,
+    PlaneBallManifoldGenerator, PlaneConvexPolyhedronManifoldGenerator,
 };
+#[cfg(feature = "dim3")]
+pub use self::contact_generator::{TriMeshTriMeshManifoldGenerator, TriangleBallManifoldGenerator};
 pub use self::events::{ContactEvent, ContactEvents, EventPool, ProximityEvent, ProximityEvents};
 pub use self::interaction_graph::{
     CollisionObjectGraphIndex, Interaction, InteractionGraph, TemporaryInteractionIndex,
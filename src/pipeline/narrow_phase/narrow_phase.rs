@@ -8,7 +8,7 @@ use crate::pipeline::narrow_phase::{
 use crate::pipeline::object::{
     CollisionObjectHandle, CollisionObjectRef, CollisionObjectSet, GeometricQueryType,
 };
-use crate::query::{ContactId, ContactManifold, Proximity};
+use crate::query::{ContactManifold, IdAllocator, Proximity};
 
 /// Collision detector dispatcher for collision objects.
 pub struct NarrowPhase<N: RealField, Handle: CollisionObjectHandle> {
@@ -16,7 +16,7 @@ pub struct NarrowPhase<N: RealField, Handle: CollisionObjectHandle> {
     proximity_dispatcher: Box<dyn ProximityDispatcher<N>>,
     contact_events: ContactEvents<Handle>,
     proximity_events: ProximityEvents<Handle>,
-    id_allocator: SlotMap<ContactId, bool>,
+    id_allocator: IdAllocator,
 }
 
 impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
@@ -68,6 +68,15 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
             .query_type()
             .contact_queries_to_prediction(co2.query_type())
         {
+            // Contacts are generated up to the persistence distance of the manifold so that
+            // tracked contacts slightly beyond the prediction are not lost.
+            let mut generation_prediction = prediction.clone();
+            let extended = manifold.persistence_distance() > prediction.linear();
+
+            if extended {
+                generation_prediction.set_linear(manifold.persistence_distance());
+            }
+
             manifold.save_cache_and_clear();
             let _ = detector.generate_contacts(
                 &*self.contact_dispatcher,
@@ -77,10 +86,16 @@ impl<N: RealField, Handle: CollisionObjectHandle> NarrowPhase<N, Handle> {
                 &co2.position(),
                 co2.shape(),
                 None,
-                &prediction,
+                &generation_prediction,
                 manifold,
             );
 
+            if extended {
+                manifold.discard_new_contacts_beyond(prediction.linear());
+            }
+
+            manifold.limit_contacts();
+
             for contact in manifold.contacts_mut() {
                 if contact.id.is_null() {
                     contact.id = self.id_allocator.insert(false)
@@ -3,21 +3,22 @@ use crate::pipeline::narrow_phase::{ProximityDetector, ProximityDispatcher};
 use crate::query::{self, Proximity};
 use crate::shape::{Ball, Shape};
 use na::RealField;
+use std::any::Any;
+use std::sync::Arc;
 
 /// Proximity detector between two balls.
-pub struct BallBallProximityDetector {}
-
-impl Clone for BallBallProximityDetector {
-    fn clone(&self) -> BallBallProximityDetector {
-        BallBallProximityDetector {}
-    }
+#[derive(Clone, Default)]
+pub struct BallBallProximityDetector {
+    compute_witnesses: bool,
+    // Type-erased `(Point<N>, Point<N>)` so this detector does not depend on the scalar type.
+    witnesses: Option<Arc<dyn Any + Send + Sync>>,
 }
 
 impl BallBallProximityDetector {
     /// Creates a new persistent collision detector between two balls.
     #[inline]
     pub fn new() -> BallBallProximityDetector {
-        BallBallProximityDetector {}
+        BallBallProximityDetector::default()
     }
 }
 
@@ -33,12 +34,30 @@ impl<N: RealField> ProximityDetector<N> for BallBallProximityDetector {
     ) -> Option<Proximity> {
         let a = a.as_shape::<Ball<N>>()?;
         let b = b.as_shape::<Ball<N>>()?;
-        Some(query::proximity_ball_ball(
-            &Point::from(ma.translation.vector),
-            a,
-            &Point::from(mb.translation.vector),
-            b,
-            margin,
-        ))
+        let center_a = Point::from(ma.translation.vector);
+        let center_b = Point::from(mb.translation.vector);
+        let proximity = query::proximity_ball_ball(&center_a, a, &center_b, b, margin);
+
+        self.witnesses = if self.compute_witnesses && proximity == Proximity::WithinMargin {
+            // The balls are disjoint so their centers are distinct.
+            let dir = (center_b - center_a).normalize();
+            let witnesses = (center_a + dir * a.radius, center_b - dir * b.radius);
+            Some(Arc::new(witnesses))
+        } else {
+            None
+        };
+
+        Some(proximity)
+    }
+
+    fn enable_witnesses(&mut self, enabled: bool) {
+        self.compute_witnesses = enabled;
+    }
+
+    fn witnesses(&self) -> Option<(Point<N>, Point<N>)> {
+        self.witnesses
+            .as_ref()?
+            .downcast_ref::<(Point<N>, Point<N>)>()
+            .cloned()
     }
 }
@@ -1,4 +1,4 @@
-use crate::math::Isometry;
+use crate::math::{Isometry, Point};
 use crate::query::Proximity;
 use crate::shape::Shape;
 use na::RealField;
@@ -17,6 +17,21 @@ pub trait ProximityDetector<N: RealField>: Any + Send + Sync {
         b: &dyn Shape<N>,
         margin: N,
     ) -> Option<Proximity>;
+
+    /// Enables or disables the computation of the closest points by the next calls to `update`.
+    ///
+    /// This is disabled by default because of its additional cost. The default implementation
+    /// does nothing.
+    fn enable_witnesses(&mut self, _enabled: bool) {}
+
+    /// The closest points, in world-space, between the two objects given to the last `update`.
+    ///
+    /// This is `None` if the witnesses are not enabled with `enable_witnesses`, if the objects
+    /// were intersecting or farther than the margin, or if this detector does not compute them.
+    /// The default implementation always returns `None`.
+    fn witnesses(&self) -> Option<(Point<N>, Point<N>)> {
+        None
+    }
 }
 
 pub type ProximityAlgorithm<N> = Box<dyn ProximityDetector<N>>;
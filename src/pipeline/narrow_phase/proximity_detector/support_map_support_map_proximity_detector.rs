@@ -1,6 +1,6 @@
-use crate::math::{Isometry, Vector};
+use crate::math::{Isometry, Point, Vector};
 use crate::pipeline::narrow_phase::{ProximityDetector, ProximityDispatcher};
-use crate::query::algorithms::VoronoiSimplex;
+use crate::query::algorithms::{gjk::GJKResult, VoronoiSimplex};
 use crate::query::{self, Proximity};
 use crate::shape::Shape;
 use na::{RealField, Unit};
@@ -12,6 +12,8 @@ use na::{RealField, Unit};
 pub struct SupportMapSupportMapProximityDetector<N: RealField> {
     simplex: VoronoiSimplex<N>,
     sep_axis: Option<Unit<Vector<N>>>,
+    compute_witnesses: bool,
+    witnesses: Option<(Point<N>, Point<N>)>,
 }
 
 impl<N: RealField> SupportMapSupportMapProximityDetector<N> {
@@ -23,10 +25,18 @@ impl<N: RealField> SupportMapSupportMapProximityDetector<N> {
         SupportMapSupportMapProximityDetector {
             simplex: VoronoiSimplex::new(),
             sep_axis: None,
+            compute_witnesses: false,
+            witnesses: None,
         }
     }
 }
 
+impl<N: RealField> Default for SupportMapSupportMapProximityDetector<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<N: RealField> ProximityDetector<N> for SupportMapSupportMapProximityDetector<N> {
     #[inline]
     fn update(
@@ -41,6 +51,33 @@ impl<N: RealField> ProximityDetector<N> for SupportMapSupportMapProximityDetecto
         let sma = a.as_support_map()?;
         let smb = b.as_support_map()?;
 
+        if self.compute_witnesses {
+            // The closest points are computed by GJK only if they are within the margin.
+            let res = query::closest_points_support_map_support_map_with_params(
+                ma,
+                sma,
+                mb,
+                smb,
+                margin,
+                &mut self.simplex,
+                self.sep_axis.map(|axis| *axis),
+            );
+
+            let (proximity, sep_axis, witnesses) = match res {
+                GJKResult::ClosestPoints(pt1, pt2, dir) => {
+                    (Proximity::WithinMargin, Some(dir), Some((pt1, pt2)))
+                }
+                GJKResult::NoIntersection(dir) => (Proximity::Disjoint, Some(dir), None),
+                GJKResult::Intersection => (Proximity::Intersecting, None, None),
+                GJKResult::Proximity(_) => unreachable!(),
+            };
+
+            self.sep_axis = sep_axis;
+            self.witnesses = witnesses;
+
+            return Some(proximity);
+        }
+
         let res = query::proximity_support_map_support_map_with_params(
             ma,
             sma,
@@ -57,6 +94,16 @@ impl<N: RealField> ProximityDetector<N> for SupportMapSupportMapProximityDetecto
             self.sep_axis = None;
         }
 
+        self.witnesses = None;
+
         Some(res.0)
     }
+
+    fn enable_witnesses(&mut self, enabled: bool) {
+        self.compute_witnesses = enabled;
+    }
+
+    fn witnesses(&self) -> Option<(Point<N>, Point<N>)> {
+        self.witnesses
+    }
 }
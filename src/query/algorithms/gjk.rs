@@ -28,6 +28,41 @@ pub fn eps_tol<N: RealField>() -> N {
     _eps * na::convert(10.0f64)
 }
 
+/// Parameters controlling the robustness of the GJK algorithm.
+///
+/// The default values are suited for shapes with sizes in the order of the unit. Shapes at
+/// much smaller or much larger scales may require scaling `eps_tol` accordingly.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GjkParams<N: RealField> {
+    /// The distance below which a point is considered to lie on the simplex, used for detecting
+    /// degenerate simplices and for deciding that the origin lies on the simplex.
+    ///
+    /// If `None`, `eps_tol()` is used with the tolerances of `VoronoiSimplex::add_point`, i.e.,
+    /// exactly as `closest_points` does.
+    pub eps_tol: Option<N>,
+    /// The maximum number of iterations before the algorithm gives up.
+    pub max_iterations: usize,
+}
+
+impl<N: RealField> GjkParams<N> {
+    /// Creates a new set of GJK parameters with an explicit tolerance.
+    pub fn new(eps_tol: N, max_iterations: usize) -> Self {
+        GjkParams {
+            eps_tol: Some(eps_tol),
+            max_iterations,
+        }
+    }
+}
+
+impl<N: RealField> Default for GjkParams<N> {
+    fn default() -> Self {
+        GjkParams {
+            eps_tol: None,
+            max_iterations: 10000,
+        }
+    }
+}
+
 /// Projects the origin on the boundary of the given shape.
 ///
 /// The origin is assumed to be outside of the shape. If it is inside,
@@ -58,6 +93,32 @@ where
     }
 }
 
+/// Projects the origin on the Minkowski difference `m1 * g1 - m2 * g2`.
+///
+/// Returns the witness points `(p1, p2)` on `m1 * g1` and `m2 * g2` such that `p1 - p2` is the
+/// point of the Minkowski difference closest to the origin, together with the simplex used by
+/// GJK to compute them. The witness points are `None` if the shapes intersect, i.e., if the
+/// origin lies inside of the Minkowski difference.
+pub fn project_origin_on_cso<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+) -> (Option<(Point<N>, Point<N>)>, VoronoiSimplex<N>)
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    let mut simplex = VoronoiSimplex::new();
+    simplex.reset(CSOPoint::from_shapes(m1, g1, m2, g2, &Vector::x_axis()));
+
+    match closest_points(m1, g1, m2, g2, N::max_value(), true, &mut simplex) {
+        GJKResult::ClosestPoints(p1, p2, _) => (Some((p1, p2)), simplex),
+        _ => (None, simplex),
+    }
+}
+
 /*
  * Separating Axis GJK
  */
@@ -67,7 +128,9 @@ where
 ///
 /// # Arguments:
 /// * simplex - the simplex to be used by the GJK algorithm. It must be already initialized
-///             with at least one point on the shape boundary.
+///             with at least one point on the shape boundary. If it was initialized with
+///             `VoronoiSimplex::reset_with_dir`, the support point along the seed direction is
+///             added to it before the first iteration.
 /// * exact_dist - if `false`, the gjk will stop as soon as it can prove that the origin is at
 /// a distance smaller than `max_dist` but not inside of `shape`. In that case, it returns a
 /// `GJKResult::Proximity(sep_axis)` where `sep_axis` is a separating axis. If `false` the gjk will
@@ -82,17 +145,56 @@ pub fn closest_points<N, G1: ?Sized, G2: ?Sized>(
     exact_dist: bool,
     simplex: &mut VoronoiSimplex<N>,
 ) -> GJKResult<N>
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    closest_points_with_params(
+        m1,
+        g1,
+        m2,
+        g2,
+        max_dist,
+        exact_dist,
+        simplex,
+        &GjkParams::default(),
+    )
+}
+
+/// Projects the origin on a shape using the Separating Axis GJK algorithm with custom
+/// tolerances.
+///
+/// This is the same as `closest_points` except that the absolute tolerance and the maximum
+/// number of iterations are given by `params` instead of `eps_tol()` and `10000`.
+pub fn closest_points_with_params<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    max_dist: N,
+    exact_dist: bool,
+    simplex: &mut VoronoiSimplex<N>,
+    params: &GjkParams<N>,
+) -> GJKResult<N>
 where
     N: RealField,
     G1: SupportMap<N>,
     G2: SupportMap<N>,
 {
     let _eps = N::default_epsilon();
-    let _eps_tol: N = eps_tol();
+    let _eps_tol: N = params.eps_tol.unwrap_or_else(eps_tol);
     let _eps_rel: N = _eps_tol.sqrt();
 
+    if let Some(seed_dir) = simplex.take_seed_dir() {
+        let _ = simplex.add_point_with(
+            CSOPoint::from_shapes(m1, g1, m2, g2, &seed_dir),
+            params.eps_tol,
+        );
+    }
+
     // FIXME: reset the simplex if it is empty?
-    let mut proj = simplex.project_origin_and_reduce();
+    let mut proj = simplex.project_origin_and_reduce_with_tolerance(_eps_tol);
 
     let mut old_dir;
 
@@ -144,7 +246,7 @@ where
             }
         }
 
-        if !simplex.add_point(cso_point) {
+        if !simplex.add_point_with(cso_point, params.eps_tol) {
             if exact_dist {
                 let (p1, p2) = result(simplex, false);
                 return GJKResult::ClosestPoints(p1, p2, dir);
@@ -154,7 +256,7 @@ where
         }
 
         old_dir = dir;
-        proj = simplex.project_origin_and_reduce();
+        proj = simplex.project_origin_and_reduce_with_tolerance(_eps_tol);
 
         if simplex.dimension() == DIM {
             if min_bound >= _eps_tol {
@@ -170,7 +272,7 @@ where
             }
         }
         niter += 1;
-        if niter == 10000 {
+        if niter == params.max_iterations {
             return GJKResult::NoIntersection(Vector::x_axis());
         }
     }
@@ -190,7 +292,7 @@ where
 {
     let m2 = Isometry::identity();
     let g2 = ConstantOrigin;
-    minkowski_ray_cast(m, shape, &m2, &g2, ray, max_toi, simplex)
+    minkowski_ray_cast(m, shape, &m2, &g2, ray, max_toi, simplex, &mut |_| {})
 }
 
 /// Compute the normal and the distance that can travel `g1` along the direction
@@ -208,21 +310,47 @@ where
     G1: SupportMap<N>,
     G2: SupportMap<N>,
 {
-    let ray = Ray::new(Point::origin(), *dir);
-    minkowski_ray_cast(m1, g1, m2, g2, &ray, N::max_value(), simplex).map(|(toi, normal)| {
-        let witnesses = if !toi.is_zero() {
-            result(simplex, simplex.dimension() == DIM)
-        } else {
-            // If there is penetration, the witness points
-            // are undefined.
-            (Point::origin(), Point::origin())
-        };
+    directional_distance_with_trace(m1, g1, m2, g2, dir, simplex, &mut |_| {})
+}
 
-        (toi, normal, witnesses.0, witnesses.1)
-    })
+/// Compute the normal and the distance that can travel `g1` along the direction
+/// `dir` so that `g1` and `g2` just touch, reporting each iteration of the computation.
+///
+/// The `on_step` callback is called at the beginning of each iteration with the current lower
+/// bound of the distance, expressed as a multiple of `dir`. It has no effect on the result which
+/// is the same as `directional_distance`.
+pub fn directional_distance_with_trace<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+    dir: &Vector<N>,
+    simplex: &mut VoronoiSimplex<N>,
+    on_step: &mut dyn FnMut(N),
+) -> Option<(N, Vector<N>, Point<N>, Point<N>)>
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    let ray = Ray::new(Point::origin(), *dir);
+    minkowski_ray_cast(m1, g1, m2, g2, &ray, N::max_value(), simplex, on_step).map(
+        |(toi, normal)| {
+            let witnesses = if !toi.is_zero() {
+                result(simplex, simplex.dimension() == DIM)
+            } else {
+                // If there is penetration, the witness points
+                // are undefined.
+                (Point::origin(), Point::origin())
+            };
+
+            (toi, normal, witnesses.0, witnesses.1)
+        },
+    )
 }
 
 // Ray-cast on the Minkowski Difference `m1 * g1 - m2 * g2`.
+#[allow(clippy::too_many_arguments)]
 fn minkowski_ray_cast<N, G1: ?Sized, G2: ?Sized>(
     m1: &Isometry<N>,
     g1: &G1,
@@ -231,6 +359,7 @@ fn minkowski_ray_cast<N, G1: ?Sized, G2: ?Sized>(
     ray: &Ray<N>,
     max_toi: N,
     simplex: &mut VoronoiSimplex<N>,
+    on_step: &mut dyn FnMut(N),
 ) -> Option<(N, Vector<N>)>
 where
     N: RealField,
@@ -264,6 +393,7 @@ where
     let mut last_chance = false;
 
     loop {
+        on_step(ltoi / ray_length);
         let old_max_bound = max_bound;
 
         if let Some((new_dir, dist)) = Unit::try_new_and_get(-proj.coords, _eps_tol) {
@@ -362,7 +492,10 @@ where
     }
 }
 
-fn result<N: RealField>(simplex: &VoronoiSimplex<N>, prev: bool) -> (Point<N>, Point<N>) {
+pub(crate) fn result<N: RealField>(
+    simplex: &VoronoiSimplex<N>,
+    prev: bool,
+) -> (Point<N>, Point<N>) {
     let mut res = (Point::origin(), Point::origin());
     if prev {
         for i in 0..simplex.prev_dimension() + 1 {
@@ -1,8 +1,8 @@
-use crate::math::{Isometry, Point};
+use crate::math::{Isometry, Point, Vector};
 use crate::query::algorithms::{gjk, CSOPoint};
 use crate::query::{PointQuery, PointQueryWithLocation};
 use crate::shape::{Segment, SegmentPointLocation, Triangle, TrianglePointLocation};
-use na::{self, RealField};
+use na::{self, RealField, Unit};
 
 /// A simplex of dimension up to 2 using Voronoï regions for computing point projections.
 #[derive(Clone, Debug)]
@@ -14,6 +14,8 @@ pub struct VoronoiSimplex<N: RealField> {
     vertices: [CSOPoint<N>; 3],
     proj: [N; 2],
     dim: usize,
+
+    seed_dir: Option<Unit<Vector<N>>>,
 }
 
 impl<N: RealField> VoronoiSimplex<N> {
@@ -26,6 +28,7 @@ impl<N: RealField> VoronoiSimplex<N> {
             vertices: [CSOPoint::origin(); 3],
             proj: [N::zero(); 2],
             dim: 0,
+            seed_dir: None,
         }
     }
 
@@ -40,16 +43,51 @@ impl<N: RealField> VoronoiSimplex<N> {
         self.prev_dim = 0;
         self.dim = 0;
         self.vertices[0] = pt;
+        self.seed_dir = None;
+    }
+
+    /// Resets this simplex to a single point and sets the first search direction of GJK.
+    ///
+    /// The next GJK query using this simplex will start by adding to it the support point of
+    /// the Minkowski difference along `dir`. A good choice is the separating direction returned
+    /// by a previous GJK query on the same pair of shapes. The seed is used only once.
+    pub fn reset_with_dir(&mut self, pt: CSOPoint<N>, dir: Unit<Vector<N>>) {
+        self.reset(pt);
+        self.seed_dir = Some(dir);
+    }
+
+    /// The search direction set by `reset_with_dir`, if it has not been used by GJK yet.
+    pub fn seed_dir(&self) -> Option<&Unit<Vector<N>>> {
+        self.seed_dir.as_ref()
+    }
+
+    pub(crate) fn take_seed_dir(&mut self) -> Option<Unit<Vector<N>>> {
+        self.seed_dir.take()
     }
 
     /// Add a point to this simplex.
     pub fn add_point(&mut self, pt: CSOPoint<N>) -> bool {
+        self.add_point_with(pt, None)
+    }
+
+    /// Add a point to this simplex, using `eps_tol` for detecting degenerate simplices.
+    ///
+    /// Returns `false` if the point was not added because its distance to one of the vertices
+    /// of this simplex is smaller than `eps_tol`.
+    pub fn add_point_with_tolerance(&mut self, pt: CSOPoint<N>, eps_tol: N) -> bool {
+        self.add_point_with(pt, Some(eps_tol))
+    }
+
+    // Adds a point to this simplex, using the tolerance of `add_point_with_tolerance` if
+    // `eps_tol` is set, and the one of `add_point` otherwise.
+    pub(crate) fn add_point_with(&mut self, pt: CSOPoint<N>, eps_tol: Option<N>) -> bool {
         self.prev_dim = self.dim;
         self.prev_proj = self.proj;
         self.prev_vertices = [0, 1, 2];
+        let threshold = eps_tol.map_or_else(gjk::eps_tol, |eps| eps * eps);
 
         for i in 0..self.dim + 1 {
-            if (self.vertices[i].point - pt.point).norm_squared() < gjk::eps_tol() {
+            if (self.vertices[i].point - pt.point).norm_squared() < threshold {
                 return false;
             }
         }
@@ -83,6 +121,14 @@ impl<N: RealField> VoronoiSimplex<N> {
         &self.vertices[self.prev_vertices[i]]
     }
 
+    /// Projects the origin on the boundary of this simplex and reduces `self` the smallest subsimplex containing the origin.
+    ///
+    /// This is the same as `project_origin_and_reduce`: in 2D, no tolerance is needed for the
+    /// projection.
+    pub fn project_origin_and_reduce_with_tolerance(&mut self, _eps_tol: N) -> Point<N> {
+        self.project_origin_and_reduce()
+    }
+
     /// Projets the origin on the boundary of this simplex and reduces `self` the smallest subsimplex containing the origin.
     ///
     /// Retruns the result of the projection or Point::origin() if the origin lies inside of the simplex.
@@ -1,11 +1,11 @@
-use crate::math::{Isometry, Point};
+use crate::math::{Isometry, Point, Vector};
 use crate::query::algorithms::{gjk, CSOPoint};
 use crate::query::{PointQuery, PointQueryWithLocation};
 use crate::shape::{
     Segment, SegmentPointLocation, Tetrahedron, TetrahedronPointLocation, Triangle,
     TrianglePointLocation,
 };
-use na::{self, RealField};
+use na::{self, RealField, Unit};
 
 /// A simplex of dimension up to 3 that uses Voronoï regions for computing point projections.
 #[derive(Clone, Debug)]
@@ -17,6 +17,8 @@ pub struct VoronoiSimplex<N: RealField> {
     vertices: [CSOPoint<N>; 4],
     proj: [N; 3],
     dim: usize,
+
+    seed_dir: Option<Unit<Vector<N>>>,
 }
 
 impl<N: RealField> VoronoiSimplex<N> {
@@ -29,6 +31,7 @@ impl<N: RealField> VoronoiSimplex<N> {
             vertices: [CSOPoint::origin(); 4],
             proj: [N::zero(); 3],
             dim: 0,
+            seed_dir: None,
         }
     }
 
@@ -43,25 +46,63 @@ impl<N: RealField> VoronoiSimplex<N> {
         self.dim = 0;
         self.prev_dim = 0;
         self.vertices[0] = pt;
+        self.seed_dir = None;
+    }
+
+    /// Resets this simplex to a single point and sets the first search direction of GJK.
+    ///
+    /// The next GJK query using this simplex will start by adding to it the support point of
+    /// the Minkowski difference along `dir`. A good choice is the separating direction returned
+    /// by a previous GJK query on the same pair of shapes. The seed is used only once.
+    pub fn reset_with_dir(&mut self, pt: CSOPoint<N>, dir: Unit<Vector<N>>) {
+        self.reset(pt);
+        self.seed_dir = Some(dir);
+    }
+
+    /// The search direction set by `reset_with_dir`, if it has not been used by GJK yet.
+    pub fn seed_dir(&self) -> Option<&Unit<Vector<N>>> {
+        self.seed_dir.as_ref()
+    }
+
+    pub(crate) fn take_seed_dir(&mut self) -> Option<Unit<Vector<N>>> {
+        self.seed_dir.take()
     }
 
     /// Add a point to this simplex.
     pub fn add_point(&mut self, pt: CSOPoint<N>) -> bool {
+        self.add_point_with(pt, None)
+    }
+
+    /// Add a point to this simplex, using `eps_tol` for detecting degenerate simplices.
+    ///
+    /// Returns `false` if the point was not added because its distance to the affine hull of
+    /// this simplex (a point, a line, or a plane) is smaller than `eps_tol`.
+    pub fn add_point_with_tolerance(&mut self, pt: CSOPoint<N>, eps_tol: N) -> bool {
+        self.add_point_with(pt, Some(eps_tol))
+    }
+
+    // Adds a point to this simplex, using the tolerances of `add_point_with_tolerance` if
+    // `eps_tol` is set, and those of `add_point` otherwise.
+    pub(crate) fn add_point_with(&mut self, pt: CSOPoint<N>, eps_tol: Option<N>) -> bool {
         self.prev_dim = self.dim;
         self.prev_proj = self.proj;
         self.prev_vertices = [0, 1, 2, 3];
 
         match self.dim {
             0 => {
-                if (self.vertices[0] - pt).norm_squared() < gjk::eps_tol() {
+                let threshold = eps_tol.map_or_else(gjk::eps_tol, |eps| eps * eps);
+
+                if (self.vertices[0] - pt).norm_squared() < threshold {
                     return false;
                 }
             }
             1 => {
                 let ab = self.vertices[1] - self.vertices[0];
                 let ac = pt - self.vertices[0];
+                let threshold =
+                    eps_tol.map_or_else(gjk::eps_tol, |eps| eps * eps * ab.norm_squared());
 
-                if ab.cross(&ac).norm_squared() < gjk::eps_tol() {
+                if ab.cross(&ac).norm_squared() < threshold {
                     return false;
                 }
             }
@@ -71,7 +112,7 @@ impl<N: RealField> VoronoiSimplex<N> {
                 let ap = pt - self.vertices[0];
                 let n = ab.cross(&ac).normalize();
 
-                if n.dot(&ap).abs() < gjk::eps_tol() {
+                if n.dot(&ap).abs() < eps_tol.unwrap_or_else(gjk::eps_tol) {
                     return false;
                 }
             }
@@ -112,7 +153,18 @@ impl<N: RealField> VoronoiSimplex<N> {
     /// Retruns the result of the projection or Point::origin() if the origin lies inside of the simplex.
     /// The state of the samplex before projection is saved, and can be retrieved using the methods prefixed
     /// by `prev_`.
+    ///
+    /// A nearly flat tetrahedron is reduced to its largest face before projection.
     pub fn project_origin_and_reduce(&mut self) -> Point<N> {
+        self.project_origin_and_reduce_with_tolerance(gjk::eps_tol())
+    }
+
+    /// Projects the origin on the boundary of this simplex and reduces `self` the smallest subsimplex containing the origin.
+    ///
+    /// This is the same as `project_origin_and_reduce` except that a tetrahedron is considered
+    /// nearly flat if the distance between its largest face and the opposite vertex is smaller
+    /// than `eps_tol` instead of `gjk::eps_tol()`.
+    pub fn project_origin_and_reduce_with_tolerance(&mut self, eps_tol: N) -> Point<N> {
         if self.dim == 0 {
             self.proj[0] = N::one();
             self.vertices[0].point
@@ -184,6 +236,22 @@ impl<N: RealField> VoronoiSimplex<N> {
             proj.point
         } else {
             assert!(self.dim == 3);
+
+            if let Some(face) = self.degenerate_tetrahedron_face(eps_tol) {
+                // The tetrahedron is too flat for its projection to be reliable so we
+                // reduce it to its largest face first.
+                match face {
+                    0 => {}
+                    1 => self.swap(2, 3),
+                    2 => self.swap(1, 3),
+                    3 => self.swap(0, 3),
+                    _ => unreachable!(),
+                }
+
+                self.dim = 2;
+                return self.project_origin_and_reduce_with_tolerance(eps_tol);
+            }
+
             // FIXME: NLL
             let (proj, location) = {
                 let tetr = Tetrahedron::new(
@@ -279,6 +347,46 @@ impl<N: RealField> VoronoiSimplex<N> {
         }
     }
 
+    /// If this simplex is a nearly flat tetrahedron, returns the index of its largest face.
+    ///
+    /// Faces are numbered as by `TetrahedronPointLocation`, i.e., `abc`, `abd`, `acd`, `bcd`.
+    /// The tetrahedron is considered degenerate if the distance between its largest face and
+    /// the opposite vertex is smaller than `eps_tol`.
+    fn degenerate_tetrahedron_face(&self, eps_tol: N) -> Option<usize> {
+        let a = self.vertices[0].point;
+        let b = self.vertices[1].point;
+        let c = self.vertices[2].point;
+        let d = self.vertices[3].point;
+
+        let ab = b - a;
+        let ac = c - a;
+        let ad = d - a;
+        let bc = c - b;
+        let bd = d - b;
+
+        let normals = [ab.cross(&ac), ab.cross(&ad), ac.cross(&ad), bc.cross(&bd)];
+        let mut face = 0;
+        let mut max_area = N::zero();
+
+        for (i, n) in normals.iter().enumerate() {
+            let area = n.norm();
+
+            if area > max_area {
+                face = i;
+                max_area = area;
+            }
+        }
+
+        // Six times the volume, i.e., twice the area of the largest face times its height.
+        let volume = normals[0].dot(&ad).abs();
+
+        if volume <= eps_tol * max_area {
+            Some(face)
+        } else {
+            None
+        }
+    }
+
     /// Compute the projection of the origin on the boundary of this simplex.
     pub fn project_origin(&mut self) -> Point<N> {
         if self.dim == 0 {
@@ -0,0 +1,44 @@
+use crate::math::Isometry;
+use crate::query;
+use crate::query::algorithms::{gjk::GJKResult, VoronoiSimplex};
+use crate::shape::{ConvexPolyhedron, FeatureId};
+use na::RealField;
+
+/// The features of two disjoint convex polyhedra that are the closest to each other.
+///
+/// Each feature is the one with a normal cone containing the direction joining the closest
+/// points of the shapes (see `ConvexPolyhedron::support_feature_id_toward`). Those are the
+/// features that would touch first if the shapes were moved toward each other along this
+/// direction. Returns `(FeatureId::Unknown, FeatureId::Unknown)` if the shapes intersect.
+pub fn closest_feature_pair<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+) -> (FeatureId, FeatureId)
+where
+    N: RealField,
+    G1: ConvexPolyhedron<N>,
+    G2: ConvexPolyhedron<N>,
+{
+    match query::closest_points_support_map_support_map_with_params(
+        m1,
+        g1,
+        m2,
+        g2,
+        N::max_value(),
+        &mut VoronoiSimplex::new(),
+        None,
+    ) {
+        GJKResult::ClosestPoints(_, _, dir) => {
+            let local_dir1 = m1.inverse_transform_unit_vector(&dir);
+            let local_dir2 = m2.inverse_transform_unit_vector(&-dir);
+
+            (
+                g1.support_feature_id_toward(&local_dir1),
+                g2.support_feature_id_toward(&local_dir2),
+            )
+        }
+        _ => (FeatureId::Unknown, FeatureId::Unknown),
+    }
+}
@@ -20,17 +20,21 @@ pub fn closest_points_ball_ball<N: RealField>(
     let r1 = b1.radius;
     let r2 = b2.radius;
     let delta_pos = *center2 - *center1;
-    let distance = delta_pos.norm();
+    let distance_squared = delta_pos.norm_squared();
     let sum_radius = r1 + r2;
 
-    if distance - margin <= sum_radius {
-        if distance <= sum_radius {
-            ClosestPoints::Intersecting
-        } else {
-            let normal = delta_pos.normalize();
+    // NOTE: this classification uses the same computations as `distance_ball_ball` so both
+    // queries agree on which side of the margin the balls are.
+    if distance_squared <= sum_radius * sum_radius {
+        ClosestPoints::Intersecting
+    } else {
+        let distance = distance_squared.sqrt();
+
+        if distance - sum_radius <= margin {
+            let normal = delta_pos / distance;
             ClosestPoints::WithinMargin(*center1 + normal * r1, *center2 + normal * (-r2))
+        } else {
+            ClosestPoints::Disjoint
         }
-    } else {
-        ClosestPoints::Disjoint
     }
 }
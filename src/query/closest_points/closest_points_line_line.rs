@@ -30,6 +30,34 @@ pub fn closest_points_line_line_parameters_eps<N: RealField>(
     orig2: &Point<N>,
     dir2: &Vector<N>,
     eps: N,
+) -> (N, N, bool) {
+    closest_points_line_line_parameters_with_tolerance(orig1, dir1, orig2, dir2, eps, false)
+}
+
+/// Closest points between two lines with a tolerance epsilon relative to their directions.
+///
+/// This is the same as `closest_points_line_line_parameters_eps` except that the lines are
+/// considered parallel if the squared sine of the angle between their directions, i.e.,
+/// `|dir1 × dir2|² / (|dir1|² |dir2|²)`, is smaller than `eps`. This test does not depend on
+/// the norms of the directions.
+#[inline]
+pub fn closest_points_line_line_parameters_relative_eps<N: RealField>(
+    orig1: &Point<N>,
+    dir1: &Vector<N>,
+    orig2: &Point<N>,
+    dir2: &Vector<N>,
+    eps: N,
+) -> (N, N, bool) {
+    closest_points_line_line_parameters_with_tolerance(orig1, dir1, orig2, dir2, eps, true)
+}
+
+fn closest_points_line_line_parameters_with_tolerance<N: RealField>(
+    orig1: &Point<N>,
+    dir1: &Vector<N>,
+    orig2: &Point<N>,
+    dir2: &Vector<N>,
+    eps: N,
+    relative: bool,
 ) -> (N, N, bool) {
     // Inspired by RealField-time collision detection by Christer Ericson.
     let r = *orig1 - *orig2;
@@ -55,8 +83,10 @@ pub fn closest_points_line_line_parameters_eps<N: RealField>(
             let bb = b * b;
             let denom = ae - bb;
 
-            // Use absolute and ulps error to test collinearity.
-            let parallel = denom <= eps || ulps_eq!(ae, bb);
+            // `denom` is the squared norm of `dir1 × dir2`, so the relative collinearity test
+            // compares it to `ae`. Use absolute and ulps error to test collinearity.
+            let threshold = if relative { eps * ae } else { eps };
+            let parallel = denom <= threshold || ulps_eq!(ae, bb);
 
             let s = if !parallel {
                 (b * f - c * e) / denom
@@ -1,6 +1,6 @@
 use na::{self, RealField};
 
-use crate::math::{Isometry, Point};
+use crate::math::Isometry;
 use crate::query::ClosestPoints;
 use crate::shape::Plane;
 use crate::shape::SupportMap;
@@ -19,7 +19,7 @@ pub fn closest_points_plane_support_map<N: RealField, G: ?Sized + SupportMap<N>>
     );
 
     let plane_normal = mplane * plane.normal;
-    let plane_center = Point::from(mplane.translation.vector);
+    let plane_center = mplane * plane.center();
     let deepest = other.support_point(mother, &-plane_normal);
 
     let distance = plane_normal.dot(&(plane_center - deepest));
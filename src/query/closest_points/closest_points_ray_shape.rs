@@ -0,0 +1,61 @@
+use na::{self, RealField};
+
+use crate::math::{Isometry, Point};
+use crate::query::algorithms::{gjk, gjk::GJKResult, VoronoiSimplex};
+use crate::query::{self, Ray};
+use crate::shape::{Segment, SupportMap};
+
+/// Computes the closest points between a ray and a support-mapped shape transformed by `m`.
+///
+/// Returns the closest point on the ray, the closest point on the shape, and the distance
+/// between them. The ray is considered as a half-line so its closest point never lies behind
+/// its origin. If the ray intersects the shape, both points are equal to its first intersection
+/// with the shape and the distance is zero.
+pub fn closest_point_ray_shape<N, G: ?Sized>(
+    ray: &Ray<N>,
+    m: &Isometry<N>,
+    g: &G,
+) -> (Point<N>, Point<N>, N)
+where
+    N: RealField,
+    G: SupportMap<N>,
+{
+    // The closest point on the ray cannot be further than the projection on the ray of the shape
+    // support point along its direction. So we may replace the ray by a segment.
+    let supp = g.support_point(m, &ray.dir);
+    let max_t = ((supp - ray.origin).dot(&ray.dir) / ray.dir.norm_squared()).max(N::zero());
+    let segment = Segment::new(ray.origin, ray.point_at(max_t));
+    let mut simplex = VoronoiSimplex::new();
+
+    match query::closest_points_support_map_support_map_with_params(
+        &Isometry::identity(),
+        &segment,
+        m,
+        g,
+        N::max_value(),
+        &mut simplex,
+        None,
+    ) {
+        GJKResult::ClosestPoints(pt1, pt2, _) => (pt1, pt2, na::distance(&pt1, &pt2)),
+        GJKResult::Intersection => {
+            let toi = query::ray_intersection_with_support_map_with_params(
+                m,
+                g,
+                &mut simplex,
+                ray,
+                max_t,
+                true,
+            )
+            .map(|inter| inter.toi)
+            .unwrap_or(N::zero());
+            let pt = ray.point_at(toi);
+
+            (pt, pt, N::zero())
+        }
+        // The maximum number of iterations was reached: use the last approximation.
+        GJKResult::NoIntersection(_) | GJKResult::Proximity(_) => {
+            let (pt1, pt2) = gjk::result(&simplex, false);
+            (pt1, pt2, na::distance(&pt1, &pt2))
+        }
+    }
+}
@@ -6,7 +6,9 @@ use crate::shape::{Ball, Plane, Segment, Shape};
 
 /// Computes the pair of closest points between two shapes.
 ///
-/// Returns `None` if the objects are separated by a distance greater than `max_dist`.
+/// Returns `ClosestPoints::Disjoint` if the objects are separated by a distance greater than
+/// `max_dist`, in which case the closest points are not computed. Use `N::max_value()` as
+/// `max_dist` to always get the closest points of separated objects.
 pub fn closest_points<N: RealField>(
     m1: &Isometry<N>,
     g1: &dyn Shape<N>,
@@ -36,3 +38,20 @@ pub fn closest_points<N: RealField>(
         panic!("No algorithm known to compute a contact point between the given pair of shapes.")
     }
 }
+
+/// Computes the pair of closest points between two shapes, whatever the distance between them.
+///
+/// Unlike `query::closest_points`, this does not take any margin: the closest points are returned
+/// even if the objects are arbitrarily far from each other. Returns `None` if the objects are
+/// intersecting, or if their closest points could not be computed.
+pub fn closest_points_witnesses<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> Option<(Point<N>, Point<N>)> {
+    match closest_points(m1, g1, m2, g2, N::max_value()) {
+        ClosestPoints::WithinMargin(p1, p2) => Some((p1, p2)),
+        ClosestPoints::Intersecting | ClosestPoints::Disjoint => None,
+    }
+}
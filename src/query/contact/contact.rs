@@ -1,7 +1,7 @@
-use crate::math::{Point, Vector};
+use crate::math::{Isometry, Point, Vector};
 use crate::query::ContactKinematic;
 use na::{self, RealField, Unit};
-use slotmap::Key;
+use slotmap::{Key, SlotMap};
 use std::mem;
 
 slotmap::new_key_type! {
@@ -9,6 +9,12 @@ slotmap::new_key_type! {
     pub struct ContactId;
 }
 
+/// An allocator of unique contact identifiers.
+///
+/// The boolean associated to each identifier is used for marking identifiers still in use during
+/// garbage collection.
+pub type IdAllocator = SlotMap<ContactId, bool>;
+
 /// Geometric description of a contact.
 #[derive(Debug, PartialEq, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -44,6 +50,111 @@ impl<N: RealField> Contact<N> {
         let depth = -normal.dot(&(world2 - world1));
         Self::new(world1, world2, normal, depth)
     }
+
+    /// Updates the witness points and the depth of this contact after the shapes moved to `m1` and `m2`.
+    ///
+    /// The witness points are set to `local1` and `local2` (given in the local-space of the
+    /// first and second shapes) transformed by the new poses, and the depth is recomputed along
+    /// the current normal, which is left unchanged.
+    #[inline]
+    pub fn update_depth(
+        &mut self,
+        m1: &Isometry<N>,
+        m2: &Isometry<N>,
+        local1: &Point<N>,
+        local2: &Point<N>,
+    ) {
+        self.world1 = m1 * local1;
+        self.world2 = m2 * local2;
+        self.depth = -self.normal.dot(&(self.world2 - self.world1));
+    }
+
+    /// Checks if `self` and `other` describe the same contact up to the tolerance `eps`.
+    ///
+    /// The witness points and depths are compared by distance, and the normals are compared by
+    /// the angle between them (in radians).
+    #[inline]
+    pub fn approx_eq(&self, other: &Contact<N>, eps: N) -> bool {
+        // Computing the angle from the chord length is accurate even for nearly equal normals.
+        let two: N = na::convert(2.0f64);
+        let half_chord = (*self.normal - *other.normal).norm() / two;
+        let angle = half_chord.min(N::one()).asin() * two;
+
+        na::distance(&self.world1, &other.world1) <= eps
+            && na::distance(&self.world2, &other.world2) <= eps
+            && (self.depth - other.depth).abs() <= eps
+            && angle <= eps
+    }
+
+    /// An orthonormal basis of the plane orthogonal to the contact normal.
+    ///
+    /// The returned tangent is the normal rotated by a quarter turn counterclockwise.
+    #[cfg(feature = "dim2")]
+    #[inline]
+    pub fn tangent_basis(&self) -> [Unit<Vector<N>>; 1] {
+        [Unit::new_unchecked(Vector::new(
+            -self.normal.y,
+            self.normal.x,
+        ))]
+    }
+
+    /// An orthonormal basis of the plane orthogonal to the contact normal.
+    ///
+    /// The two returned tangents `[t1, t2]` are such that `(t1, t2, normal)` forms a direct
+    /// orthonormal frame.
+    #[cfg(feature = "dim3")]
+    #[inline]
+    pub fn tangent_basis(&self) -> [Unit<Vector<N>>; 2] {
+        let n = &self.normal;
+
+        // Zero-out the component of smallest magnitude among `x` and `y`, and build `t1`
+        // from the two others to avoid constructing a vector close to zero.
+        let t1 = if n.x.abs() > n.y.abs() {
+            Vector::new(n.z, N::zero(), -n.x)
+        } else {
+            Vector::new(N::zero(), -n.z, n.y)
+        };
+        let t1 = Unit::new_normalize(t1);
+        let t2 = Unit::new_unchecked(n.cross(&t1));
+
+        [t1, t2]
+    }
+
+    /// The relative velocity of the witness points along the contact normal.
+    ///
+    /// This computes `(v2 + w2 × world2 - v1 - w1 × world1) · normal`, i.e., the witness points
+    /// are used as the moment arms. Thus `v1` and `v2` are the linear velocities, at the
+    /// world-space origin, of the first and second bodies, and `w1` and `w2` their angular
+    /// velocities. Because the normal points toward the second body, the result is negative if
+    /// the bodies are approaching each other and positive if they are separating.
+    #[cfg(feature = "dim2")]
+    #[inline]
+    pub fn relative_normal_velocity(&self, v1: &Vector<N>, w1: N, v2: &Vector<N>, w2: N) -> N {
+        let vel1 = v1 + Vector::new(-self.world1.y, self.world1.x) * w1;
+        let vel2 = v2 + Vector::new(-self.world2.y, self.world2.x) * w2;
+        self.normal.dot(&(vel2 - vel1))
+    }
+
+    /// The relative velocity of the witness points along the contact normal.
+    ///
+    /// This computes `(v2 + w2 × world2 - v1 - w1 × world1) · normal`, i.e., the witness points
+    /// are used as the moment arms. Thus `v1` and `v2` are the linear velocities, at the
+    /// world-space origin, of the first and second bodies, and `w1` and `w2` their angular
+    /// velocities. Because the normal points toward the second body, the result is negative if
+    /// the bodies are approaching each other and positive if they are separating.
+    #[cfg(feature = "dim3")]
+    #[inline]
+    pub fn relative_normal_velocity(
+        &self,
+        v1: &Vector<N>,
+        w1: &Vector<N>,
+        v2: &Vector<N>,
+        w2: &Vector<N>,
+    ) -> N {
+        let vel1 = v1 + w1.cross(&self.world1.coords);
+        let vel2 = v2 + w2.cross(&self.world2.coords);
+        self.normal.dot(&(vel2 - vel1))
+    }
 }
 
 impl<N: RealField> Contact<N> {
@@ -82,6 +193,38 @@ impl<N: RealField> TrackedContact<N> {
     }
 }
 
+/// A contact with a flat, C-compatible, memory layout.
+///
+/// Each point or vector field is laid out as an array of `DIM` scalars. See
+/// `ContactManifold::export_flat`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FlatContact<N: RealField> {
+    /// The contact point on the first object, in world-space.
+    pub p1: Point<N>,
+    /// The contact point on the second object, in world-space.
+    pub p2: Point<N>,
+    /// The contact normal, in world-space, oriented toward the exterior of the first object.
+    pub normal: Vector<N>,
+    /// The penetration depth of this contact.
+    pub depth: N,
+    /// The identifier of this contact, as given by `ContactId::data().as_ffi()`.
+    pub id: u64,
+}
+
+impl<N: RealField> FlatContact<N> {
+    /// Flattens the given tracked contact.
+    pub fn from_tracked(contact: &TrackedContact<N>) -> Self {
+        FlatContact {
+            p1: contact.contact.world1,
+            p2: contact.contact.world2,
+            normal: *contact.contact.normal,
+            depth: contact.contact.depth,
+            id: contact.id.data().as_ffi(),
+        }
+    }
+}
+
 /// The prediction parameters for contact determination.
 #[derive(Clone, Debug, PartialEq)]
 pub struct ContactPrediction<N: RealField> {
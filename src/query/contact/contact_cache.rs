@@ -0,0 +1,76 @@
+use crate::query::ContactManifold;
+use crate::shape::FeatureId;
+use na::RealField;
+use std::collections::{HashMap, HashSet};
+
+/// A map from pairs of features to user data attached to the contact between them.
+///
+/// This is typically used to store solver data for warm-starting. The pairs of features are
+/// keyed by their packed representation (see `FeatureId::pack`), the same way the contacts of a
+/// `ContactManifold` are tracked with `ContactTrackingMode::FeatureBased`.
+#[derive(Clone, Debug)]
+pub struct ContactCache<T> {
+    data: HashMap<(u64, u64), T>,
+}
+
+impl<T> ContactCache<T> {
+    /// Creates an empty contact cache.
+    pub fn new() -> Self {
+        ContactCache {
+            data: HashMap::new(),
+        }
+    }
+
+    /// The number of feature pairs with data in this cache.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if this cache does not contain any data.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Sets the data attached to the given pair of features.
+    ///
+    /// Returns the data previously attached to this pair, if any.
+    pub fn insert(&mut self, feature1: FeatureId, feature2: FeatureId, data: T) -> Option<T> {
+        self.data.insert((feature1.pack(), feature2.pack()), data)
+    }
+
+    /// The data attached to the given pair of features, if any.
+    pub fn get(&self, feature1: FeatureId, feature2: FeatureId) -> Option<&T> {
+        self.data.get(&(feature1.pack(), feature2.pack()))
+    }
+
+    /// Mutable reference to the data attached to the given pair of features, if any.
+    pub fn get_mut(&mut self, feature1: FeatureId, feature2: FeatureId) -> Option<&mut T> {
+        self.data.get_mut(&(feature1.pack(), feature2.pack()))
+    }
+
+    /// Removes the data attached to the given pair of features.
+    pub fn remove(&mut self, feature1: FeatureId, feature2: FeatureId) -> Option<T> {
+        self.data.remove(&(feature1.pack(), feature2.pack()))
+    }
+
+    /// Removes the data of all the feature pairs.
+    pub fn clear(&mut self) {
+        self.data.clear()
+    }
+
+    /// Removes the data of the feature pairs not matching any contact of `manifold`.
+    pub fn retain_matching<N: RealField>(&mut self, manifold: &ContactManifold<N>) {
+        let pairs: HashSet<_> = manifold
+            .contacts()
+            .map(|c| (c.kinematic.feature1().pack(), c.kinematic.feature2().pack()))
+            .collect();
+
+        self.data.retain(|k, _| pairs.contains(k))
+    }
+}
+
+impl<T> Default for ContactCache<T> {
+    fn default() -> Self {
+        ContactCache::new()
+    }
+}
@@ -1,9 +1,13 @@
 use crate::bounding_volume::BoundingVolume;
 use crate::math::Isometry;
-use crate::query::visitors::BoundingVolumeInterferencesCollector;
-use crate::query::{self, Contact};
-use crate::shape::{CompositeShape, Shape};
+use crate::pipeline::narrow_phase::ContactDispatcher;
+use crate::query::visitors::{
+    AABBSetsInterferencesCollector, BoundingVolumeInterferencesCollector,
+};
+use crate::query::{self, Contact, ContactManifold, ContactPrediction, IdAllocator};
+use crate::shape::{CompositeShape, Compound, Shape};
 use na::{self, RealField};
+use std::collections::HashSet;
 
 /// Best contact between a composite shape (`Mesh`, `Compound`) and any other shape.
 pub fn contact_composite_shape_shape<N: RealField, G1: ?Sized>(
@@ -61,3 +65,121 @@ where
     }
     res
 }
+
+/// All the contacts between a shape and the parts of a compound shape.
+///
+/// The contacts between `g1` and each part of `compound` with an AABB interfering with the
+/// AABB of `g1` (loosened by the linear prediction) are computed with the contact manifold
+/// generators given by `dispatcher`, and pushed into `manifold` after its contacts are saved
+/// into its cache. The second feature of each contact is the feature of the whole compound,
+/// not of its part, so contacts on different parts are never confused.
+///
+/// The `manifold` should be kept by the caller from one call to the next: contacts matched with
+/// the previous call keep their identifier, the other ones are given a new identifier from
+/// `id_alloc`, and the identifiers of the contacts that are no longer tracked are removed from
+/// `id_alloc`.
+pub fn contacts_shape_compound<N: RealField>(
+    dispatcher: &dyn ContactDispatcher<N>,
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    compound: &Compound<N>,
+    prediction: &ContactPrediction<N>,
+    id_alloc: &mut IdAllocator,
+    manifold: &mut ContactManifold<N>,
+) {
+    let ls_m1 = m2.inverse() * m1;
+    let ls_aabb1 = g1.aabb(&ls_m1).loosened(prediction.linear());
+    let prev_ids: Vec<_> = manifold.contacts().map(|c| c.id).collect();
+    let mut interferences = Vec::new();
+
+    {
+        let mut visitor = BoundingVolumeInterferencesCollector::new(&ls_aabb1, &mut interferences);
+        compound.bvh().visit(&mut visitor);
+    }
+
+    manifold.save_cache_and_clear();
+
+    for i in interferences.into_iter() {
+        compound.map_part_and_preprocessor_at(i, m2, prediction, &mut |m, part, proc2| {
+            if let Some(mut generator) = dispatcher.get_contact_algorithm(g1, part) {
+                let _ = generator.generate_contacts(
+                    dispatcher,
+                    m1,
+                    g1,
+                    None,
+                    m,
+                    part,
+                    Some(proc2),
+                    prediction,
+                    manifold,
+                );
+            }
+        });
+    }
+
+    for contact in manifold.contacts_mut() {
+        // New contacts have a null identifier, and a cached contact revived by this update
+        // may have had its identifier removed already.
+        if !id_alloc.contains_key(contact.id) {
+            contact.id = id_alloc.insert(false)
+        }
+    }
+
+    let curr_ids: HashSet<_> = manifold.contacts().map(|c| c.id).collect();
+
+    for id in prev_ids {
+        if !curr_ids.contains(&id) {
+            let _ = id_alloc.remove(id);
+        }
+    }
+}
+
+/// Deepest contact between two composite shapes (`Mesh`, `Compound`).
+///
+/// The pairs of parts with interfering AABBs are found with a simultaneous traversal of both
+/// BVHs and the contact with the largest depth among all the part-part contacts is returned.
+/// Returns `None` if the composite shapes do not intersect.
+pub fn deepest_contact_composite_composite<N: RealField, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+) -> Option<Contact<N>>
+where
+    G1: CompositeShape<N>,
+    G2: CompositeShape<N>,
+{
+    let ls_m2 = m1.inverse() * m2;
+    // For transforming AABBs from g2 in the local space of g1.
+    let ls_m2_abs_rot = ls_m2.rotation.to_rotation_matrix().matrix().abs();
+    let mut interferences = Vec::new();
+
+    {
+        let mut visitor = AABBSetsInterferencesCollector::new(
+            N::zero(),
+            &ls_m2,
+            &ls_m2_abs_rot,
+            &mut interferences,
+        );
+        g1.bvh().visit_bvtt(g2.bvh(), &mut visitor);
+    }
+
+    let mut res = None::<Contact<N>>;
+
+    for (i1, i2) in interferences.into_iter() {
+        g1.map_part_at(i1, m1, &mut |m1, part1| {
+            g2.map_part_at(i2, m2, &mut |m2, part2| {
+                if let Some(c) = query::contact(m1, part1, m2, part2, N::zero()) {
+                    let replace = res.map_or(true, |cbest| c.depth > cbest.depth);
+
+                    if replace && c.depth > N::zero() {
+                        res = Some(c)
+                    }
+                }
+            });
+        });
+    }
+
+    res
+}
@@ -161,11 +161,15 @@ impl<N: RealField> ContactKinematic<N> {
     }
 
     /// Sets the dilation of the first solid.
+    ///
+    /// A negative dilation erodes the solid. See `self.contact` for details.
     pub fn set_dilation1(&mut self, margin: N) {
         self.margin1 = margin;
     }
 
     /// Sets the dilation of the second solid.
+    ///
+    /// A negative dilation erodes the solid. See `self.contact` for details.
     pub fn set_dilation2(&mut self, margin: N) {
         self.margin2 = margin;
     }
@@ -215,6 +219,12 @@ impl<N: RealField> ContactKinematic<N> {
     /// The vector `default_normal1` is the normal of the resulting contact
     /// in the rare case where the contact normal cannot be determined by the update.
     /// Typically, this should be set to the latest contact normal known.
+    ///
+    /// The contact points are moved along the normal by the dilations of their solids, i.e.,
+    /// outward for positive dilations and inward for negative dilations, and the depth changes
+    /// accordingly. Negative dilations erode the solids, and cannot erode them past each other:
+    /// the erosion decreases the depth linearly, but not below zero. If the depth is already
+    /// negative before the erosion, the erosion is ignored.
     pub fn contact(
         &self,
         m1: &Isometry<N>,
@@ -326,11 +336,18 @@ impl<N: RealField> ContactKinematic<N> {
             (NeighborhoodGeometry::Line(dir1), NeighborhoodGeometry::Line(dir2)) => {
                 let world_dir1 = m1 * dir1;
                 let world_dir2 = m2 * dir2;
-                let (pt1, pt2) =
-                    query::closest_points_line_line(&world1, &world_dir1, &world2, &world_dir2);
-
-                world1 = pt1;
-                world2 = pt2;
+                // Nearly parallel lines have far away and unstable closest points so they are
+                // handled as parallel lines, i.e., `world1` is projected on the second line.
+                let (s, t, _) = query::closest_points_line_line_parameters_relative_eps(
+                    &world1,
+                    &world_dir1,
+                    &world2,
+                    &world_dir2,
+                    N::default_epsilon().sqrt(),
+                );
+
+                world1 += world_dir1.into_inner() * s;
+                world2 += world_dir2.into_inner() * t;
 
                 if let Some((n, d)) = Unit::try_new_and_get(world2 - world1, na::zero()) {
                     if s1.tangent_cone_contains_dir(self.approx1.feature, m1, deformations1, &n)
@@ -357,9 +374,29 @@ impl<N: RealField> ContactKinematic<N> {
             }
         }
 
-        world1 += normal.into_inner() * self.margin1;
-        world2 += normal.into_inner() * (-self.margin2);
-        depth += self.margin1 + self.margin2;
+        let erosion1 = self.margin1.min(N::zero());
+        let erosion2 = self.margin2.min(N::zero());
+        let dilation1 = self.margin1 - erosion1;
+        let dilation2 = self.margin2 - erosion2;
+
+        world1 += normal.into_inner() * dilation1;
+        world2 += normal.into_inner() * (-dilation2);
+        depth += dilation1 + dilation2;
+
+        // Scale the erosions down so they don't make the depth negative.
+        let erosion = erosion1 + erosion2;
+
+        if erosion < N::zero() {
+            let scale = if depth <= N::zero() {
+                N::zero()
+            } else {
+                (depth / -erosion).min(N::one())
+            };
+
+            world1 += normal.into_inner() * (erosion1 * scale);
+            world2 += normal.into_inner() * (-erosion2 * scale);
+            depth += erosion * scale;
+        }
 
         Some(Contact::new(world1, world2, normal, depth))
     }
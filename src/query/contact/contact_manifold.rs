@@ -1,10 +1,15 @@
-use crate::math::Point;
+use crate::math::{Point, Vector};
 use crate::query::ContactPreprocessor;
-use crate::query::{Contact, ContactKinematic, TrackedContact};
+use crate::query::{
+    Contact, ContactId, ContactKinematic, FlatContact, IdAllocator, TrackedContact,
+};
 use crate::shape::FeatureId;
-use na::{self, RealField};
+#[cfg(feature = "dim3")]
+use na::Point2;
+use na::{self, RealField, Unit};
 use slab::Slab;
-use std::collections::{hash_map::Entry, HashMap};
+use slotmap::Key;
+use std::collections::{hash_map::Entry, HashMap, HashSet};
 
 /// The technique used for contact tracking.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -17,12 +22,44 @@ pub enum ContactTrackingMode<N: RealField> {
     DistanceBased(N),
 }
 
+/// How the normals of the contacts of a manifold are reported.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ContactNormalMode {
+    /// All the contacts share the normal of the deepest contact of the manifold.
+    Shared,
+    /// Each contact is reported with its own normal.
+    ///
+    /// This is more accurate for curved shapes, e.g., two non-parallel capsules, where the
+    /// normal varies from one contact to another.
+    PerContact,
+}
+
 #[derive(Clone, Debug)]
 enum ContactCache<N: RealField> {
     FeatureBased(HashMap<(FeatureId, FeatureId), usize>),
     DistanceBased(Vec<(Point<N>, usize)>, N),
 }
 
+/// The changes of the contacts of a manifold between two frames.
+///
+/// See `ContactManifold::diff`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ManifoldDiff {
+    /// Identifiers of the contacts that are new in the current frame.
+    pub added: Vec<ContactId>,
+    /// Identifiers of the contacts present in both frames.
+    pub persisted: Vec<ContactId>,
+    /// Identifiers of the contacts that disappeared in the current frame.
+    pub removed: Vec<ContactId>,
+}
+
+impl ManifoldDiff {
+    /// Returns `true` if no contact appeared nor disappeared.
+    pub fn is_unchanged(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
 /// A contact manifold.
 ///
 /// A contact manifold is a set of contacts between two shapes.
@@ -34,6 +71,9 @@ pub struct ContactManifold<N: RealField> {
     ncontacts: usize,
     persistence: usize,
     deepest: usize,
+    max_contacts: Option<usize>,
+    normal_mode: ContactNormalMode,
+    persistence_distance: N,
     contacts: Slab<(TrackedContact<N>, usize)>,
     cache: ContactCache<N>,
 }
@@ -47,6 +87,9 @@ impl<N: RealField> ContactManifold<N> {
             ncontacts: 0,
             deepest: 0,
             persistence: 1,
+            max_contacts: None,
+            normal_mode: ContactNormalMode::Shared,
+            persistence_distance: N::zero(),
             contacts: Slab::new(),
             cache: ContactCache::DistanceBased(Vec::new(), na::convert(0.02)),
         }
@@ -77,6 +120,29 @@ impl<N: RealField> ContactManifold<N> {
         })
     }
 
+    /// All the contacts tracked by this manifold together with the normal to be used for each of them.
+    ///
+    /// With `ContactNormalMode::Shared`, every contact is reported with the normal of the deepest
+    /// contact. With `ContactNormalMode::PerContact`, each contact is reported with its own normal.
+    pub fn contacts_with_normals(
+        &self,
+    ) -> impl Iterator<Item = (&TrackedContact<N>, Unit<Vector<N>>)> {
+        let shared = match self.normal_mode {
+            ContactNormalMode::Shared => self.deepest_contact().map(|c| c.contact.normal),
+            ContactNormalMode::PerContact => None,
+        };
+
+        self.contacts()
+            .map(move |c| (c, shared.unwrap_or(c.contact.normal)))
+    }
+
+    /// Appends to `out` the contacts of this manifold, flattened.
+    ///
+    /// The contacts are appended in the same order as they are yielded by `self.contacts()`.
+    pub fn export_flat(&self, out: &mut Vec<FlatContact<N>>) {
+        out.extend(self.contacts().map(FlatContact::from_tracked))
+    }
+
     /// The contact of this manifold with the deepest penetration depth.
     pub fn deepest_contact(&self) -> Option<&TrackedContact<N>> {
         if self.len() != 0 {
@@ -86,6 +152,159 @@ impl<N: RealField> ContactManifold<N> {
         }
     }
 
+    /// The two contacts of this manifold with the deepest penetration depths, deepest first.
+    ///
+    /// Contacts with equal depths are ordered by increasing identifier so the result does not
+    /// depend on the order the contacts were added to this manifold.
+    pub fn deepest_two(&self) -> (Option<&TrackedContact<N>>, Option<&TrackedContact<N>>) {
+        let is_deeper = |a: &TrackedContact<N>, b: &TrackedContact<N>| {
+            a.contact.depth > b.contact.depth || (a.contact.depth == b.contact.depth && a.id < b.id)
+        };
+
+        let mut first: Option<&TrackedContact<N>> = None;
+        let mut second: Option<&TrackedContact<N>> = None;
+
+        for c in self.contacts() {
+            if first.map(|f| is_deeper(c, f)).unwrap_or(true) {
+                second = first;
+                first = Some(c);
+            } else if second.map(|s| is_deeper(c, s)).unwrap_or(true) {
+                second = Some(c);
+            }
+        }
+
+        (first, second)
+    }
+
+    /// The area of the convex hull of the contact points, projected on the plane orthogonal to the
+    /// average contact normal.
+    ///
+    /// Each contact point is taken as the middle point of its two world-space witness points.
+    /// Returns zero if this manifold contains less than three contacts.
+    #[cfg(feature = "dim3")]
+    pub fn contact_patch_area(&self) -> N {
+        if self.len() < 3 {
+            return N::zero();
+        }
+
+        let normal = self
+            .contacts()
+            .fold(Vector::zeros(), |acc, c| acc + *c.contact.normal);
+        let normal = try_ret!(normal.try_normalize(N::default_epsilon()), N::zero());
+
+        let mut basis = [Vector::zeros(), Vector::zeros()];
+        let mut basis_i = 0;
+
+        Vector::orthonormal_subspace_basis(&[normal], |dir| {
+            basis[basis_i] = *dir;
+            basis_i += 1;
+            true
+        });
+
+        let mut pts: Vec<_> = self
+            .contacts()
+            .map(|c| {
+                let pt = na::center(&c.contact.world1, &c.contact.world2);
+                Point2::new(basis[0].dot(&pt.coords), basis[1].dot(&pt.coords))
+            })
+            .collect();
+
+        convex_polygon_area(&mut pts)
+    }
+
+    /// The maximum number of contacts kept by `self.limit_contacts()`, if any.
+    pub fn max_contacts(&self) -> Option<usize> {
+        self.max_contacts
+    }
+
+    /// Sets the maximum number of contacts kept by `self.limit_contacts()`.
+    ///
+    /// The `NarrowPhase` calls `limit_contacts` after each contact generation so this bounds the
+    /// number of contacts reported between two collision objects. Set to `None` (the default) to
+    /// disable the limit.
+    pub fn set_max_contacts(&mut self, max_contacts: Option<usize>) {
+        self.max_contacts = max_contacts
+    }
+
+    /// Drops contacts until this manifold contains at most `self.max_contacts()` contacts.
+    ///
+    /// The deepest contact is always kept. The other contacts are then selected one by one as the
+    /// contact farthest from all the contacts selected so far, so that the kept contacts span the
+    /// contact patch. The kept contacts retain their identifiers. This does nothing if no limit is
+    /// set or if this manifold does not exceed it.
+    pub fn limit_contacts(&mut self) {
+        let max_contacts = match self.max_contacts {
+            Some(max) if max < self.ncontacts => max,
+            _ => return,
+        };
+
+        let persistence = self.persistence;
+        let mut active: Vec<(usize, Contact<N>)> = self
+            .contacts
+            .iter()
+            .filter(|(_, c)| c.1 == persistence)
+            .map(|(i, c)| (i, c.0.contact))
+            .collect();
+
+        select_contacts(&mut active, max_contacts, |c| &c.1);
+
+        if let Some(deepest) = active.first() {
+            self.deepest = deepest.0;
+        }
+
+        // The contacts that were not selected are dropped.
+        for (i, _) in &active[max_contacts..] {
+            self.contacts[*i].1 = persistence - 1;
+        }
+
+        self.ncontacts = max_contacts;
+    }
+
+    /// The distance up to which the contacts already tracked by this manifold are kept.
+    pub fn persistence_distance(&self) -> N {
+        self.persistence_distance
+    }
+
+    /// Sets the distance up to which the contacts already tracked by this manifold are kept.
+    ///
+    /// When this is larger than the linear contact prediction, the `NarrowPhase` generates
+    /// contacts up to this distance but only keeps those beyond the prediction that match a
+    /// contact tracked during the previous update (see `self.discard_new_contacts_beyond`). This
+    /// lets a contact keep its identifier, e.g., for warm-starting, during brief separations.
+    /// Contacts farther than both distances are removed. Set to zero (the default) to disable.
+    pub fn set_persistence_distance(&mut self, distance: N) {
+        self.persistence_distance = distance
+    }
+
+    /// Drops the contacts farther than `distance` that do not have an identifier yet.
+    ///
+    /// Contacts without identifiers are those which did not match any contact tracked during the
+    /// previous update. The contacts farther than `distance` matching a tracked contact are kept.
+    pub fn discard_new_contacts_beyond(&mut self, distance: N) {
+        let persistence = self.persistence;
+        let mut deepest = None;
+        self.ncontacts = 0;
+
+        for (i, c) in self.contacts.iter_mut() {
+            if c.1 != persistence {
+                continue;
+            }
+
+            if c.0.id.is_null() && c.0.contact.depth < -distance {
+                c.1 = persistence - 1;
+            } else {
+                self.ncontacts += 1;
+
+                match deepest {
+                    Some((_, depth)) if depth >= c.0.contact.depth => {}
+                    _ => deepest = Some((i, c.0.contact.depth)),
+                }
+            }
+        }
+
+        self.deepest = deepest.map(|d| d.0).unwrap_or(0);
+    }
+
     /// Empty the manifold as well as its cache.
     pub fn clear(&mut self) {
         match &mut self.cache {
@@ -96,6 +315,19 @@ impl<N: RealField> ContactManifold<N> {
         self.ncontacts = 0;
     }
 
+    /// Gets how the normals of the contacts of this manifold are reported.
+    pub fn normal_mode(&self) -> ContactNormalMode {
+        self.normal_mode
+    }
+
+    /// Sets how the normals of the contacts of this manifold are reported.
+    ///
+    /// Contact generators may also use this mode to compute a distinct normal for each contact
+    /// instead of a single normal shared by the whole manifold.
+    pub fn set_normal_mode(&mut self, mode: ContactNormalMode) {
+        self.normal_mode = mode
+    }
+
     /// Gets the technique currently used for tracking contacts.
     pub fn tracking_mode(&self) -> ContactTrackingMode<N> {
         match self.cache {
@@ -274,4 +506,231 @@ impl<N: RealField> ContactManifold<N> {
             }
         }
     }
+
+    /// Sorts the contacts of this manifold by their features, breaking ties with their identifiers.
+    ///
+    /// Features are compared using their packed representation (see `FeatureId::pack`). After this
+    /// call, the order of the contacts yielded by `self.contacts()` does not depend on the order
+    /// they were added to this manifold, which is useful for reproducible simulations.
+    pub fn sort_contacts_by_feature(&mut self) {
+        let persistence = self.persistence;
+        let mut entries: Vec<(usize, (TrackedContact<N>, usize))> =
+            self.contacts.iter().map(|(i, c)| (i, *c)).collect();
+
+        // Inactive contacts are only kept for matching so they are moved at the end.
+        entries.sort_by_key(|(_, c)| {
+            (
+                c.1 != persistence,
+                c.0.kinematic.feature1().pack(),
+                c.0.kinematic.feature2().pack(),
+                c.0.id,
+            )
+        });
+
+        let mut remap = HashMap::with_capacity(entries.len());
+        self.contacts.clear();
+
+        for (old_i, c) in entries {
+            let _ = remap.insert(old_i, self.contacts.insert(c));
+        }
+
+        if let Some(deepest) = remap.get(&self.deepest) {
+            self.deepest = *deepest;
+        }
+
+        match &mut self.cache {
+            ContactCache::DistanceBased(cache, _) => {
+                for cached in cache.iter_mut() {
+                    cached.1 = remap[&cached.1];
+                }
+
+                cache.sort_by_key(|cached| cached.1);
+            }
+            ContactCache::FeatureBased(cache) => {
+                for i in cache.values_mut() {
+                    *i = remap[&*i];
+                }
+            }
+        }
+    }
+
+    /// Compares the contacts of this manifold with the contacts of `prev`, by identifier.
+    ///
+    /// `prev` is typically a copy of this manifold made at the previous frame. Since the
+    /// identifiers are kept by the contact tracking, the result can be used to emit contact
+    /// begin/end events. The identifiers in `added` and `persisted` are given in the order of
+    /// `self.contacts()`, and the identifiers in `removed` in the order of `prev.contacts()`.
+    pub fn diff(&self, prev: &ContactManifold<N>) -> ManifoldDiff {
+        let prev_ids: HashSet<ContactId> = prev.contacts().map(|c| c.id).collect();
+        let curr_ids: HashSet<ContactId> = self.contacts().map(|c| c.id).collect();
+        let mut res = ManifoldDiff::default();
+
+        for c in self.contacts() {
+            if prev_ids.contains(&c.id) {
+                res.persisted.push(c.id);
+            } else {
+                res.added.push(c.id);
+            }
+        }
+
+        res.removed = prev
+            .contacts()
+            .map(|c| c.id)
+            .filter(|id| !curr_ids.contains(id))
+            .collect();
+
+        res
+    }
+
+    /// Appends all the contacts tracked by `other` to this manifold.
+    ///
+    /// The contacts of `other` keep their identifier unless it is null or already used by a
+    /// contact of `self`, in which case a new identifier is allocated from `id_alloc`. The
+    /// appended contacts are registered into the contact cache of `self` according to its
+    /// current tracking mode so they can be matched by subsequent calls to `push`.
+    pub fn merge_from(&mut self, other: &ContactManifold<N>, id_alloc: &mut IdAllocator) {
+        let mut used_ids: HashSet<ContactId> = self
+            .contacts
+            .iter()
+            .map(|(_, c)| c.0.id)
+            .filter(|id| !id.is_null())
+            .collect();
+
+        for (other_i, c) in other.contacts.iter() {
+            if c.1 != other.persistence {
+                continue;
+            }
+
+            let mut tracked = c.0;
+
+            if tracked.id.is_null() || !used_ids.insert(tracked.id) {
+                tracked.id = id_alloc.insert(false);
+                let _ = used_ids.insert(tracked.id);
+            }
+
+            let is_deepest = self.ncontacts == 0
+                || tracked.contact.depth > self.contacts[self.deepest].0.contact.depth;
+            let features = (tracked.kinematic.feature1(), tracked.kinematic.feature2());
+            let tracking_pt = match &other.cache {
+                ContactCache::DistanceBased(cache, _) => cache
+                    .iter()
+                    .find(|cached| cached.1 == other_i)
+                    .map(|cached| cached.0),
+                ContactCache::FeatureBased(_) => None,
+            }
+            .unwrap_or_else(|| tracked.kinematic.local1());
+
+            let i = self.contacts.insert((tracked, self.persistence));
+            self.ncontacts += 1;
+
+            if is_deepest {
+                self.deepest = i;
+            }
+
+            match &mut self.cache {
+                ContactCache::DistanceBased(cache, _) => cache.push((tracking_pt, i)),
+                ContactCache::FeatureBased(cache) => {
+                    // Keep the existing entry, if any, so that the contact already tracked
+                    // by `self` for those features remains matched.
+                    let _ = cache.entry(features).or_insert(i);
+                }
+            }
+        }
+    }
+}
+
+/// Area of the convex hull of a set of 2D points, computed with the monotone chain algorithm.
+#[cfg(feature = "dim3")]
+fn convex_polygon_area<N: RealField>(pts: &mut [Point2<N>]) -> N {
+    fn cross<N: RealField>(o: &Point2<N>, a: &Point2<N>, b: &Point2<N>) -> N {
+        (a - o).perp(&(b - o))
+    }
+
+    pts.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.y.partial_cmp(&b.y).unwrap_or(std::cmp::Ordering::Equal))
+    });
+
+    let mut hull: Vec<Point2<N>> = Vec::with_capacity(pts.len() * 2);
+
+    // Lower hull.
+    for pt in pts.iter() {
+        while hull.len() >= 2
+            && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], pt) <= N::zero()
+        {
+            let _ = hull.pop();
+        }
+        hull.push(*pt);
+    }
+
+    // Upper hull.
+    let lower_len = hull.len() + 1;
+    for pt in pts.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && cross(&hull[hull.len() - 2], &hull[hull.len() - 1], pt) <= N::zero()
+        {
+            let _ = hull.pop();
+        }
+        hull.push(*pt);
+    }
+
+    // The last point is equal to the first one.
+    let _ = hull.pop();
+
+    let mut area = N::zero();
+
+    for i in 0..hull.len() {
+        let j = (i + 1) % hull.len();
+        area += hull[i].coords.perp(&hull[j].coords);
+    }
+
+    area.abs() * na::convert(0.5)
+}
+
+/// Moves to the front of `contacts` the `max_contacts` elements that best span the contact patch.
+///
+/// The deepest contact is selected first. The other contacts are then selected one by one as the
+/// contact farthest from all the contacts selected so far. This does nothing if `max_contacts`
+/// is zero or if `contacts` contains at most `max_contacts` elements.
+pub(crate) fn select_contacts<N: RealField, T>(
+    contacts: &mut [T],
+    max_contacts: usize,
+    contact: impl Fn(&T) -> &Contact<N>,
+) {
+    if max_contacts == 0 || max_contacts >= contacts.len() {
+        return;
+    }
+
+    let mut deepest = 0;
+
+    for (i, c) in contacts.iter().enumerate().skip(1) {
+        if contact(c).depth > contact(&contacts[deepest]).depth {
+            deepest = i;
+        }
+    }
+
+    contacts.swap(0, deepest);
+
+    // `sq_dists` contains the squared distance from each remaining contact to the closest
+    // selected one.
+    let center = |c: &T| na::center(&contact(c).world1, &contact(c).world2);
+    let mut sq_dists = vec![N::max_value(); contacts.len()];
+
+    for k in 1..max_contacts {
+        let last = center(&contacts[k - 1]);
+        let mut farthest = k;
+
+        for j in k..contacts.len() {
+            let sq_dist = na::distance_squared(&center(&contacts[j]), &last);
+            sq_dists[j] = sq_dists[j].min(sq_dist);
+
+            if sq_dists[j] > sq_dists[farthest] {
+                farthest = j;
+            }
+        }
+
+        contacts.swap(k, farthest);
+        sq_dists.swap(k, farthest);
+    }
 }
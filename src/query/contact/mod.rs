@@ -1,10 +1,16 @@
 //! Implementation details of the `contact` and `contacts` functions.
 
-pub use self::contact::{Contact, ContactId, ContactPrediction, TrackedContact};
+pub use self::contact::{
+    Contact, ContactId, ContactPrediction, FlatContact, IdAllocator, TrackedContact,
+};
+pub use self::contact_cache::ContactCache;
 pub use self::contact_kinematic::{
     ContactKinematic, LocalShapeApproximation, NeighborhoodGeometry,
 };
-pub use self::contact_manifold::{ContactManifold, ContactTrackingMode};
+pub(crate) use self::contact_manifold::select_contacts;
+pub use self::contact_manifold::{
+    ContactManifold, ContactNormalMode, ContactTrackingMode, ManifoldDiff,
+};
 pub use self::contact_preprocessor::ContactPreprocessor;
 
 pub use self::contact_ball_ball::contact_ball_ball;
@@ -12,16 +18,19 @@ pub use self::contact_ball_convex_polyhedron::{
     contact_ball_convex_polyhedron, contact_convex_polyhedron_ball,
 };
 pub use self::contact_composite_shape_shape::{
-    contact_composite_shape_shape, contact_shape_composite_shape,
+    contact_composite_shape_shape, contact_shape_composite_shape, contacts_shape_compound,
+    deepest_contact_composite_composite,
 };
 pub use self::contact_plane_support_map::{contact_plane_support_map, contact_support_map_plane};
 pub use self::contact_shape_shape::contact;
 pub use self::contact_support_map_support_map::contact_support_map_support_map;
 pub use self::contact_support_map_support_map::contact_support_map_support_map_with_params;
+pub use self::penetration_mtv::penetration_mtv;
 
 mod contact;
 mod contact_ball_ball;
 mod contact_ball_convex_polyhedron;
+mod contact_cache;
 mod contact_composite_shape_shape;
 mod contact_kinematic;
 mod contact_manifold;
@@ -29,3 +38,4 @@ mod contact_plane_support_map;
 mod contact_preprocessor;
 mod contact_shape_shape;
 mod contact_support_map_support_map;
+mod penetration_mtv;
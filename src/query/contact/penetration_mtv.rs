@@ -0,0 +1,71 @@
+use crate::math::{Isometry, Vector};
+use crate::query::algorithms::{gjk, gjk::GJKResult, CSOPoint, VoronoiSimplex, EPA};
+use crate::shape::SupportMap;
+use na::{RealField, Unit};
+
+/// Minimum translation vector separating two overlapping support-mapped shapes.
+///
+/// This is the smallest translation that should be applied to `g2` (or its opposite to `g1`)
+/// to make the shapes touching without penetration. Returns `None` if the shapes are disjoint
+/// or merely touching.
+pub fn penetration_mtv<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+) -> Option<Vector<N>>
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    let dir = Unit::try_new(
+        m2.translation.vector - m1.translation.vector,
+        N::default_epsilon(),
+    )
+    .unwrap_or_else(Vector::x_axis);
+    let simplex = &mut VoronoiSimplex::new();
+    simplex.reset(CSOPoint::from_shapes(m1, g1, m2, g2, &dir));
+
+    match gjk::closest_points(m1, g1, m2, g2, N::zero(), true, simplex) {
+        GJKResult::Intersection => {}
+        GJKResult::ClosestPoints(p1, p2, normal) => {
+            let depth = normal.dot(&(p1 - p2));
+            return if depth > N::zero() {
+                Some(*normal * depth)
+            } else {
+                None
+            };
+        }
+        _ => return None,
+    }
+
+    if let Some((p1, p2, normal)) = EPA::new().closest_points(m1, g1, m2, g2, simplex) {
+        let depth = normal.dot(&(p1 - p2));
+
+        if depth > N::zero() {
+            return Some(*normal * depth);
+        }
+    }
+
+    // The EPA fails when its polytope is flat, i.e., when the penetration is too shallow to be
+    // distinguished from the rounding errors. In this case, separate the shapes by a small
+    // translation along `dir` and deduce the penetration from their distance.
+    let shift = gjk::eps_tol::<N>().sqrt();
+    let shifted_m2 =
+        Isometry::from_parts((m2.translation.vector + *dir * shift).into(), m2.rotation);
+    simplex.reset(CSOPoint::from_shapes(m1, g1, &shifted_m2, g2, &dir));
+
+    match gjk::closest_points(m1, g1, &shifted_m2, g2, N::max_value(), true, simplex) {
+        GJKResult::ClosestPoints(p1, p2, normal) => {
+            let depth = normal.dot(&dir) * shift - normal.dot(&(p2 - p1));
+
+            if depth > N::zero() {
+                Some(*normal * depth)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
@@ -1,4 +1,4 @@
-use crate::math::{Isometry, Point};
+use crate::math::Isometry;
 use crate::shape::Plane;
 use crate::shape::SupportMap;
 use na::{self, RealField};
@@ -11,7 +11,7 @@ pub fn distance_plane_support_map<N: RealField, G: ?Sized + SupportMap<N>>(
     other: &G,
 ) -> N {
     let plane_normal = mplane * plane.normal;
-    let plane_center = Point::from(mplane.translation.vector);
+    let plane_center = mplane * plane.center();
     let deepest = other.support_point_toward(mother, &-plane_normal);
 
     let distance = plane_normal.dot(&(plane_center - deepest));
@@ -0,0 +1,97 @@
+use crate::interpolation::RigidMotion;
+use crate::math::Point;
+use crate::query::PointQuery;
+use na::{self, RealField};
+
+/// Minimum distance between the fixed point `point` and the shape `g` moving with `motion` during
+/// the time interval `[t0, t1]`.
+///
+/// Returns the time at which this minimum is reached and the corresponding distance. The distance
+/// is minimized with a golden-section search refined until the search interval is smaller than
+/// `tol`. The distance is generally not a convex function of time so this only finds a local
+/// minimum: the search is bracketed around the best of the positions sampled at `t0`, `t1`, and
+/// in-between.
+pub fn min_distance_point_to_swept_shape<N, G: ?Sized>(
+    point: &Point<N>,
+    g: &G,
+    motion: &dyn RigidMotion<N>,
+    t0: N,
+    t1: N,
+    tol: N,
+) -> (N, N)
+where
+    N: RealField,
+    G: PointQuery<N>,
+{
+    assert!(tol > N::zero(), "The time tolerance must be positive.");
+
+    let dist_at = |t: N| g.distance_to_point(&motion.position_at_time(t), point, true);
+    let inv_phi: N = na::convert(0.618_033_988_749_894_9);
+    let tmid = (t0 + t1) * na::convert(0.5);
+    let samples = [(t0, dist_at(t0)), (tmid, dist_at(tmid)), (t1, dist_at(t1))];
+
+    let mut best = 0;
+    for (i, sample) in samples.iter().enumerate().skip(1) {
+        if sample.1 < samples[best].1 {
+            best = i;
+        }
+    }
+
+    let (mut a, mut b) = match best {
+        0 => (t0, tmid),
+        1 => (t0, t1),
+        _ => (tmid, t1),
+    };
+
+    let mut c = b - (b - a) * inv_phi;
+    let mut d = a + (b - a) * inv_phi;
+    let mut dist_c = dist_at(c);
+    let mut dist_d = dist_at(d);
+
+    while b - a > tol {
+        if dist_c < dist_d {
+            b = d;
+            d = c;
+            dist_d = dist_c;
+            c = b - (b - a) * inv_phi;
+            dist_c = dist_at(c);
+        } else {
+            a = c;
+            c = d;
+            dist_c = dist_d;
+            d = a + (b - a) * inv_phi;
+            dist_d = dist_at(d);
+        }
+    }
+
+    [(c, dist_c), (d, dist_d)]
+        .iter()
+        .fold(samples[best], |best, candidate| {
+            if candidate.1 < best.1 {
+                *candidate
+            } else {
+                best
+            }
+        })
+}
+
+/// Tests if the fixed point `point` is inside of the shape `g` at some time of the interval
+/// `[t0, t1]` while it moves with `motion`.
+///
+/// This checks if the minimum distance computed by `min_distance_point_to_swept_shape` with the
+/// time tolerance `tol` is zero. It is therefore subject to the same limitations: only a local
+/// minimum of the distance is searched for, so very thin shapes moving fast may be missed.
+pub fn point_in_swept_shape<N, G: ?Sized>(
+    point: &Point<N>,
+    g: &G,
+    motion: &dyn RigidMotion<N>,
+    t0: N,
+    t1: N,
+    tol: N,
+) -> bool
+where
+    N: RealField,
+    G: PointQuery<N>,
+{
+    min_distance_point_to_swept_shape(point, g, motion, t0, t1, tol).1 <= N::zero()
+}
@@ -0,0 +1,23 @@
+use crate::math::Isometry;
+use crate::query;
+use crate::shape::{Segment, Shape};
+use na::RealField;
+
+/// Minimum distance between the world-space segment `segment` and the shape `g` transformed by `m`.
+///
+/// The segment is handled as a support-mapped shape so its endpoints are taken into account:
+/// the closest point of the segment may be one of its endpoints instead of the projection on its
+/// supporting line. Returns `0.0` if the segment touches or penetrates `g`.
+pub fn distance_segment_shape<N: RealField>(
+    segment: &Segment<N>,
+    m: &Isometry<N>,
+    g: &dyn Shape<N>,
+) -> N {
+    let identity = Isometry::identity();
+
+    if let Some(s) = g.as_support_map() {
+        query::distance_support_map_support_map(&identity, segment, m, s)
+    } else {
+        query::distance(&identity, segment, m, g)
+    }
+}
@@ -19,6 +19,19 @@ where
     distance_support_map_support_map_with_params(m1, g1, m2, g2, &mut VoronoiSimplex::new(), None)
 }
 
+/// Distance between two support-mapped shapes given as trait objects.
+///
+/// This runs GJK directly on the two support maps, without going through the shape dispatch of
+/// `query::distance`, e.g., for user-defined support maps that do not implement `Shape`.
+pub fn support_map_distance<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn SupportMap<N>,
+    m2: &Isometry<N>,
+    g2: &dyn SupportMap<N>,
+) -> N {
+    distance_support_map_support_map_with_params(m1, g1, m2, g2, &mut VoronoiSimplex::new(), None)
+}
+
 /// Distance between support-mapped shapes.
 ///
 /// This allows a more fine grained control other the underlying GJK algorigtm.
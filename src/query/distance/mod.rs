@@ -8,12 +8,19 @@ pub use self::distance_composite_shape_shape::{
 pub use self::distance_plane_support_map::{
     distance_plane_support_map, distance_support_map_plane,
 };
+pub use self::distance_point_swept_shape::{
+    min_distance_point_to_swept_shape, point_in_swept_shape,
+};
+pub use self::distance_segment_shape::distance_segment_shape;
 pub use self::distance_support_map_support_map::{
     distance_support_map_support_map, distance_support_map_support_map_with_params,
+    support_map_distance,
 };
 
 mod distance;
 mod distance_ball_ball;
 mod distance_composite_shape_shape;
 mod distance_plane_support_map;
+mod distance_point_swept_shape;
+mod distance_segment_shape;
 mod distance_support_map_support_map;
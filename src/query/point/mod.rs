@@ -2,6 +2,7 @@
 
 #[doc(inline)]
 pub use self::point_query::{PointProjection, PointQuery, PointQueryWithLocation};
+pub use self::point_sdf_grid::sample_sdf_grid;
 pub use self::point_support_map::point_projection_on_support_map;
 
 mod point_aabb;
@@ -15,6 +16,8 @@ mod point_plane;
 mod point_polyline;
 #[doc(hidden)]
 pub mod point_query;
+mod point_rounded;
+mod point_sdf_grid;
 mod point_segment;
 mod point_shape;
 mod point_support_map;
@@ -14,10 +14,11 @@ impl<N: RealField> PointQuery<N> for Ball<N> {
         let inside = distance_squared <= self.radius * self.radius;
 
         if inside && solid {
-            PointProjection::new(true, *pt)
+            PointProjection::new_with_distance(true, *pt, na::zero())
         } else {
-            let ls_proj = Point::from(ls_pt.coords * (self.radius / distance_squared.sqrt()));
-            PointProjection::new(inside, m * ls_proj)
+            let distance = distance_squared.sqrt();
+            let ls_proj = Point::from(ls_pt.coords * (self.radius / distance));
+            PointProjection::new_with_distance(inside, m * ls_proj, (distance - self.radius).abs())
         }
     }
 
@@ -1,4 +1,5 @@
-use crate::math::{Isometry, Point};
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, Vector};
 use crate::query::{PointProjection, PointQuery, PointQueryWithLocation};
 use crate::shape::{FeatureId, HeightField, TrianglePointLocation};
 use na::{self, RealField};
@@ -6,24 +7,54 @@ use na::{self, RealField};
 impl<N: RealField> PointQuery<N> for HeightField<N> {
     #[inline]
     fn project_point(&self, m: &Isometry<N>, point: &Point<N>, _: bool) -> PointProjection<N> {
-        let mut smallest_dist = N::max_value();
-        let mut best_proj = PointProjection::new(false, *point);
+        let ls_pt = m.inverse_transform_point(point);
+        let id = Isometry::identity();
+        // The smallest squared distance found so far, and the corresponding projection.
+        let mut best = (N::max_value(), ls_pt);
 
+        let update = |best: &mut (N, Point<N>), proj: Point<N>| {
+            let dist = na::distance_squared(&ls_pt, &proj);
+
+            if dist < best.0 {
+                *best = (dist, proj);
+            }
+        };
+
+        // First project on the elements of the cell below the point (or the closest cell if the
+        // point is outside of the heightfield bounds). This gives an upper bound of the distance.
         #[cfg(feature = "dim2")]
-        let iter = self.segments();
+        {
+            if let Some(seg) = self.segment_at(self.closest_cell_at_point(&ls_pt)) {
+                update(&mut best, seg.project_point(&id, &ls_pt, false).point);
+            }
+        }
         #[cfg(feature = "dim3")]
-        let iter = self.triangles();
-        for elt in iter {
-            let proj = elt.project_point(m, point, false);
-            let dist = na::distance_squared(point, &proj.point);
-
-            if dist < smallest_dist {
-                smallest_dist = dist;
-                best_proj = proj;
+        {
+            let (i, j) = self.closest_cell_at_point(&ls_pt);
+            let (tri1, tri2) = self.triangles_at(i, j);
+
+            for tri in tri1.iter().chain(tri2.iter()) {
+                update(&mut best, tri.project_point(&id, &ls_pt, false).point);
             }
         }
 
-        best_proj
+        // Then check only the elements that may be closer than this bound.
+        let bound = best.0.sqrt();
+        let aabb = if bound < N::max_value() {
+            let extents = Vector::repeat(bound);
+            AABB::new(ls_pt - extents, ls_pt + extents)
+        } else {
+            // All the elements of the cell have been removed.
+            let mut aabb = *self.aabb();
+            aabb.take_point(ls_pt);
+            aabb
+        };
+
+        self.map_elements_in_local_aabb(&aabb, &mut |_, elt, _| {
+            update(&mut best, elt.project_point(&id, &ls_pt, false).point)
+        });
+
+        PointProjection::new(false, m * best.1)
     }
 
     #[inline]
@@ -10,12 +10,36 @@ pub struct PointProjection<N: RealField> {
     pub is_inside: bool,
     /// The projection result.
     pub point: Point<N>,
+    distance: Option<N>,
 }
 
 impl<N: RealField> PointProjection<N> {
     /// Initializes a new `PointProjection`.
     pub fn new(is_inside: bool, point: Point<N>) -> PointProjection<N> {
-        PointProjection { is_inside, point }
+        PointProjection {
+            is_inside,
+            point,
+            distance: None,
+        }
+    }
+
+    /// Initializes a new `PointProjection` with the already known distance between the projected
+    /// point and its projection.
+    pub fn new_with_distance(is_inside: bool, point: Point<N>, distance: N) -> PointProjection<N> {
+        PointProjection {
+            is_inside,
+            point,
+            distance: Some(distance),
+        }
+    }
+
+    /// The (unsigned) distance between the projected point and its projection, if it was
+    /// computed by the projection itself.
+    ///
+    /// This is `None` if the shape did not compute it cheaply as part of the projection.
+    #[inline]
+    pub fn distance(&self) -> Option<N> {
+        self.distance
     }
 }
 
@@ -28,7 +52,9 @@ pub trait PointQuery<N: RealField> {
     #[inline]
     fn distance_to_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> N {
         let proj = self.project_point(m, pt, solid);
-        let dist = na::distance(pt, &proj.point);
+        let dist = proj
+            .distance()
+            .unwrap_or_else(|| na::distance(pt, &proj.point));
 
         if solid || !proj.is_inside {
             dist
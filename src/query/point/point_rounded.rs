@@ -0,0 +1,60 @@
+use crate::math::{Isometry, Point};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Rounded};
+use na::{RealField, Unit};
+
+impl<S: PointQuery<N>, N: RealField> PointQuery<N> for Rounded<S, N> {
+    #[inline]
+    fn project_point(&self, m: &Isometry<N>, pt: &Point<N>, solid: bool) -> PointProjection<N> {
+        let proj = self.shape.project_point(m, pt, false);
+        self.dilate_projection(pt, proj, solid)
+    }
+
+    #[inline]
+    fn project_point_with_feature(
+        &self,
+        m: &Isometry<N>,
+        pt: &Point<N>,
+    ) -> (PointProjection<N>, FeatureId) {
+        let (proj, feature) = self.shape.project_point_with_feature(m, pt);
+        (self.dilate_projection(pt, proj, false), feature)
+    }
+}
+
+impl<S, N: RealField> Rounded<S, N> {
+    /// Pushes the projection of `pt` on the boundary of the inner shape outward by `self.radius`.
+    fn dilate_projection(
+        &self,
+        pt: &Point<N>,
+        proj: PointProjection<N>,
+        solid: bool,
+    ) -> PointProjection<N> {
+        if solid && proj.is_inside {
+            return PointProjection::new(true, *pt);
+        }
+
+        // The outward direction goes from the point to its projection if it lies inside of the
+        // inner shape, and from the projection to the point otherwise.
+        let dproj = if proj.is_inside {
+            proj.point - *pt
+        } else {
+            *pt - proj.point
+        };
+
+        if let Some((dir, dist)) = Unit::try_new_and_get(dproj, N::default_epsilon()) {
+            let inside = proj.is_inside || dist <= self.radius;
+
+            if solid && inside {
+                PointProjection::new(true, *pt)
+            } else {
+                PointProjection::new(inside, proj.point + dir.into_inner() * self.radius)
+            }
+        } else if solid {
+            PointProjection::new(true, *pt)
+        } else {
+            // The point lies on the boundary of the inner shape, where the outward direction
+            // cannot be deduced from the projection alone.
+            PointProjection::new(true, proj.point)
+        }
+    }
+}
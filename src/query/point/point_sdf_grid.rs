@@ -0,0 +1,48 @@
+use crate::bounding_volume::AABB;
+use crate::math::{Isometry, Point, DIM};
+use crate::query::PointQuery;
+use na::{self, RealField};
+
+/// Samples the signed distance from `shape` transformed by `m` at the nodes of a regular grid
+/// covering `aabb`.
+///
+/// The grid has `resolution` nodes along each axis, including the nodes on the boundary of
+/// `aabb`, so `resolution` must be at least 2. The samples are returned with the `x` index
+/// varying fastest, then `y` (then `z` in 3D). Samples inside of the shape are negative.
+pub fn sample_sdf_grid<N, G>(
+    shape: &G,
+    m: &Isometry<N>,
+    aabb: &AABB<N>,
+    resolution: usize,
+) -> Vec<N>
+where
+    N: RealField,
+    G: ?Sized + PointQuery<N>,
+{
+    assert!(
+        resolution >= 2,
+        "The SDF grid resolution must be at least 2."
+    );
+
+    let nsamples = resolution.pow(DIM as u32);
+    let step = aabb.extents() / na::convert::<f64, N>((resolution - 1) as f64);
+    let identity = Isometry::identity();
+    let mut result = Vec::with_capacity(nsamples);
+
+    for id in 0..nsamples {
+        let mut pt = aabb.mins;
+        let mut rem = id;
+
+        for i in 0..DIM {
+            pt[i] += step[i] * na::convert::<f64, N>((rem % resolution) as f64);
+            rem /= resolution;
+        }
+
+        // Work in the local-space of the shape to avoid transforming the point at each
+        // projection.
+        let local_pt: Point<N> = m.inverse_transform_point(&pt);
+        result.push(shape.distance_to_point(&identity, &local_pt, false));
+    }
+
+    result
+}
@@ -0,0 +1,112 @@
+use crate::math::{Isometry, Point, Vector, DIM};
+use crate::query::algorithms::gjk;
+use crate::query::Unsupported;
+#[cfg(feature = "dim3")]
+use crate::shape::ConvexHull;
+#[cfg(feature = "dim2")]
+use crate::shape::ConvexPolygon;
+use crate::shape::{Ball, Capsule, ConvexPolyhedron, Cuboid, FeatureId, Shape};
+use na::{self, RealField, Unit};
+
+/// Tests whether the convex shape `g2` is entirely contained by the convex shape `g1`.
+///
+/// If `g1` is polyhedral (a `Cuboid`, a `ConvexHull` in 3D, or a `ConvexPolygon` in 2D), this
+/// checks that the support point of `g2` toward each face normal of `g1` does not lie past this
+/// face, so `g2` may be any support-mapped shape. If `g1` is a `Ball`, this checks the distance
+/// between its center and the farthest point of `g2`, so `g2` must be a ball, a capsule, or a
+/// polyhedral shape. Other combinations of shapes are not supported.
+pub fn convex_contains_convex<N: RealField>(
+    m1: &Isometry<N>,
+    g1: &dyn Shape<N>,
+    m2: &Isometry<N>,
+    g2: &dyn Shape<N>,
+) -> Result<bool, Unsupported> {
+    let eps = gjk::eps_tol::<N>();
+
+    if let Some(ball1) = g1.as_shape::<Ball<N>>() {
+        let center1 = Point::from(m1.translation.vector);
+        let (pts2, radius2) = dilated_vertices(g2).ok_or(Unsupported)?;
+        let max_dist = ball1.radius - radius2;
+
+        Ok(pts2
+            .iter()
+            .all(|pt| na::distance(&center1, &(m2 * pt)) <= max_dist + eps))
+    } else if let Some(normals1) = face_normals(g1) {
+        let sm1 = g1.as_support_map().ok_or(Unsupported)?;
+        let sm2 = g2.as_support_map().ok_or(Unsupported)?;
+
+        Ok(normals1.iter().all(|local_n| {
+            let n = m1 * local_n;
+            let limit = sm1.support_point_toward(m1, &n).coords.dot(&n);
+            sm2.support_point_toward(m2, &n).coords.dot(&n) <= limit + eps
+        }))
+    } else {
+        Err(Unsupported)
+    }
+}
+
+/// The outward face normals of a polyhedral shape, in its local-space.
+fn face_normals<N: RealField>(g: &dyn Shape<N>) -> Option<Vec<Unit<Vector<N>>>> {
+    if g.is_shape::<Cuboid<N>>() {
+        let normals = (0..DIM)
+            .flat_map(|i| {
+                let mut n = Vector::zeros();
+                n[i] = N::one();
+                vec![Unit::new_unchecked(n), Unit::new_unchecked(-n)]
+            })
+            .collect();
+        return Some(normals);
+    }
+
+    #[cfg(feature = "dim3")]
+    {
+        if let Some(hull) = g.as_shape::<ConvexHull<N>>() {
+            return Some(hull.face_normals().collect());
+        }
+    }
+
+    #[cfg(feature = "dim2")]
+    {
+        if let Some(polygon) = g.as_shape::<ConvexPolygon<N>>() {
+            return Some(polygon.normals().to_vec());
+        }
+    }
+
+    None
+}
+
+/// The local-space points of a shape which, once dilated by the returned radius, have the same
+/// convex hull as the shape itself.
+fn dilated_vertices<N: RealField>(g: &dyn Shape<N>) -> Option<(Vec<Point<N>>, N)> {
+    if let Some(ball) = g.as_shape::<Ball<N>>() {
+        return Some((vec![Point::origin()], ball.radius));
+    }
+
+    if let Some(capsule) = g.as_shape::<Capsule<N>>() {
+        let segment = capsule.segment();
+        return Some((vec![segment.a, segment.b], capsule.radius));
+    }
+
+    if let Some(cuboid) = g.as_shape::<Cuboid<N>>() {
+        let vertices = (0..1 << DIM)
+            .map(|i| cuboid.vertex(FeatureId::Vertex(i)))
+            .collect();
+        return Some((vertices, N::zero()));
+    }
+
+    #[cfg(feature = "dim3")]
+    {
+        if let Some(hull) = g.as_shape::<ConvexHull<N>>() {
+            return Some((hull.points().to_vec(), N::zero()));
+        }
+    }
+
+    #[cfg(feature = "dim2")]
+    {
+        if let Some(polygon) = g.as_shape::<ConvexPolygon<N>>() {
+            return Some((polygon.points().to_vec(), N::zero()));
+        }
+    }
+
+    None
+}
@@ -1,5 +1,6 @@
 //! Implementation details of the `proximity` function.
 
+pub use self::convex_contains_convex::convex_contains_convex;
 pub use self::proximity::Proximity;
 pub use self::proximity_ball_ball::proximity_ball_ball;
 pub use self::proximity_composite_shape_shape::{
@@ -11,10 +12,19 @@ pub use self::proximity_plane_support_map::{
 pub use self::proximity_shape_shape::proximity;
 pub use self::proximity_support_map_support_map::proximity_support_map_support_map;
 pub use self::proximity_support_map_support_map::proximity_support_map_support_map_with_params;
+pub use self::separating_axis::separating_axis;
+#[cfg(feature = "dim3")]
+pub use self::triangle_triangle_intersection::{
+    triangle_triangle_intersection, TriTriIntersection,
+};
 
+mod convex_contains_convex;
 mod proximity;
 mod proximity_ball_ball;
 mod proximity_composite_shape_shape;
 mod proximity_plane_support_map;
 mod proximity_shape_shape;
 mod proximity_support_map_support_map;
+mod separating_axis;
+#[cfg(feature = "dim3")]
+mod triangle_triangle_intersection;
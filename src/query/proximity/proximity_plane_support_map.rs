@@ -1,6 +1,6 @@
 use na::{self, RealField};
 
-use crate::math::{Isometry, Point};
+use crate::math::Isometry;
 use crate::query::Proximity;
 use crate::shape::Plane;
 use crate::shape::SupportMap;
@@ -19,7 +19,7 @@ pub fn proximity_plane_support_map<N: RealField, G: ?Sized + SupportMap<N>>(
     );
 
     let plane_normal = mplane * plane.normal;
-    let plane_center = Point::from(mplane.translation.vector);
+    let plane_center = mplane * plane.center();
     let deepest = other.support_point_toward(mother, &-plane_normal);
 
     let distance = plane_normal.dot(&(plane_center - deepest));
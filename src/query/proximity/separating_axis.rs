@@ -0,0 +1,37 @@
+use na::{RealField, Unit};
+
+use crate::math::{Isometry, Vector};
+use crate::query;
+use crate::query::algorithms::{gjk::GJKResult, VoronoiSimplex};
+use crate::shape::SupportMap;
+
+/// Computes a separating axis between two disjoint support-mapped shapes.
+///
+/// The returned axis is the unit direction from the closest point of `g1` to the closest point
+/// of `g2`, as found by the GJK algorithm. Returns `None` if the shapes are intersecting.
+pub fn separating_axis<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    g2: &G2,
+) -> Option<Unit<Vector<N>>>
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    match query::closest_points_support_map_support_map_with_params(
+        m1,
+        g1,
+        m2,
+        g2,
+        N::max_value(),
+        &mut VoronoiSimplex::new(),
+        None,
+    ) {
+        GJKResult::ClosestPoints(pt1, pt2, dir) => {
+            Unit::try_new(pt2 - pt1, N::default_epsilon()).or(Some(dir))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,191 @@
+use crate::math::{Point, Vector};
+use crate::shape::{Segment, Triangle};
+use na::{self, RealField};
+
+/// The intersection between two triangles.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TriTriIntersection<N: RealField> {
+    /// The triangles are not coplanar and intersect along a segment.
+    ///
+    /// Both endpoints of this segment are equal if the triangles only touch at a single point.
+    Segment(Segment<N>),
+    /// The triangles are coplanar and overlap on this convex polygon.
+    ///
+    /// The polygon has less than three vertices if the triangles only touch at a vertex or
+    /// along an edge.
+    Polygon(Vec<Point<N>>),
+}
+
+/// Computes the intersection between two triangles.
+///
+/// Returns `None` if the triangles are disjoint or if one of them is degenerate.
+pub fn triangle_triangle_intersection<N: RealField>(
+    t1: &Triangle<N>,
+    t2: &Triangle<N>,
+) -> Option<TriTriIntersection<N>> {
+    let n1 = t1.normal()?;
+    let n2 = t2.normal()?;
+    let pts1 = t1.vertices();
+    let pts2 = t2.vertices();
+
+    let scale = pts1
+        .iter()
+        .chain(pts2.iter())
+        .fold(N::zero(), |max, pt| max.max(pt.coords.amax()));
+    let eps = N::default_epsilon().sqrt() * (N::one() + scale);
+
+    // Signed distances of the vertices of each triangle to the plane of the other one.
+    let dists1 = plane_distances(pts1, &pts2[0], &n2, eps);
+    let dists2 = plane_distances(pts2, &pts1[0], &n1, eps);
+
+    if same_strict_sign(&dists1) || same_strict_sign(&dists2) {
+        return None;
+    }
+
+    if dists1.iter().all(|d| d.is_zero()) {
+        return coplanar_intersection(pts1, pts2, &n2, eps).map(TriTriIntersection::Polygon);
+    }
+
+    // Both triangles cross the line where their planes intersect.
+    let dir = n1.cross(&n2);
+    let ((start1, pt_start1), (end1, pt_end1)) = plane_section(pts1, &dists1, &dir);
+    let ((start2, pt_start2), (end2, pt_end2)) = plane_section(pts2, &dists2, &dir);
+
+    let (start, pt_start) = if start1 >= start2 {
+        (start1, pt_start1)
+    } else {
+        (start2, pt_start2)
+    };
+    let (end, pt_end) = if end1 <= end2 {
+        (end1, pt_end1)
+    } else {
+        (end2, pt_end2)
+    };
+
+    if start > end + eps {
+        None
+    } else {
+        Some(TriTriIntersection::Segment(Segment::new(pt_start, pt_end)))
+    }
+}
+
+/// The signed distances of `pts` to the plane with normal `n` passing through `origin`.
+///
+/// Distances smaller than `eps` are snapped to zero.
+fn plane_distances<N: RealField>(
+    pts: &[Point<N>; 3],
+    origin: &Point<N>,
+    n: &Vector<N>,
+    eps: N,
+) -> [N; 3] {
+    let mut dists = [N::zero(); 3];
+
+    for (dist, pt) in dists.iter_mut().zip(pts.iter()) {
+        let d = n.dot(&(pt - origin));
+
+        if d.abs() > eps {
+            *dist = d;
+        }
+    }
+
+    dists
+}
+
+fn same_strict_sign<N: RealField>(dists: &[N; 3]) -> bool {
+    dists.iter().all(|d| *d > N::zero()) || dists.iter().all(|d| *d < N::zero())
+}
+
+/// The endpoints of the intersection between a triangle and a plane, sorted by their
+/// projection on `dir` and given with this projection.
+///
+/// The triangle must touch the plane without lying on it.
+fn plane_section<N: RealField>(
+    pts: &[Point<N>; 3],
+    dists: &[N; 3],
+    dir: &Vector<N>,
+) -> ((N, Point<N>), (N, Point<N>)) {
+    let mut section = Vec::with_capacity(3);
+
+    for i in 0..3 {
+        let j = (i + 1) % 3;
+
+        if dists[i].is_zero() {
+            section.push(pts[i]);
+        } else if dists[i] * dists[j] < N::zero() {
+            let t = dists[i] / (dists[i] - dists[j]);
+            section.push(pts[i] + (pts[j] - pts[i]) * t);
+        }
+    }
+
+    let mut start = (N::max_value(), Point::origin());
+    let mut end = (-N::max_value(), Point::origin());
+
+    for pt in section {
+        let s = dir.dot(&pt.coords);
+
+        if s < start.0 {
+            start = (s, pt);
+        }
+
+        if s > end.0 {
+            end = (s, pt);
+        }
+    }
+
+    (start, end)
+}
+
+/// Clips the triangle `pts1` by the triangle `pts2` lying on the same plane with normal `n2`.
+fn coplanar_intersection<N: RealField>(
+    pts1: &[Point<N>; 3],
+    pts2: &[Point<N>; 3],
+    n2: &Vector<N>,
+    eps: N,
+) -> Option<Vec<Point<N>>> {
+    let mut poly = pts1.to_vec();
+    let mut clipped = Vec::with_capacity(6);
+
+    for i in 0..3 {
+        let a = pts2[i];
+        let b = pts2[(i + 1) % 3];
+        // Points toward the interior of `pts2`.
+        let inward = n2.cross(&(b - a)).normalize();
+
+        clipped.clear();
+
+        for (j, pt) in poly.iter().enumerate() {
+            let next = poly[(j + 1) % poly.len()];
+            let d = inward.dot(&(pt - a));
+            let d_next = inward.dot(&(next - a));
+
+            if d >= -eps {
+                clipped.push(*pt);
+            }
+
+            if (d < -eps && d_next > eps) || (d > eps && d_next < -eps) {
+                let t = d / (d - d_next);
+                clipped.push(pt + (next - pt) * t);
+            }
+        }
+
+        std::mem::swap(&mut poly, &mut clipped);
+
+        if poly.is_empty() {
+            return None;
+        }
+    }
+
+    // Remove duplicate vertices resulting from contacts along edges or vertices.
+    let mut result: Vec<Point<N>> = Vec::with_capacity(poly.len());
+
+    for pt in poly {
+        if result
+            .iter()
+            .all(|other| na::distance_squared(other, &pt) > eps * eps)
+        {
+            result.push(pt);
+        }
+    }
+
+    Some(result)
+}
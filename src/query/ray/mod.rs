@@ -6,13 +6,16 @@ pub use self::ray_ball::ray_toi_with_ball;
 pub use self::ray_plane::{line_toi_with_plane, ray_toi_with_plane};
 pub use self::ray_support_map::ray_intersection_with_support_map_with_params;
 #[cfg(feature = "dim3")]
-pub use self::ray_triangle::ray_intersection_with_triangle;
+pub use self::ray_triangle::{
+    ray_intersection_with_triangle, ray_intersection_with_triangle_with_params,
+};
 
 #[doc(hidden)]
 pub mod ray;
 mod ray_aabb;
 mod ray_ball;
 mod ray_bounding_sphere;
+mod ray_capsule;
 mod ray_compound;
 mod ray_cuboid;
 mod ray_heightfield;
@@ -25,6 +25,26 @@ impl<N: RealField> Ray<N> {
         }
     }
 
+    /// Creates a new ray starting from `origin` and with the direction `dir` normalized.
+    ///
+    /// The time of impact of a ray-cast is then the distance between the origin and the hit
+    /// point. `dir` must not be zero.
+    #[inline]
+    pub fn from_origin_dir(origin: Point<N>, dir: Vector<N>) -> Ray<N> {
+        Self::new(origin, dir.normalize())
+    }
+
+    /// Creates a new ray starting from `from` and with the direction `to - from`.
+    ///
+    /// The direction is not normalized so `ray.point_at(N::one())` is `to`: the times of impact
+    /// in `[0, 1]` map the segment `[from, to]`. A ray-cast with a `max_toi` of one thus only
+    /// finds the hits on this segment, and its `toi` is the fraction of the segment traveled
+    /// before the hit instead of a distance.
+    #[inline]
+    pub fn between_points(from: Point<N>, to: Point<N>) -> Ray<N> {
+        Self::new(from, to - from)
+    }
+
     /// Transforms this ray by the given isometry.
     #[inline]
     pub fn transform_by(&self, m: &Isometry<N>) -> Self {
@@ -119,6 +139,15 @@ impl<N: RealField> RayIntersection<N> {
             feature,
         }
     }
+
+    /// The intersection point of `ray` with the object, i.e., `ray.point_at(self.toi)`.
+    ///
+    /// `ray` must be the ray that was cast to obtain this intersection, expressed in the same
+    /// coordinate system as the returned point.
+    #[inline]
+    pub fn point(&self, ray: &Ray<N>) -> Point<N> {
+        ray.point_at(self.toi)
+    }
 }
 
 /// Traits of objects which can be transformed and tested for intersection with a ray.
@@ -157,4 +186,16 @@ pub trait RayCast<N: RealField> {
     fn intersects_ray(&self, m: &Isometry<N>, ray: &Ray<N>, max_toi: N) -> bool {
         self.toi_with_ray(m, ray, max_toi, true).is_some()
     }
+
+    /// Tests whether the origin of a ray lies inside of this transformed shape.
+    ///
+    /// A ray starting on the boundary of the shape is considered inside. This defaults to a
+    /// solid ray cast limited to a time of impact of zero, which some shapes override with a
+    /// direct point containment test. Shapes without interior, i.e., `TriMesh`, `Polyline`
+    /// and `HeightField`, use this default, so it returns `true` only if the ray origin lies on
+    /// their surface.
+    #[inline]
+    fn ray_origin_inside(&self, m: &Isometry<N>, ray: &Ray<N>) -> bool {
+        self.intersects_ray(m, ray, N::zero())
+    }
 }
@@ -6,7 +6,7 @@ use simba::scalar::RealField;
 #[cfg(feature = "dim3")]
 use crate::math::Vector;
 use crate::math::{Isometry, Point};
-use crate::query::{Ray, RayCast, RayIntersection};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
 use crate::shape::{Ball, FeatureId};
 
 #[cfg(feature = "dim3")]
@@ -68,6 +68,11 @@ impl<N: RealField> RayCast<N> for Ball<N> {
             )
         })
     }
+
+    #[inline]
+    fn ray_origin_inside(&self, m: &Isometry<N>, ray: &Ray<N>) -> bool {
+        self.contains_point(m, &ray.origin)
+    }
 }
 
 /// Computes the time of impact of a ray on a ball.
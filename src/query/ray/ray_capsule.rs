@@ -0,0 +1,174 @@
+use na::{self, RealField};
+
+use crate::math::{Isometry, Point, Vector};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
+use crate::shape::{Capsule, FeatureId};
+
+impl<N: RealField> RayCast<N> for Capsule<N> {
+    fn toi_and_normal_with_ray(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        ray_intersection_with_capsule(self, &ls_ray, max_toi, solid).map(|mut res| {
+            res.normal = m * res.normal;
+            res
+        })
+    }
+
+    #[inline]
+    fn ray_origin_inside(&self, m: &Isometry<N>, ray: &Ray<N>) -> bool {
+        self.contains_point(m, &ray.origin)
+    }
+}
+
+/// Casts a ray, expressed in the capsule local-space, on a capsule.
+///
+/// The capsule is the union of its two end balls and of the part of its infinite cylinder
+/// (a slab in 2D) lying between the two end-caps. Because the capsule is convex, the ray
+/// intersects it along a single interval which is the union of the intervals of each piece.
+fn ray_intersection_with_capsule<N: RealField>(
+    capsule: &Capsule<N>,
+    ray: &Ray<N>,
+    max_toi: N,
+    solid: bool,
+) -> Option<RayIntersection<N>> {
+    let mut tmin = N::max_value();
+    let mut tmax = -N::max_value();
+    let mut merge = |interval: Option<(N, N)>| {
+        if let Some((t1, t2)) = interval {
+            tmin = tmin.min(t1);
+            tmax = tmax.max(t2);
+        }
+    };
+
+    let mut top = Point::origin();
+    top[1] = capsule.half_height;
+    merge(ray_interval_with_ball(&top, capsule.radius, ray));
+    merge(ray_interval_with_ball(&-top, capsule.radius, ray));
+    merge(ray_interval_with_cylinder(
+        capsule.half_height,
+        capsule.radius,
+        ray,
+    ));
+
+    if tmin > tmax || tmax < N::zero() {
+        return None;
+    }
+
+    let (toi, inside) = if tmin >= N::zero() {
+        (tmin, false)
+    } else if solid {
+        return Some(RayIntersection::new(
+            N::zero(),
+            Vector::zeros(),
+            FeatureId::Unknown,
+        ));
+    } else {
+        (tmax, true)
+    };
+
+    if toi > max_toi {
+        return None;
+    }
+
+    // The normal points away from the closest point of the capsule segment.
+    let pt = ray.point_at(toi);
+    let mut proj = Point::origin();
+    proj[1] = na::clamp(pt[1], -capsule.half_height, capsule.half_height);
+    let normal = (pt - proj).normalize();
+
+    Some(RayIntersection::new(
+        toi,
+        if inside { -normal } else { normal },
+        FeatureId::Unknown,
+    ))
+}
+
+/// The interval of times for which the ray lies inside of the given ball.
+fn ray_interval_with_ball<N: RealField>(
+    center: &Point<N>,
+    radius: N,
+    ray: &Ray<N>,
+) -> Option<(N, N)> {
+    let dcenter = ray.origin - *center;
+
+    let a = ray.dir.norm_squared();
+    let b = dcenter.dot(&ray.dir);
+    let c = dcenter.norm_squared() - radius * radius;
+
+    if a.is_zero() {
+        return if c > N::zero() {
+            None
+        } else {
+            Some((-N::max_value(), N::max_value()))
+        };
+    }
+
+    let delta = b * b - a * c;
+
+    if delta < N::zero() {
+        None
+    } else {
+        let sqrt_delta = delta.sqrt();
+        Some(((-b - sqrt_delta) / a, (-b + sqrt_delta) / a))
+    }
+}
+
+/// The interval of times for which the ray lies inside of the cylinder (a slab in 2D) of
+/// axis `y`, clamped to the span `[-half_height, half_height]` of this axis.
+fn ray_interval_with_cylinder<N: RealField>(
+    half_height: N,
+    radius: N,
+    ray: &Ray<N>,
+) -> Option<(N, N)> {
+    let mut orig = ray.origin.coords;
+    let mut dir = ray.dir;
+    orig[1] = N::zero();
+    dir[1] = N::zero();
+
+    // Intersection with the infinite cylinder.
+    let a = dir.norm_squared();
+    let b = orig.dot(&dir);
+    let c = orig.norm_squared() - radius * radius;
+
+    let (mut t1, mut t2) = if a.is_zero() {
+        // The ray is parallel to the cylinder axis.
+        if c > N::zero() {
+            return None;
+        }
+
+        (-N::max_value(), N::max_value())
+    } else {
+        let delta = b * b - a * c;
+
+        if delta < N::zero() {
+            return None;
+        }
+
+        let sqrt_delta = delta.sqrt();
+        ((-b - sqrt_delta) / a, (-b + sqrt_delta) / a)
+    };
+
+    // Clamp to the span of the segment.
+    if ray.dir[1].is_zero() {
+        if ray.origin[1].abs() > half_height {
+            return None;
+        }
+    } else {
+        let s1 = (-half_height - ray.origin[1]) / ray.dir[1];
+        let s2 = (half_height - ray.origin[1]) / ray.dir[1];
+        t1 = t1.max(s1.min(s2));
+        t2 = t2.min(s1.max(s2));
+    }
+
+    if t1 > t2 {
+        None
+    } else {
+        Some((t1, t2))
+    }
+}
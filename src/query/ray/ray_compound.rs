@@ -77,10 +77,9 @@ impl<'a, N: RealField> BestFirstVisitor<N, usize, AABB<N>> for CompoundRayToiVis
             if let Some(b) = data {
                 if toi < best {
                     let elt = &self.compound.shapes()[*b];
-                    if let Some(toi) =
-                        elt.1
-                            .toi_with_ray(&elt.0, self.ray, self.max_toi, self.solid)
-                    {
+                    // Hits farther than the current best are useless.
+                    let max_toi = best.min(self.max_toi);
+                    if let Some(toi) = elt.1.toi_with_ray(&elt.0, self.ray, max_toi, self.solid) {
                         res = BestFirstVisitStatus::Continue {
                             cost: toi,
                             result: Some(toi),
@@ -124,9 +123,11 @@ impl<'a, N: RealField> BestFirstVisitor<N, usize, AABB<N>>
             if let Some(b) = data {
                 if toi < best {
                     let elt = &self.compound.shapes()[*b];
-                    if let Some(toi) =
-                        elt.1
-                            .toi_and_normal_with_ray(&elt.0, self.ray, self.max_toi, self.solid)
+                    // Hits farther than the current best are useless.
+                    let max_toi = best.min(self.max_toi);
+                    if let Some(toi) = elt
+                        .1
+                        .toi_and_normal_with_ray(&elt.0, self.ray, max_toi, self.solid)
                     {
                         res = BestFirstVisitStatus::Continue {
                             cost: toi.toi,
@@ -1,6 +1,6 @@
 use crate::bounding_volume::AABB;
 use crate::math::{Isometry, Point};
-use crate::query::{Ray, RayCast, RayIntersection};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
 use crate::shape::Cuboid;
 use na::RealField;
 
@@ -38,4 +38,9 @@ impl<N: RealField> RayCast<N> for Cuboid<N> {
         let ur = Point::from(self.half_extents);
         AABB::new(dl, ur).toi_and_normal_and_uv_with_ray(m, ray, max_toi, solid)
     }
+
+    #[inline]
+    fn ray_origin_inside(&self, m: &Isometry<N>, ray: &Ray<N>) -> bool {
+        self.contains_point(m, &ray.origin)
+    }
 }
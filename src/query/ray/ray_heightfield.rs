@@ -43,12 +43,12 @@ impl<N: RealField> RayCast<N> for HeightField<N> {
          */
         if let Some(seg) = self.segment_at(curr) {
             let (s, t) = query::closest_points_line_line_parameters(
-                &ray.origin,
-                &ray.dir,
+                &ls_ray.origin,
+                &ls_ray.dir,
                 &seg.a,
                 &seg.scaled_direction(),
             );
-            if s >= N::zero() && t >= N::zero() && t <= N::one() {
+            if s >= N::zero() && s <= max_toi && t >= N::zero() && t <= N::one() {
                 // Cast succeeded on the first element!
                 let n = seg.normal().unwrap().into_inner();
                 let fid = if n.dot(&ls_ray.dir) > N::zero() {
@@ -95,8 +95,8 @@ impl<N: RealField> RayCast<N> for HeightField<N> {
             if let Some(seg) = self.segment_at(curr) {
                 // TODO: test the y-coordinates (equivalent to an AABB test) before actually computing the intersection.
                 let (s, t) = query::closest_points_line_line_parameters(
-                    &ray.origin,
-                    &ray.dir,
+                    &ls_ray.origin,
+                    &ls_ray.dir,
                     &seg.a,
                     &seg.scaled_direction(),
                 );
@@ -132,107 +132,126 @@ impl<N: RealField> RayCast<N> for HeightField<N> {
         let aabb = self.aabb();
         let ls_ray = ray.inverse_transform_by(m);
         let (min_t, mut max_t) = aabb.clip_ray_parameters(&ls_ray)?;
+
+        if min_t > max_toi {
+            return None;
+        }
+
         max_t = max_t.min(max_toi);
+
+        /*
+         * Find the cell containing the point where the ray enters the heightfield AABB.
+         * We clamp the indices since the clipped point may be slightly outside of the grid
+         * due to numerical errors.
+         */
         let clip_ray_a = ls_ray.point_at(min_t);
-        let mut cell = match self.cell_at_point(&clip_ray_a) {
-            Some(cell) => cell,
-            // None may happen due to slight numerical errors.
-            None => {
-                let i = if ls_ray.origin.z > N::zero() {
-                    self.nrows() - 1
-                } else {
-                    0
-                };
+        let cell_width = self.cell_width();
+        let cell_height = self.cell_height();
+        let max_j: N = na::convert((self.ncols() - 1) as f64);
+        let max_i: N = na::convert((self.nrows() - 1) as f64);
+        let j = na::clamp(
+            ((clip_ray_a.x - self.x_at(0)) / cell_width).floor(),
+            N::zero(),
+            max_j,
+        );
+        let i = na::clamp(
+            ((clip_ray_a.z - self.z_at(0)) / cell_height).floor(),
+            N::zero(),
+            max_i,
+        );
+        let mut cell = (
+            na::convert_unchecked::<N, f64>(i) as usize,
+            na::convert_unchecked::<N, f64>(j) as usize,
+        );
 
-                let j = if ls_ray.origin.x > N::zero() {
-                    self.ncols() - 1
-                } else {
-                    0
-                };
+        /*
+         * Setup the 2D DDA on the footprint of the ray on the `xz` plane.
+         * `next_x` (resp. `next_z`) is the ray parameter where it crosses the next cell boundary
+         * along the `x` (resp. `z`) axis, and `delta_x` (resp. `delta_z`) the ray parameter
+         * increment between two such crossings.
+         */
+        let right = ls_ray.dir.x > N::zero();
+        let (mut next_x, delta_x) = if ls_ray.dir.x != N::zero() {
+            let x = if right {
+                self.x_at(cell.1 + 1)
+            } else {
+                self.x_at(cell.1)
+            };
+            (
+                (x - ls_ray.origin.x) / ls_ray.dir.x,
+                (cell_width / ls_ray.dir.x).abs(),
+            )
+        } else {
+            (N::max_value(), N::max_value())
+        };
 
-                (i, j)
-            }
+        let down = ls_ray.dir.z > N::zero();
+        let (mut next_z, delta_z) = if ls_ray.dir.z != N::zero() {
+            let z = if down {
+                self.z_at(cell.0 + 1)
+            } else {
+                self.z_at(cell.0)
+            };
+            (
+                (z - ls_ray.origin.z) / ls_ray.dir.z,
+                (cell_height / ls_ray.dir.z).abs(),
+            )
+        } else {
+            (N::max_value(), N::max_value())
         };
 
+        let mut enter_t = min_t;
+
         loop {
-            let tris = self.triangles_at(cell.0, cell.1);
-            let inter1 = tris
-                .0
-                .and_then(|tri| tri.toi_and_normal_with_ray(m, ray, max_toi, solid));
-            let inter2 = tris
-                .1
-                .and_then(|tri| tri.toi_and_normal_with_ray(m, ray, max_toi, solid));
-
-            match (inter1, inter2) {
-                (Some(mut inter1), Some(mut inter2)) => {
-                    if inter1.toi < inter2.toi {
-                        inter1.feature =
-                            self.convert_triangle_feature_id(cell.0, cell.1, true, inter1.feature);
-                        return Some(inter1);
-                    } else {
-                        inter2.feature =
-                            self.convert_triangle_feature_id(cell.0, cell.1, false, inter2.feature);
-                        return Some(inter2);
-                    }
-                }
-                (Some(mut inter), None) => {
-                    inter.feature =
-                        self.convert_triangle_feature_id(cell.0, cell.1, true, inter.feature);
-                    return Some(inter);
-                }
-                (None, Some(mut inter)) => {
-                    inter.feature =
-                        self.convert_triangle_feature_id(cell.0, cell.1, false, inter.feature);
+            let exit_t = next_x.min(next_z).min(max_t);
+
+            /*
+             * Test the triangles of the current cell only if the part of the ray above this cell
+             * spans heights overlapping the heights of the cell.
+             */
+            let ray_y1 = ls_ray.origin.y + ls_ray.dir.y * enter_t;
+            let ray_y2 = ls_ray.origin.y + ls_ray.dir.y * exit_t;
+            let (cell_min_y, cell_max_y) = self.cell_height_range(cell.0, cell.1);
+
+            if ray_y1.min(ray_y2) <= cell_max_y + N::default_epsilon()
+                && ray_y1.max(ray_y2) >= cell_min_y - N::default_epsilon()
+            {
+                if let Some(inter) =
+                    self.toi_and_normal_with_ray_at(m, &ls_ray, cell.0, cell.1, max_toi, solid)
+                {
                     return Some(inter);
                 }
-                (None, None) => {}
             }
 
             /*
              * Find the next cell to cast the ray on.
              */
-            let (toi_x, right) = if ls_ray.dir.x > N::zero() {
-                let x = self.x_at(cell.1 + 1);
-                ((x - ls_ray.origin.x) / ls_ray.dir.x, true)
-            } else if ls_ray.dir.x < N::zero() {
-                let x = self.x_at(cell.1 + 0);
-                ((x - ls_ray.origin.x) / ls_ray.dir.x, false)
-            } else {
-                (N::max_value(), false)
-            };
-
-            let (toi_z, down) = if ls_ray.dir.z > N::zero() {
-                let z = self.z_at(cell.0 + 1);
-                ((z - ls_ray.origin.z) / ls_ray.dir.z, true)
-            } else if ls_ray.dir.z < N::zero() {
-                let z = self.z_at(cell.0 + 0);
-                ((z - ls_ray.origin.z) / ls_ray.dir.z, false)
-            } else {
-                (N::max_value(), false)
-            };
-
-            if toi_x > max_t && toi_z > max_t {
+            if exit_t >= max_t {
                 break;
             }
 
-            if toi_x >= N::zero() && toi_x < toi_z {
+            if next_x < next_z {
                 if right {
-                    cell.1 += 1
+                    cell.1 += 1;
                 } else if cell.1 > 0 {
-                    cell.1 -= 1
+                    cell.1 -= 1;
                 } else {
                     break;
                 }
-            } else if toi_z >= N::zero() {
+
+                enter_t = next_x;
+                next_x += delta_x;
+            } else {
                 if down {
-                    cell.0 += 1
+                    cell.0 += 1;
                 } else if cell.0 > 0 {
-                    cell.0 -= 1
+                    cell.0 -= 1;
                 } else {
                     break;
                 }
-            } else {
-                break;
+
+                enter_t = next_z;
+                next_z += delta_z;
             }
 
             if cell.0 >= self.nrows() || cell.1 >= self.ncols() {
@@ -243,3 +262,57 @@ impl<N: RealField> RayCast<N> for HeightField<N> {
         None
     }
 }
+
+#[cfg(feature = "dim3")]
+impl<N: RealField> HeightField<N> {
+    /// The minimum and maximum heights, including the scale factor, of the `(i, j)`-th cell.
+    fn cell_height_range(&self, i: usize, j: usize) -> (N, N) {
+        let heights = self.heights();
+        let y00 = heights[(i, j)] * self.scale().y;
+        let y10 = heights[(i + 1, j)] * self.scale().y;
+        let y01 = heights[(i, j + 1)] * self.scale().y;
+        let y11 = heights[(i + 1, j + 1)] * self.scale().y;
+
+        (
+            y00.min(y10).min(y01.min(y11)),
+            y00.max(y10).max(y01.max(y11)),
+        )
+    }
+
+    /// Casts the local-space ray `ls_ray` on the two triangles of the `(i, j)`-th cell.
+    fn toi_and_normal_with_ray_at(
+        &self,
+        m: &Isometry<N>,
+        ls_ray: &Ray<N>,
+        i: usize,
+        j: usize,
+        max_toi: N,
+        solid: bool,
+    ) -> Option<RayIntersection<N>> {
+        let id = Isometry::identity();
+        let tris = self.triangles_at(i, j);
+        let inter1 = tris
+            .0
+            .and_then(|tri| tri.toi_and_normal_with_ray(&id, ls_ray, max_toi, solid));
+        let inter2 = tris
+            .1
+            .and_then(|tri| tri.toi_and_normal_with_ray(&id, ls_ray, max_toi, solid));
+
+        let (mut inter, left) = match (inter1, inter2) {
+            (Some(inter1), Some(inter2)) => {
+                if inter1.toi < inter2.toi {
+                    (inter1, true)
+                } else {
+                    (inter2, false)
+                }
+            }
+            (Some(inter), None) => (inter, true),
+            (None, Some(inter)) => (inter, false),
+            (None, None) => return None,
+        };
+
+        inter.normal = m * inter.normal;
+        inter.feature = self.convert_triangle_feature_id(i, j, left, inter.feature);
+        Some(inter)
+    }
+}
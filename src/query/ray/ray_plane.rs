@@ -49,9 +49,9 @@ impl<N: RealField> RayCast<N> for Plane<N> {
     ) -> Option<RayIntersection<N>> {
         let ls_ray = ray.inverse_transform_by(m);
 
-        let dpos = -ls_ray.origin;
+        let dpos = self.center() - ls_ray.origin;
 
-        let dot_normal_dpos = self.normal.dot(&dpos.coords);
+        let dot_normal_dpos = self.normal.dot(&dpos);
 
         if solid && dot_normal_dpos > na::zero() {
             // The ray is inside of the solid half-space.
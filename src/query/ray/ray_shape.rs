@@ -44,4 +44,11 @@ impl<N: RealField> RayCast<N> for dyn Shape<N> {
             .expect("No RayCast implementation for the underlying shape.")
             .intersects_ray(m, ray, max_toi)
     }
+
+    #[inline]
+    fn ray_origin_inside(&self, m: &Isometry<N>, ray: &Ray<N>) -> bool {
+        self.as_ray_cast()
+            .expect("No RayCast implementation for the underlying shape.")
+            .ray_origin_inside(m, ray)
+    }
 }
@@ -4,12 +4,12 @@ use crate::math::Isometry;
 #[cfg(feature = "dim2")]
 use crate::query;
 use crate::query::algorithms::{gjk, CSOPoint, VoronoiSimplex};
-use crate::query::{Ray, RayCast, RayIntersection};
+use crate::query::{PointQuery, Ray, RayCast, RayIntersection};
 #[cfg(feature = "dim2")]
 use crate::shape::ConvexPolygon;
-use crate::shape::{Capsule, FeatureId, Segment, SupportMap};
 #[cfg(feature = "dim3")]
 use crate::shape::{Cone, ConvexHull, Cylinder};
+use crate::shape::{FeatureId, Rounded, Segment, SupportMap};
 
 /// Cast a ray on a shape using the GJK algorithm.
 pub fn ray_intersection_with_support_map_with_params<N, G: ?Sized>(
@@ -83,6 +83,11 @@ impl<N: RealField> RayCast<N> for Cylinder<N> {
             res
         })
     }
+
+    #[inline]
+    fn ray_origin_inside(&self, m: &Isometry<N>, ray: &Ray<N>) -> bool {
+        self.contains_point(m, &ray.origin)
+    }
 }
 
 #[cfg(feature = "dim3")]
@@ -109,9 +114,18 @@ impl<N: RealField> RayCast<N> for Cone<N> {
             res
         })
     }
+
+    #[inline]
+    fn ray_origin_inside(&self, m: &Isometry<N>, ray: &Ray<N>) -> bool {
+        self.contains_point(m, &ray.origin)
+    }
 }
 
-impl<N: RealField> RayCast<N> for Capsule<N> {
+impl<S, N> RayCast<N> for Rounded<S, N>
+where
+    S: SupportMap<N> + PointQuery<N>,
+    N: RealField,
+{
     fn toi_and_normal_with_ray(
         &self,
         m: &Isometry<N>,
@@ -134,6 +148,11 @@ impl<N: RealField> RayCast<N> for Capsule<N> {
             res
         })
     }
+
+    #[inline]
+    fn ray_origin_inside(&self, m: &Isometry<N>, ray: &Ray<N>) -> bool {
+        self.contains_point(m, &ray.origin)
+    }
 }
 
 #[cfg(feature = "dim3")]
@@ -160,6 +179,11 @@ impl<N: RealField> RayCast<N> for ConvexHull<N> {
             res
         })
     }
+
+    #[inline]
+    fn ray_origin_inside(&self, m: &Isometry<N>, ray: &Ray<N>) -> bool {
+        self.contains_point(m, &ray.origin)
+    }
 }
 
 #[cfg(feature = "dim2")]
@@ -186,6 +210,11 @@ impl<N: RealField> RayCast<N> for ConvexPolygon<N> {
             res
         })
     }
+
+    #[inline]
+    fn ray_origin_inside(&self, m: &Isometry<N>, ray: &Ray<N>) -> bool {
+        self.contains_point(m, &ray.origin)
+    }
 }
 
 // FIXME: optimize this, we should use the general algorithm for triangles.
@@ -311,3 +340,26 @@ impl<N: RealField> RayCast<N> for Segment<N> {
         }
     }
 }
+
+impl<N: RealField> Segment<N> {
+    /// Computes the time of impact, and normal between this transformed segment and a ray.
+    ///
+    /// If `backface_culling` is `true`, hits on the side opposite to the segment normal (as given
+    /// by `self.scaled_normal()`) are ignored, and so are rays parallel to the segment.
+    #[cfg(feature = "dim2")]
+    pub fn toi_and_normal_with_ray_culled(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        backface_culling: bool,
+    ) -> Option<RayIntersection<N>> {
+        let inter = self.toi_and_normal_with_ray(m, ray, max_toi, true)?;
+
+        if backface_culling && self.transformed(m).scaled_normal().dot(&ray.dir) >= N::zero() {
+            None
+        } else {
+            Some(inter)
+        }
+    }
+}
@@ -12,9 +12,32 @@ impl<N: RealField> RayCast<N> for Triangle<N> {
         ray: &Ray<N>,
         max_toi: N,
         _: bool,
+    ) -> Option<RayIntersection<N>> {
+        self.toi_and_normal_with_ray_culled(m, ray, max_toi, false)
+    }
+}
+
+impl<N: RealField> Triangle<N> {
+    /// Computes the time of impact, and normal between this transformed triangle and a ray.
+    ///
+    /// If `backface_culling` is `true`, hits on the side opposite to the triangle normal (as
+    /// given by its counter-clockwise winding) are ignored.
+    pub fn toi_and_normal_with_ray_culled(
+        &self,
+        m: &Isometry<N>,
+        ray: &Ray<N>,
+        max_toi: N,
+        backface_culling: bool,
     ) -> Option<RayIntersection<N>> {
         let ls_ray = ray.inverse_transform_by(m);
-        let mut inter = ray_intersection_with_triangle(&self.a, &self.b, &self.c, &ls_ray)?.0;
+        let mut inter = ray_intersection_with_triangle_with_params(
+            &self.a,
+            &self.b,
+            &self.c,
+            &ls_ray,
+            backface_culling,
+        )?
+        .0;
 
         if inter.toi <= max_toi {
             inter.normal = m * inter.normal;
@@ -34,6 +57,21 @@ pub fn ray_intersection_with_triangle<N: RealField>(
     b: &Point<N>,
     c: &Point<N>,
     ray: &Ray<N>,
+) -> Option<(RayIntersection<N>, Vector3<N>)> {
+    ray_intersection_with_triangle_with_params(a, b, c, ray, false)
+}
+
+/// Computes the intersection between a triangle and a ray, optionally ignoring backfaces.
+///
+/// If `backface_culling` is `true`, the ray hitting the triangle from the side opposite to its
+/// normal `(b - a).cross(c - a)` is not considered as an intersection. A ray parallel to the
+/// triangle plane never intersects it.
+pub fn ray_intersection_with_triangle_with_params<N: RealField>(
+    a: &Point<N>,
+    b: &Point<N>,
+    c: &Point<N>,
+    ray: &Ray<N>,
+    backface_culling: bool,
 ) -> Option<(RayIntersection<N>, Vector3<N>)> {
     let ab = *b - *a;
     let ac = *c - *a;
@@ -47,6 +85,11 @@ pub fn ray_intersection_with_triangle<N: RealField>(
         return None;
     }
 
+    // the ray hits the back of the triangle
+    if backface_culling && d > N::zero() {
+        return None;
+    }
+
     let ap = ray.origin - *a;
     let t = ap.dot(&n);
 
@@ -0,0 +1,63 @@
+use na::RealField;
+
+use crate::math::{Isometry, Vector};
+use crate::query;
+use crate::shape::SupportMap;
+
+/// Computes the time interval during which two support-mapped shapes under translational
+/// movement are in contact.
+///
+/// Returns `(t_enter, t_exit)` where `t_enter` is the first time the shapes touch and `t_exit`
+/// the time they separate again, both clamped to `[0, max_toi]`. Shapes that overlap at the time
+/// 0 have a `t_enter` of zero, and shapes still overlapping at `max_toi` have a `t_exit` equal to
+/// `max_toi`. Returns `None` if the shapes do not touch before `max_toi`.
+///
+/// Because both shapes are convex and move with constant velocities, they touch during a single
+/// time interval. Its end is computed as the time of impact of the reversed motion starting at
+/// `max_toi`.
+pub fn contact_interval_linear<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    vel1: &Vector<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    vel2: &Vector<N>,
+    g2: &G2,
+    max_toi: N,
+) -> Option<(N, N)>
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    let enter = query::time_of_impact_support_map_support_map(
+        m1,
+        vel1,
+        g1,
+        m2,
+        vel2,
+        g2,
+        max_toi,
+        N::zero(),
+    )?
+    .toi;
+
+    let mut end1 = *m1;
+    let mut end2 = *m2;
+    end1.translation.vector += vel1 * max_toi;
+    end2.translation.vector += vel2 * max_toi;
+
+    let exit = query::time_of_impact_support_map_support_map(
+        &end1,
+        &-vel1,
+        g1,
+        &end2,
+        &-vel2,
+        g2,
+        max_toi,
+        N::zero(),
+    )
+    .map(|toi| max_toi - toi.toi)
+    .unwrap_or(enter);
+
+    Some((enter, exit.max(enter)))
+}
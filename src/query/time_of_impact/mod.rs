@@ -1,5 +1,7 @@
 //! Implementation details of the `time_of_impact` function.
 
+pub use self::contact_interval_linear::contact_interval_linear;
+pub use self::shape_cast_ball::shape_cast_ball;
 pub use self::time_of_impact::{time_of_impact, TOIStatus, TOI};
 pub use self::time_of_impact_ball_ball::time_of_impact_ball_ball;
 pub use self::time_of_impact_composite_shape_shape::{
@@ -8,8 +10,12 @@ pub use self::time_of_impact_composite_shape_shape::{
 pub use self::time_of_impact_plane_support_map::{
     time_of_impact_plane_support_map, time_of_impact_support_map_plane,
 };
-pub use self::time_of_impact_support_map_support_map::time_of_impact_support_map_support_map;
+pub use self::time_of_impact_support_map_support_map::{
+    time_of_impact_support_map_support_map, time_of_impact_with_trace, TOITraceCallback,
+};
 
+mod contact_interval_linear;
+mod shape_cast_ball;
 mod time_of_impact;
 mod time_of_impact_ball_ball;
 mod time_of_impact_composite_shape_shape;
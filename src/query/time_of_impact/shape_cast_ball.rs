@@ -0,0 +1,45 @@
+use na::RealField;
+
+use crate::math::{Isometry, Point, Vector};
+use crate::query::{self, TOI};
+use crate::shape::{Ball, SupportMap};
+
+/// Casts a ball with radius `ball_radius` from `origin` along `dir` against the static
+/// support-mapped shape `g` transformed by `m`.
+///
+/// This is a time-of-impact between a moving ball and `g`, computed by casting the ball
+/// center against `g` dilated by `ball_radius`. The first shape of the returned `TOI` is the
+/// ball, expressed in the local-space of the ball at its starting position `origin`, and the
+/// second shape is `g`.
+pub fn shape_cast_ball<N, G: ?Sized>(
+    ball_radius: N,
+    origin: &Point<N>,
+    dir: &Vector<N>,
+    max_toi: N,
+    target_distance: N,
+    m: &Isometry<N>,
+    g: &G,
+) -> Option<TOI<N>>
+where
+    N: RealField,
+    G: SupportMap<N>,
+{
+    let center = Ball::new(N::zero());
+    let center_pos = Isometry::from_parts(origin.coords.into(), na::one());
+
+    query::time_of_impact_support_map_support_map(
+        m,
+        &Vector::zeros(),
+        g,
+        &center_pos,
+        dir,
+        &center,
+        max_toi,
+        target_distance + ball_radius,
+    )
+    .map(|toi| {
+        let mut toi = toi.swapped();
+        toi.witness1 = Point::from(*toi.normal1 * ball_radius);
+        toi
+    })
+}
@@ -5,6 +5,11 @@ use crate::query::{self, Ray, TOIStatus, TOI};
 use crate::shape::Ball;
 
 /// Time Of Impact of two balls under translational movement.
+///
+/// This solves the quadratic equation of the relative motion of the ball centers directly,
+/// i.e., it is a ray-cast on a ball with a radius equal to the sum of the radii of `b1` and `b2`
+/// and `target_distance`. If the balls are already closer than `target_distance`, the returned
+/// time of impact is zero.
 #[inline]
 pub fn time_of_impact_ball_ball<N: RealField>(
     center1: &Point<N>,
@@ -39,7 +44,14 @@ pub fn time_of_impact_ball_ball<N: RealField>(
             witness1 = Point::origin();
             witness2 = Point::origin();
         } else {
-            normal1 = Unit::new_unchecked(dpt / radius);
+            // NOTE: if the balls already overlap at `toi = 0`, `dpt` is shorter than `radius`
+            // so it has to be normalized explicitly. Concentric balls have no well-defined
+            // normal so we pick an arbitrary one.
+            normal1 = if inside {
+                Unit::try_new(dpt, N::default_epsilon()).unwrap_or_else(Vector::x_axis)
+            } else {
+                Unit::new_unchecked(dpt / radius)
+            };
             normal2 = -normal1;
             witness1 = Point::from(*normal1 * b1.radius);
             witness2 = Point::from(*normal2 * b2.radius);
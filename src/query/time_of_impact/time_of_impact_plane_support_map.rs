@@ -36,12 +36,13 @@ where
         let witness1 = mother.inverse_transform_point(&support_point);
         let mut witness2 = mplane.inverse_transform_point(&ray.point_at(toi));
 
-        if (support_point.coords - mplane.translation.vector).dot(&plane_normal) < N::zero() {
+        if (support_point - mplane * plane.center()).dot(&plane_normal) < N::zero() {
             status = TOIStatus::Penetrating
         } else {
             // Project the witness point to the plane.
             // Note that witness2 is already in the plane's local-space.
-            witness2 = witness2 - *plane.normal * witness2.coords.dot(&plane.normal);
+            witness2 =
+                witness2 - *plane.normal * (witness2.coords.dot(&plane.normal) - plane.offset);
             status = TOIStatus::Converged
         }
 
@@ -114,3 +114,72 @@ where
             })
     }
 }
+
+/// A callback reporting an iteration of a time of impact computation.
+///
+/// It is called with the current time and the positions of both shapes at this time.
+pub type TOITraceCallback<'a, N> = dyn FnMut(N, &Isometry<N>, &Isometry<N>) + 'a;
+
+/// Time of impacts between two support-mapped shapes under translational movement, reporting each
+/// iteration of the computation.
+///
+/// The `on_step` callback is called at each iteration of the GJK ray-cast on the Minkowski
+/// difference of both shapes, with the current lower bound of the time of impact and the positions
+/// of both shapes at this time. Only iterations with a time smaller than `max_toi` are reported.
+/// This is mostly useful for debugging, e.g., to visualize why the computation converged or
+/// failed. The callback has no effect on the result, which is computed by
+/// `query::time_of_impact_support_map_support_map`.
+#[allow(clippy::too_many_arguments)]
+pub fn time_of_impact_with_trace<N, G1: ?Sized, G2: ?Sized>(
+    m1: &Isometry<N>,
+    vel1: &Vector<N>,
+    g1: &G1,
+    m2: &Isometry<N>,
+    vel2: &Vector<N>,
+    g2: &G2,
+    max_toi: N,
+    target_distance: N,
+    on_step: &mut TOITraceCallback<N>,
+) -> Option<TOI<N>>
+where
+    N: RealField,
+    G1: SupportMap<N>,
+    G2: SupportMap<N>,
+{
+    let dvel = vel2 - vel1;
+    let position_at_time = |m: &Isometry<N>, vel: &Vector<N>, t: N| {
+        Isometry::from_parts((m.translation.vector + vel * t).into(), m.rotation)
+    };
+    let mut on_iteration = |t: N| {
+        if t <= max_toi {
+            on_step(
+                t,
+                &position_at_time(m1, vel1, t),
+                &position_at_time(m2, vel2, t),
+            )
+        }
+    };
+
+    // Replay the ray-cast performed by `time_of_impact_support_map_support_map`.
+    let mut simplex = VoronoiSimplex::new();
+    let _ = if target_distance.is_zero() {
+        gjk::directional_distance_with_trace(m1, g1, m2, g2, &dvel, &mut simplex, &mut on_iteration)
+    } else {
+        let dilated1 = DilatedShape {
+            shape: g1,
+            radius: target_distance,
+        };
+
+        gjk::directional_distance_with_trace(
+            m1,
+            &dilated1,
+            m2,
+            g2,
+            &dvel,
+            &mut simplex,
+            &mut on_iteration,
+        )
+    };
+
+    time_of_impact_support_map_support_map(m1, vel1, g1, m2, vel2, g2, max_toi, target_distance)
+}
@@ -18,6 +18,16 @@ impl<N: RealField> Ball<N> {
         Ball { radius }
     }
 
+    /// Creates a new ball with the radius of `self` multiplied by `factor`.
+    ///
+    /// # Panics
+    /// Panics if `factor` is not strictly positive.
+    #[inline]
+    pub fn scaled(&self, factor: N) -> Ball<N> {
+        assert!(factor > N::zero(), "The scaling factor must be positive.");
+        Ball::new(self.radius * factor)
+    }
+
     /// The ball radius.
     #[inline]
     #[deprecated(note = "use the `self.radius` public field directly.")]
@@ -26,10 +36,19 @@ impl<N: RealField> Ball<N> {
     }
 }
 
+/// Normalizes `dir`, falling back to the `x` axis if it is (close to) zero.
+///
+/// GJK may query a support point along a degenerate direction, in which case any point of the
+/// ball is valid so we pick a fixed one to keep the result deterministic.
+#[inline]
+fn normalize_or_x_axis<N: RealField>(dir: &Vector<N>) -> Unit<Vector<N>> {
+    Unit::try_new(*dir, N::default_epsilon()).unwrap_or_else(Vector::x_axis)
+}
+
 impl<N: RealField> SupportMap<N> for Ball<N> {
     #[inline]
     fn support_point(&self, m: &Isometry<N>, dir: &Vector<N>) -> Point<N> {
-        self.support_point_toward(m, &Unit::new_normalize(*dir))
+        self.support_point_toward(m, &normalize_or_x_axis(dir))
     }
 
     #[inline]
@@ -39,7 +58,7 @@ impl<N: RealField> SupportMap<N> for Ball<N> {
 
     #[inline]
     fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
-        self.local_support_point_toward(&Unit::new_normalize(*dir))
+        self.local_support_point_toward(&normalize_or_x_axis(dir))
     }
 
     #[inline]
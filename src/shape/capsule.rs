@@ -2,11 +2,13 @@
 
 use na::{self, RealField, Unit};
 
-use crate::math::{Point, Vector};
+use crate::math::{AngularInertia, Isometry, Point, Vector};
 use crate::query::{Contact, ContactKinematic, ContactPreprocessor};
-use crate::shape::{FeatureId, Segment, SupportMap};
+use crate::shape::{FeatureId, MassProperties, Segment, SupportMap};
 
 /// SupportMap description of a capsule shape with its principal axis aligned with the `y` axis.
+///
+/// In 2D, this is a rounded segment, i.e., a rectangle capped by two half-disks.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub struct Capsule<N> {
@@ -29,6 +31,16 @@ impl<N: RealField> Capsule<N> {
         }
     }
 
+    /// Creates a new capsule with the half-height and radius of `self` multiplied by `factor`.
+    ///
+    /// # Panics
+    /// Panics if `factor` is not strictly positive.
+    #[inline]
+    pub fn scaled(&self, factor: N) -> Capsule<N> {
+        assert!(factor > N::zero(), "The scaling factor must be positive.");
+        Capsule::new(self.half_height * factor, self.radius * factor)
+    }
+
     /// The capsule half length along its local `y` axis.
     #[inline]
     #[deprecated(note = "use the `self.half_height` public field directly.")]
@@ -42,6 +54,14 @@ impl<N: RealField> Capsule<N> {
         self.half_height * na::convert(2.0)
     }
 
+    /// The local symmetry axis of this capsule, i.e., the local `y` axis.
+    ///
+    /// It points from the first endpoint of `self.segment()` toward the second one.
+    #[inline]
+    pub fn axis(&self) -> Unit<Vector<N>> {
+        Vector::y_axis()
+    }
+
     /// The radius of the capsule's rounded part.
     #[inline]
     #[deprecated(note = "use the `self.radius` public field directly.")]
@@ -49,6 +69,66 @@ impl<N: RealField> Capsule<N> {
         self.radius
     }
 
+    /// Computes the mass properties of this capsule, assuming a uniform `density`.
+    ///
+    /// The capsule is decomposed into a rectangle and two half-disks.
+    #[cfg(feature = "dim2")]
+    pub fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let _2: N = na::convert(2.0f64);
+        let _3: N = na::convert(3.0f64);
+        let _4: N = na::convert(4.0f64);
+        let _12: N = na::convert(12.0f64);
+        let r = self.radius;
+        let r2 = r * r;
+        let height = self.height();
+
+        let rect_mass = _2 * r * height * density;
+        let rect_inertia = rect_mass * (_4 * r2 + height * height) / _12;
+
+        // Each half-disk has its center of mass at `4r / (3pi)` from its flat side.
+        let disk_mass = N::pi() * r2 / _2 * density;
+        let disk_com = _4 * r / (_3 * N::pi());
+        let disk_shift = self.half_height + disk_com;
+        let disk_inertia = disk_mass * (r2 / _2 - disk_com * disk_com + disk_shift * disk_shift);
+
+        let inertia: AngularInertia<N> = rect_inertia + disk_inertia * _2;
+        MassProperties::new(rect_mass + disk_mass * _2, Point::origin(), inertia)
+    }
+
+    /// Computes the mass properties of this capsule, assuming a uniform `density`.
+    ///
+    /// The capsule is decomposed into a cylinder and two hemispheres. The inertia of each
+    /// hemisphere is shifted to the capsule center with the parallel axis theorem.
+    #[cfg(feature = "dim3")]
+    pub fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let _2: N = na::convert(2.0f64);
+        let _3: N = na::convert(3.0f64);
+        let _5: N = na::convert(5.0f64);
+        let _8: N = na::convert(8.0f64);
+        let _12: N = na::convert(12.0f64);
+        let _83_320: N = na::convert(83.0f64 / 320.0);
+        let r = self.radius;
+        let r2 = r * r;
+        let height = self.height();
+
+        let cyl_mass = N::pi() * r2 * height * density;
+        let cyl_axial = cyl_mass * r2 / _2;
+        let cyl_transverse = cyl_mass * (_3 * r2 + height * height) / _12;
+
+        // Each hemisphere has its center of mass at `3r / 8` from its flat side.
+        let hemi_mass = _2 * N::pi() * r2 * r / _3 * density;
+        let hemi_axial = hemi_mass * r2 * _2 / _5;
+        let hemi_shift = self.half_height + _3 * r / _8;
+        let hemi_transverse = hemi_mass * (_83_320 * r2 + hemi_shift * hemi_shift);
+
+        let axial = cyl_axial + hemi_axial * _2;
+        let transverse = cyl_transverse + hemi_transverse * _2;
+        let inertia: AngularInertia<N> =
+            AngularInertia::from_diagonal(&Vector::new(transverse, axial, transverse));
+
+        MassProperties::new(cyl_mass + hemi_mass * _2, Point::origin(), inertia)
+    }
+
     /// The segment that, once dilated by `self.radius` yields this capsule.
     #[inline]
     pub fn segment(&self) -> Segment<N> {
@@ -60,6 +140,21 @@ impl<N: RealField> Capsule<N> {
         Segment::new(a, b)
     }
 
+    /// The segment that, once dilated by `self.radius` yields this capsule transformed by `m`.
+    #[inline]
+    pub fn world_segment(&self, m: &Isometry<N>) -> Segment<N> {
+        self.segment().transformed(m)
+    }
+
+    /// The endpoints of the segment of this capsule transformed by `m`.
+    ///
+    /// The first endpoint is the one with a negative local `y` coordinate.
+    #[inline]
+    pub fn world_endpoints(&self, m: &Isometry<N>) -> (Point<N>, Point<N>) {
+        let segment = self.world_segment(m);
+        (segment.a, segment.b)
+    }
+
     /// The contact preprocessor to be used for contact determination with this capsule.
     #[inline]
     pub fn contact_preprocessor(&self) -> impl ContactPreprocessor<N> {
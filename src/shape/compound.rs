@@ -4,7 +4,8 @@
 
 use crate::bounding_volume::{BoundingVolume, AABB};
 use crate::math::Isometry;
-use crate::partitioning::{BVHImpl, BVT};
+use crate::partitioning::{BVHImpl, BVH, BVT};
+use crate::query::visitors::BoundingVolumeInterferencesCollector;
 use crate::query::{Contact, ContactKinematic, ContactPrediction, ContactPreprocessor};
 use crate::shape::{CompositeShape, FeatureId, Shape, ShapeHandle};
 use na::{self, RealField};
@@ -20,6 +21,7 @@ pub struct Compound<N: RealField> {
     shapes: Vec<(Isometry<N>, ShapeHandle<N>)>,
     bvt: BVT<usize, AABB<N>>,
     bvs: Vec<AABB<N>>,
+    local_aabb: AABB<N>,
     nbits: usize,
 }
 
@@ -28,11 +30,13 @@ impl<N: RealField> Compound<N> {
     pub fn new(shapes: Vec<(Isometry<N>, ShapeHandle<N>)>) -> Compound<N> {
         let mut bvs = Vec::new();
         let mut leaves = Vec::new();
+        let mut local_aabb = AABB::new_invalid();
 
         for (i, &(ref delta, ref shape)) in shapes.iter().enumerate() {
             // loosen for better persistancy
             let bv = shape.as_ref().aabb(delta).loosened(na::convert(0.04f64));
 
+            local_aabb.merge(&bv);
             bvs.push(bv.clone());
             leaves.push((i, bv));
 
@@ -48,6 +52,7 @@ impl<N: RealField> Compound<N> {
             shapes: shapes,
             bvt: bvt,
             bvs: bvs,
+            local_aabb,
             nbits,
         }
     }
@@ -74,18 +79,61 @@ impl<N: RealField> Compound<N> {
             .expect("An empty Compound has no AABB.")
     }
 
+    /// The AABB of this compound in its local-space, i.e., the union of the AABBs of its parts.
+    ///
+    /// This is computed once at the construction of the compound. If the compound is empty, this
+    /// returns an invalid AABB (see `AABB::new_invalid`).
+    #[inline]
+    pub fn local_aabb(&self) -> AABB<N> {
+        self.local_aabb
+    }
+
     /// The shapes bounding volumes.
+    ///
+    /// Those are computed once at the construction of the compound and are slightly loosened
+    /// (by `0.04`) for better persistency.
     #[inline]
     pub fn bounding_volumes(&self) -> &[AABB<N>] {
         &self.bvs[..]
     }
 
-    /// The AABB of the i-th shape compositing this compound.
+    /// The AABB of the i-th shape compositing this compound, expressed in the compound
+    /// local-space.
+    ///
+    /// This AABB is cached at the construction of the compound and is slightly loosened
+    /// (by `0.04`) for better persistency.
     #[inline]
     pub fn aabb_at(&self, i: usize) -> &AABB<N> {
         &self.bvs[i]
     }
 
+    /// Appends to `out` the indices of the shapes compositing this compound with an AABB
+    /// intersecting `aabb`, expressed in the compound local-space.
+    ///
+    /// The AABBs touching `aabb` are included. The candidates found with the loosened bounding
+    /// volumes of the BVT are filtered with the exact AABBs of the shapes.
+    pub fn children_in_aabb(&self, aabb: &AABB<N>, out: &mut Vec<usize>) {
+        let start = out.len();
+
+        {
+            let mut visitor = BoundingVolumeInterferencesCollector::new(aabb, out);
+            self.bvt.visit(&mut visitor);
+        }
+
+        let mut nkept = start;
+
+        for k in start..out.len() {
+            let (ref m, ref shape) = self.shapes[out[k]];
+
+            if shape.aabb(m).intersects(aabb) {
+                out[nkept] = out[k];
+                nkept += 1;
+            }
+        }
+
+        out.truncate(nkept);
+    }
+
     /// Transforms a FeatureId of this compound into a pair containing the index of the subshape
     /// containing this feature, and the corresponding FeatureId on this subshape.
     pub fn subshape_feature_id(&self, fid: FeatureId) -> (usize, FeatureId) {
@@ -1,11 +1,15 @@
 use crate::math::{Isometry, Point, Vector};
-use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportMap};
+use crate::shape::{
+    ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, MassProperties, SupportMap,
+};
 use crate::transformation;
 use crate::utils::{self, SortedPair};
-use na::{self, Point2, Point3, RealField, Unit};
+use na::{self, Matrix3, Point2, Point3, RealField, Unit};
+use std::borrow::Cow;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::f64;
+use std::mem;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Debug, Copy, Clone)]
@@ -352,6 +356,144 @@ impl<N: RealField> ConvexHull<N> {
         &self.points[..]
     }
 
+    /// The outward normals of the faces of this convex polyhedron.
+    #[inline]
+    pub fn face_normals(&self) -> impl Iterator<Item = Unit<Vector<N>>> + '_ {
+        self.faces.iter().map(|face| face.normal)
+    }
+
+    /// The number of faces of this convex polyhedron.
+    #[inline]
+    pub fn num_faces(&self) -> usize {
+        self.faces.len()
+    }
+
+    /// The outward normal of the `i`-th face of this convex polyhedron, in its local-space.
+    ///
+    /// The `i`-th face is the one identified by `FeatureId::Face(i)`. Every point of this convex
+    /// polyhedron lies on the side of the face plane opposite to this normal.
+    #[inline]
+    pub fn face_normal(&self, i: usize) -> Unit<Vector<N>> {
+        self.faces[i].normal
+    }
+
+    /// A point on the plane of the `i`-th face of this convex polyhedron, in its local-space.
+    ///
+    /// This is one of the vertices of the face identified by `FeatureId::Face(i)`.
+    #[inline]
+    pub fn face_point(&self, i: usize) -> Point<N> {
+        self.points[self.vertices_adj_to_face[self.faces[i].first_vertex_or_edge]]
+    }
+
+    /// The index of the vertex of this convex polyhedron that is the farthest along `dir`.
+    ///
+    /// The returned index identifies a point of `self.points()`. This checks every vertex, see
+    /// `self.support_vertex_id_from` to start from a known vertex instead.
+    #[inline]
+    pub fn support_vertex_id(&self, dir: &Vector<N>) -> usize {
+        utils::point_cloud_support_point_id(dir, &self.points)
+    }
+
+    /// The index of the vertex of this convex polyhedron that is the farthest along `dir`, found
+    /// by hill-climbing from the vertex with index `start`.
+    ///
+    /// Starting from the support vertex of a nearby direction, e.g., the result of the previous
+    /// call, usually only visits a few vertices. If several vertices are the farthest along `dir`,
+    /// the returned one may differ from the one returned by `self.support_vertex_id(dir)`.
+    pub fn support_vertex_id_from(&self, dir: &Vector<N>, start: usize) -> usize {
+        if self.vertices[start].num_adj_faces_or_edge == 0 {
+            // `start` is not connected to the polyhedron.
+            return self.support_vertex_id(dir);
+        }
+
+        let mut best = start;
+        let mut best_dot = self.points[best].coords.dot(dir);
+
+        loop {
+            let current = best;
+            let vertex = &self.vertices[current];
+            let first = vertex.first_adj_face_or_edge;
+            let last = first + vertex.num_adj_faces_or_edge;
+
+            for edge_id in &self.edges_adj_to_vertex[first..last] {
+                let edge = &self.edges[*edge_id];
+                let neighbor = if edge.vertices[0] == current {
+                    edge.vertices[1]
+                } else {
+                    edge.vertices[0]
+                };
+                let dot = self.points[neighbor].coords.dot(dir);
+
+                if dot > best_dot {
+                    best = neighbor;
+                    best_dot = dot;
+                }
+            }
+
+            // Because the polyhedron is convex, a vertex without any better neighbor is optimal.
+            if best == current {
+                return best;
+            }
+        }
+    }
+
+    /// Computes the mass properties of this convex polyhedron, assuming a uniform `density`.
+    ///
+    /// The polyhedron is decomposed into tetrahedra joining the center of its vertices to a
+    /// triangle fan of each face, and their contributions are summed using their covariance
+    /// matrices.
+    pub fn mass_properties(&self, density: N) -> MassProperties<N> {
+        let _6: N = na::convert(6.0f64);
+        let _4: N = na::convert(4.0f64);
+        let _120: N = na::convert(120.0f64);
+
+        // The reference point is inside of the polyhedron so every tetrahedron is positively
+        // oriented as long as its base triangle is wound counterclockwise around the outward
+        // face normal.
+        let reference = utils::center(&self.points);
+        let mut volume = N::zero();
+        let mut com = Vector::zeros();
+        let mut covariance = Matrix3::zeros();
+
+        for face in &self.faces {
+            let first = face.first_vertex_or_edge;
+            let last = first + face.num_vertices_or_edges;
+            let a = self.points[self.vertices_adj_to_face[first]] - reference;
+
+            for i in first + 1..last - 1 {
+                let mut b = self.points[self.vertices_adj_to_face[i]] - reference;
+                let mut c = self.points[self.vertices_adj_to_face[i + 1]] - reference;
+
+                if (b - a).cross(&(c - a)).dot(&face.normal) < N::zero() {
+                    mem::swap(&mut b, &mut c);
+                }
+
+                let det = a.dot(&b.cross(&c));
+                let sum = a + b + c;
+
+                volume += det / _6;
+                com += sum * (det / (_6 * _4));
+                covariance += (a * a.transpose()
+                    + b * b.transpose()
+                    + c * c.transpose()
+                    + sum * sum.transpose())
+                    * (det / _120);
+            }
+        }
+
+        if volume.is_zero() {
+            return MassProperties::new(N::zero(), reference, Matrix3::zeros());
+        }
+
+        com /= volume;
+        // Move the covariance to the center of mass.
+        covariance -= com * com.transpose() * volume;
+
+        let inertia = Matrix3::from_diagonal_element(covariance.trace()) - covariance;
+
+        MassProperties::new(volume * density, reference + com, inertia * density)
+    }
+
     /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
     pub fn tangent_cone_contains_dir(
         &self,
@@ -427,6 +569,19 @@ impl<N: RealField> ConvexPolyhedron<N> for ConvexHull<N> {
         self.points[id.unwrap_vertex()]
     }
 
+    fn vertices(&self) -> Cow<'_, [Point<N>]> {
+        Cow::Borrowed(&self.points)
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+        Box::new(
+            self.edges
+                .iter()
+                .filter(|e| !e.deleted)
+                .map(|e| (e.vertices[0], e.vertices[1])),
+        )
+    }
+
     fn edge(&self, id: FeatureId) -> (Point<N>, Point<N>, FeatureId, FeatureId) {
         let edge = &self.edges[id.unwrap_edge()];
         let v1 = edge.vertices[0];
@@ -3,6 +3,7 @@ use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportM
 use crate::transformation;
 use crate::utils;
 use na::{self, RealField, Unit};
+use std::borrow::Cow;
 use std::f64;
 
 /// A 2D convex polygon.
@@ -120,6 +121,15 @@ impl<N: RealField> ConvexPolyhedron<N> for ConvexPolygon<N> {
         self.points[id.unwrap_vertex()]
     }
 
+    fn vertices(&self) -> Cow<'_, [Point<N>]> {
+        Cow::Borrowed(&self.points)
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+        let n = self.points.len();
+        Box::new((0..n).map(move |i| (i, (i + 1) % n)))
+    }
+
     fn face(&self, id: FeatureId, out: &mut ConvexPolygonalFeature<N>) {
         out.clear();
 
@@ -1,6 +1,7 @@
 use crate::math::{Isometry, Point, Vector};
 use crate::shape::{ConvexPolygonalFeature, SupportMap};
 use na::{RealField, Unit};
+use std::borrow::Cow;
 
 /// An identifier of a feature of a convex polyhedron.
 ///
@@ -47,8 +48,58 @@ impl FeatureId {
             _ => panic!("The feature id does not identify a face."),
         }
     }
+
+    /// Encodes this feature identifier into a single integer.
+    ///
+    /// The two most significant bits store the feature kind (`0` for `Unknown`, `1` for a vertex,
+    /// `2` for an edge, and `3` for a face) and the 62 remaining bits store the feature subid.
+    /// `FeatureId::Unknown` is therefore packed as `0`. The result can be decoded with
+    /// `FeatureId::unpack` and `FeatureId::unpack(id.pack()) == id` for any `id` with a subid
+    /// smaller than `2^62`.
+    ///
+    /// # Panics
+    /// Panics if the subid does not fit in 62 bits.
+    pub fn pack(&self) -> u64 {
+        let (kind, subid) = match *self {
+            FeatureId::Unknown => return 0,
+            FeatureId::Vertex(id) => (FEATURE_KIND_VERTEX, id),
+            #[cfg(feature = "dim3")]
+            FeatureId::Edge(id) => (FEATURE_KIND_EDGE, id),
+            FeatureId::Face(id) => (FEATURE_KIND_FACE, id),
+        };
+
+        let subid = subid as u64;
+        assert!(
+            subid & !FEATURE_SUBID_MASK == 0,
+            "The feature subid is too large to be packed."
+        );
+
+        (kind << FEATURE_KIND_SHIFT) | subid
+    }
+
+    /// Decodes a feature identifier encoded with `FeatureId::pack`.
+    ///
+    /// An edge code decoded in 2D yields `FeatureId::Unknown`.
+    pub fn unpack(code: u64) -> FeatureId {
+        let subid = (code & FEATURE_SUBID_MASK) as usize;
+
+        match code >> FEATURE_KIND_SHIFT {
+            FEATURE_KIND_VERTEX => FeatureId::Vertex(subid),
+            #[cfg(feature = "dim3")]
+            FEATURE_KIND_EDGE => FeatureId::Edge(subid),
+            FEATURE_KIND_FACE => FeatureId::Face(subid),
+            _ => FeatureId::Unknown,
+        }
+    }
 }
 
+const FEATURE_KIND_SHIFT: u64 = 62;
+const FEATURE_SUBID_MASK: u64 = (1 << FEATURE_KIND_SHIFT) - 1;
+const FEATURE_KIND_VERTEX: u64 = 1;
+#[cfg(feature = "dim3")]
+const FEATURE_KIND_EDGE: u64 = 2;
+const FEATURE_KIND_FACE: u64 = 3;
+
 /// Trait implemented by all convex polyhedron.
 pub trait ConvexPolyhedron<N: RealField>: SupportMap<N> {
     /// Gets the specified vertex in the shape local-space.
@@ -59,6 +110,22 @@ pub trait ConvexPolyhedron<N: RealField>: SupportMap<N> {
     /// Get the specified edge's vertices (in the shape local-space) and the vertices' identifiers.
     fn edge(&self, id: FeatureId) -> (Point<N>, Point<N>, FeatureId, FeatureId);
 
+    /// The vertices of this convex polyhedron, in its local-space.
+    ///
+    /// The `i`-th vertex is the one identified by `FeatureId::Vertex(i)`. The default
+    /// implementation returns no vertex.
+    fn vertices(&self) -> Cow<'_, [Point<N>]> {
+        Cow::Borrowed(&[])
+    }
+
+    /// The edges of this convex polyhedron, given as pairs of indices into `self.vertices()`.
+    ///
+    /// Each edge is reported only once, regardless of its orientation. The default
+    /// implementation returns no edge.
+    fn edges(&self) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+        Box::new(std::iter::empty())
+    }
+
     /// Returns any normal from the normal cone of the given feature.
     fn feature_normal(&self, feature: FeatureId) -> Unit<Vector<N>>;
 
@@ -80,5 +147,8 @@ pub trait ConvexPolyhedron<N: RealField>: SupportMap<N> {
     );
 
     /// Retrieve the identifier of the feature which normal cone contains `dir`.
+    ///
+    /// If `dir` lies on the boundary between several normal cones, the feature with the highest
+    /// dimension is returned, i.e., a face is preferred to an edge, and an edge to a vertex.
     fn support_feature_id_toward(&self, local_dir: &Unit<Vector<N>>) -> FeatureId;
 }
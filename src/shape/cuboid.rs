@@ -3,6 +3,7 @@
 use crate::math::{Isometry, Point, Vector, DIM};
 use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportMap};
 use na::{self, RealField, Unit};
+use std::borrow::Cow;
 use std::f64;
 
 /// Shape of a box.
@@ -27,6 +28,16 @@ impl<N: RealField> Cuboid<N> {
     pub fn new(half_extents: Vector<N>) -> Cuboid<N> {
         Cuboid { half_extents }
     }
+
+    /// Creates a new box with the half-extents of `self` multiplied by `factor`.
+    ///
+    /// # Panics
+    /// Panics if `factor` is not strictly positive.
+    #[inline]
+    pub fn scaled(&self, factor: N) -> Cuboid<N> {
+        assert!(factor > N::zero(), "The scaling factor must be positive.");
+        Cuboid::new(self.half_extents * factor)
+    }
 }
 
 impl<N: RealField> Cuboid<N> {
@@ -37,6 +48,34 @@ impl<N: RealField> Cuboid<N> {
         &self.half_extents
     }
 
+    /// The extents of this box, i.e., its full width along each axis.
+    #[inline]
+    pub fn extents(&self) -> Vector<N> {
+        self.half_extents * na::convert::<_, N>(2.0f64)
+    }
+
+    /// The four corners of this box, in its local-space.
+    ///
+    /// The `i`-th corner is the vertex identified by `FeatureId::Vertex(i)`: its `k`-th
+    /// coordinate is negative iff. the `k`-th bit of `i` is set.
+    #[cfg(feature = "dim2")]
+    #[inline]
+    pub fn corners(&self) -> [Point<N>; 4] {
+        let v = |i| self.vertex(FeatureId::Vertex(i));
+        [v(0), v(1), v(2), v(3)]
+    }
+
+    /// The eight corners of this box, in its local-space.
+    ///
+    /// The `i`-th corner is the vertex identified by `FeatureId::Vertex(i)`: its `k`-th
+    /// coordinate is negative iff. the `k`-th bit of `i` is set.
+    #[cfg(feature = "dim3")]
+    #[inline]
+    pub fn corners(&self) -> [Point<N>; 8] {
+        let v = |i| self.vertex(FeatureId::Vertex(i));
+        [v(0), v(1), v(2), v(3), v(4), v(5), v(6), v(7)]
+    }
+
     /// Checks that the given direction in world-space is on the tangent cone of the given `feature`.
     #[cfg(feature = "dim2")]
     pub fn tangent_cone_contains_dir(
@@ -159,6 +198,22 @@ impl<N: RealField> ConvexPolyhedron<N> for Cuboid<N> {
         Point::from(res)
     }
 
+    fn vertices(&self) -> Cow<'_, [Point<N>]> {
+        let vertices = (0..1usize << DIM)
+            .map(|i| self.vertex(FeatureId::Vertex(i)))
+            .collect();
+        Cow::Owned(vertices)
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+        // Two vertices share an edge if their ids differ by exactly one bit.
+        Box::new((0..1usize << DIM).flat_map(|i| {
+            (0..DIM)
+                .filter(move |k| i & (1 << k) == 0)
+                .map(move |k| (i, i | (1 << k)))
+        }))
+    }
+
     #[cfg(feature = "dim3")]
     fn edge(&self, id: FeatureId) -> (Point<N>, Point<N>, FeatureId, FeatureId) {
         let eid = id.unwrap_edge();
@@ -408,62 +463,37 @@ impl<N: RealField> ConvexPolyhedron<N> for Cuboid<N> {
         let one_degree: N = na::convert(f64::consts::PI / 180.0);
         let cang = one_degree.cos();
 
-        #[cfg(feature = "dim2")]
-        {
-            let mut support_point_id = 0;
-            for i1 in 0..2 {
-                let sign = local_dir[i1].signum();
-                if sign * local_dir[i1] >= cang {
-                    if sign > na::zero() {
-                        return FeatureId::Face(i1);
-                    } else {
-                        return FeatureId::Face(i1 + 2);
-                    }
-                } else {
-                    if sign < na::zero() {
-                        support_point_id |= 1 << i1;
-                    }
-                }
-            }
+        // Faces are checked first, then edges, then vertices, so a direction lying on the
+        // boundary between two normal cones resolves to the highest-dimensional feature. A zero
+        // component (of any sign) is considered positive so the result does not depend on the
+        // sign of zeros.
+        let mut support_point_id = 0;
 
-            // We are not on a face, return the support vertex.
-            FeatureId::Vertex(support_point_id)
+        for i in 0..DIM {
+            if local_dir[i] >= cang {
+                return FeatureId::Face(i);
+            } else if local_dir[i] <= -cang {
+                return FeatureId::Face(i + DIM);
+            } else if local_dir[i] < N::zero() {
+                support_point_id |= 1 << i;
+            }
         }
 
         #[cfg(feature = "dim3")]
         {
             let sang = one_degree.sin();
-            let mut support_point_id = 0;
 
-            // Check faces.
-            for i1 in 0..3 {
-                let sign = local_dir[i1].signum();
-                if sign * local_dir[i1] >= cang {
-                    if sign > na::zero() {
-                        return FeatureId::Face(i1);
-                    } else {
-                        return FeatureId::Face(i1 + 3);
-                    }
-                } else {
-                    if sign < na::zero() {
-                        support_point_id |= 1 << i1;
-                    }
-                }
-            }
-
-            // Check edges.
             for i in 0..3 {
-                let sign = local_dir[i].signum();
-
-                // sign * local_dir[i] <= cos(pi / 2 - angle)
-                if sign * local_dir[i] <= sang {
+                // |local_dir[i]| <= cos(pi / 2 - angle)
+                if local_dir[i].abs() <= sang {
                     let mask_i = !(1 << i); // To ensure each edge has a unique id.
                     return FeatureId::Edge(i | ((support_point_id & mask_i) << 2));
                 }
             }
-
-            FeatureId::Vertex(support_point_id)
         }
+
+        // We are not on a face nor an edge, return the support vertex.
+        FeatureId::Vertex(support_point_id)
     }
 
     #[cfg(feature = "dim2")]
@@ -2,7 +2,7 @@
 
 use crate::math::{Point, Vector};
 use crate::shape::SupportMap;
-use na::{self, RealField};
+use na::{self, RealField, Unit};
 
 /// SupportMap description of a cylinder shape with its principal axis aligned with the `y` axis.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -36,6 +36,12 @@ impl<N: RealField> Cylinder<N> {
         self.half_height
     }
 
+    /// The local symmetry axis of this cylinder, i.e., the local `y` axis.
+    #[inline]
+    pub fn axis(&self) -> Unit<Vector<N>> {
+        Vector::y_axis()
+    }
+
     /// The radius of the cylinder along all but the `y` axis.
     #[inline]
     #[deprecated(note = "use the `self.radius` field directly.")]
@@ -109,6 +109,14 @@ impl<N: RealField> HeightField<N> {
         }
     }
 
+    /// Index of the cell a point is on after vertical projection.
+    ///
+    /// If the point is outside of the heightfield bounds, this is the index of the closest cell.
+    pub fn closest_cell_at_point(&self, pt: &Point2<N>) -> usize {
+        let scaled_pt = pt.coords.component_div(&self.scale);
+        self.quantize_floor(scaled_pt.x, self.unit_cell_width())
+    }
+
     /// Iterator through all the segments of this heightfield.
     pub fn segments<'a>(&'a self) -> impl Iterator<Item = Segment<N>> + 'a {
         // FIXME: this is not very efficient since this wil
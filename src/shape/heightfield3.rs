@@ -129,6 +129,16 @@ impl<N: RealField> HeightField<N> {
         }
     }
 
+    /// The pair of index of the cell containing the vertical projection of the given point.
+    ///
+    /// If the point is outside of the heightfield bounds, this is the index of the closest cell.
+    pub fn closest_cell_at_point(&self, pt: &Point3<N>) -> (usize, usize) {
+        let scaled_pt = pt.coords.component_div(&self.scale);
+        let j = self.quantize_floor(scaled_pt.x, self.unit_cell_width(), self.ncols());
+        let i = self.quantize_floor(scaled_pt.z, self.unit_cell_height(), self.nrows());
+        (i, j)
+    }
+
     /// The smallest x coordinate of the `j`-th column of this heightfield.
     pub fn x_at(&self, j: usize) -> N {
         let _0_5: N = na::convert(0.5);
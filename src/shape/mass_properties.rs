@@ -0,0 +1,27 @@
+use crate::math::{AngularInertia, Point};
+use na::RealField;
+
+/// The mass properties of a shape with a uniform density.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct MassProperties<N: RealField> {
+    /// The mass of the shape.
+    pub mass: N,
+    /// The center of mass of the shape, expressed in its local-space.
+    pub local_com: Point<N>,
+    /// The angular inertia of the shape with respect to its center of mass, expressed in its
+    /// local-space.
+    pub angular_inertia: AngularInertia<N>,
+}
+
+impl<N: RealField> MassProperties<N> {
+    /// Creates new mass properties from the mass, center of mass, and angular inertia of a shape.
+    #[inline]
+    pub fn new(mass: N, local_com: Point<N>, angular_inertia: AngularInertia<N>) -> Self {
+        MassProperties {
+            mass,
+            local_com,
+            angular_inertia,
+        }
+    }
+}
@@ -26,8 +26,10 @@ pub use self::deformable_shape::{DeformableShape, DeformationsType};
 pub use self::heightfield2::HeightField;
 #[cfg(feature = "dim3")]
 pub use self::heightfield3::{HeightField, HeightFieldCellStatus};
+pub use self::mass_properties::MassProperties;
 pub use self::plane::Plane;
 pub use self::polyline::Polyline;
+pub use self::rounded::Rounded;
 pub use self::segment::{Segment, SegmentPointLocation};
 #[doc(inline)]
 pub use self::shape::{Shape, ShapeHandle};
@@ -63,8 +65,10 @@ mod deformable_shape;
 mod heightfield2;
 #[cfg(feature = "dim3")]
 mod heightfield3;
+mod mass_properties;
 mod plane;
 mod polyline;
+mod rounded;
 mod segment;
 #[doc(hidden)]
 pub mod shape;
@@ -1,20 +1,43 @@
 //! Support mapping based Plane shape.
-use crate::math::Vector;
+use crate::math::{Point, Vector};
 use na::{RealField, Unit};
 
 /// SupportMap description of a plane.
+///
+/// The plane is the boundary of the half-space of the points `x` of its local-space such that
+/// `normal.dot(x) <= offset`.
 #[derive(PartialEq, Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Plane<N: RealField> {
     /// The plane normal.
     pub normal: Unit<Vector<N>>,
+    /// The signed distance from the local-space origin to the plane, along the plane normal.
+    ///
+    /// A positive offset moves the plane toward its normal, and a negative offset moves it away
+    /// from its normal. It defaults to zero when deserializing a plane serialized without offset.
+    #[cfg_attr(feature = "serde", serde(default = "N::zero"))]
+    pub offset: N,
 }
 
 impl<N: RealField> Plane<N> {
-    /// Builds a new plane from its center and its normal.
+    /// Builds a new plane from its normal, passing through the local-space origin.
     #[inline]
     pub fn new(normal: Unit<Vector<N>>) -> Plane<N> {
-        Plane { normal }
+        Plane::with_offset(normal, N::zero())
+    }
+
+    /// Builds a new plane from its normal and its signed distance from the local-space origin.
+    ///
+    /// A positive `offset` moves the plane toward `normal`.
+    #[inline]
+    pub fn with_offset(normal: Unit<Vector<N>>, offset: N) -> Plane<N> {
+        Plane { normal, offset }
+    }
+
+    /// The point of this plane closest to its local-space origin.
+    #[inline]
+    pub fn center(&self) -> Point<N> {
+        Point::from(*self.normal * self.offset)
     }
 
     /// The plane normal.
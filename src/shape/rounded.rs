@@ -0,0 +1,40 @@
+use crate::math::{Point, Vector};
+use crate::shape::SupportMap;
+use na::{RealField, Unit};
+
+/// A shape dilated by a sphere, i.e., the Minkowski sum of a shape and of a ball.
+///
+/// The boundary of a rounded shape is the offset surface of the boundary of its inner shape
+/// at a distance equal to the rounding radius.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Rounded<S, N: RealField> {
+    /// The inner shape being rounded.
+    pub shape: S,
+    /// The radius of the rounding, i.e., the thickness of the margin added around `self.shape`.
+    pub radius: N,
+}
+
+impl<S, N: RealField> Rounded<S, N> {
+    /// Creates a new shape by rounding `shape` with a margin of thickness `radius`.
+    ///
+    /// # Panics
+    /// Panics if `radius` is negative.
+    #[inline]
+    pub fn new(shape: S, radius: N) -> Rounded<S, N> {
+        assert!(radius >= N::zero(), "The rounding radius must be positive.");
+        Rounded { shape, radius }
+    }
+}
+
+impl<S: SupportMap<N>, N: RealField> SupportMap<N> for Rounded<S, N> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<N>) -> Point<N> {
+        self.local_support_point_toward(&Unit::new_normalize(*dir))
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<N>>) -> Point<N> {
+        self.shape.local_support_point_toward(dir) + **dir * self.radius
+    }
+}
@@ -5,6 +5,7 @@ use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId, SupportM
 #[cfg(feature = "dim2")]
 use crate::utils;
 use na::{self, RealField, Unit};
+use std::borrow::Cow;
 use std::f64;
 use std::mem;
 
@@ -199,6 +200,14 @@ impl<N: RealField> ConvexPolyhedron<N> for Segment<N> {
         }
     }
 
+    fn vertices(&self) -> Cow<'_, [Point<N>]> {
+        Cow::Owned(vec![self.a, self.b])
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+        Box::new(std::iter::once((0, 1)))
+    }
+
     #[cfg(feature = "dim3")]
     fn edge(&self, _: FeatureId) -> (Point<N>, Point<N>, FeatureId, FeatureId) {
         (self.a, self.b, FeatureId::Vertex(0), FeatureId::Vertex(1))
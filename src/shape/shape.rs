@@ -146,6 +146,33 @@ impl<N: RealField> dyn Shape<N> {
     pub fn as_shape<T: Shape<N>>(&self) -> Option<&T> {
         self.downcast_ref()
     }
+
+    /// Casts `a` to `A` and `b` to `B`.
+    ///
+    /// Returns `None` if any of those casts fail.
+    #[inline]
+    pub fn downcast_pair<'a, A: Shape<N>, B: Shape<N>>(
+        a: &'a dyn Shape<N>,
+        b: &'a dyn Shape<N>,
+    ) -> Option<(&'a A, &'a B)> {
+        Some((a.as_shape()?, b.as_shape()?))
+    }
+
+    /// Casts the pair `(a, b)`, or the swapped pair `(b, a)`, to `(A, B)`.
+    ///
+    /// The returned boolean is `true` if the swapped pair matched, i.e., if `a` is a `B` and `b`
+    /// is an `A`. The non-swapped ordering is tried first.
+    #[inline]
+    pub fn downcast_pair_unordered<'a, A: Shape<N>, B: Shape<N>>(
+        a: &'a dyn Shape<N>,
+        b: &'a dyn Shape<N>,
+    ) -> Option<(&'a A, &'a B, bool)> {
+        if let Some((a, b)) = Self::downcast_pair(a, b) {
+            Some((a, b, false))
+        } else {
+            Self::downcast_pair(b, a).map(|(b, a)| (b, a, true))
+        }
+    }
 }
 
 /// A shared handle to an abstract shape.
@@ -1,11 +1,11 @@
-use crate::bounding_volume::{self, BoundingSphere, AABB};
+use crate::bounding_volume::{self, BoundingSphere, HasBoundingVolume, AABB};
 use crate::math::{Isometry, Vector};
 use crate::query::{PointQuery, RayCast};
 #[cfg(feature = "dim2")]
 use crate::shape::ConvexPolygon;
 use crate::shape::{
     Ball, Capsule, CompositeShape, Compound, ConvexPolyhedron, Cuboid, DeformableShape, FeatureId,
-    HeightField, Plane, Polyline, Segment, Shape, SupportMap,
+    HeightField, Plane, Polyline, Rounded, Segment, Shape, SupportMap,
 };
 #[cfg(feature = "dim3")]
 use crate::shape::{ConvexHull, TriMesh, Triangle};
@@ -161,6 +161,33 @@ impl<N: RealField> Shape<N> for Capsule<N> {
     }
 }
 
+impl<S, N> Shape<N> for Rounded<S, N>
+where
+    S: Shape<N>
+        + SupportMap<N>
+        + PointQuery<N>
+        + HasBoundingVolume<N, AABB<N>>
+        + HasBoundingVolume<N, BoundingSphere<N>>
+        + Clone,
+    N: RealField,
+{
+    impl_shape_common!();
+    impl_as_support_map!();
+
+    // FIXME: this is wrong in theory but keep it this
+    // way for now because of the way the ContactKinematic
+    // currently works.
+    fn tangent_cone_contains_dir(
+        &self,
+        _: FeatureId,
+        _: &Isometry<N>,
+        _: Option<&[N]>,
+        _: &Unit<Vector<N>>,
+    ) -> bool {
+        false
+    }
+}
+
 #[cfg(feature = "dim3")]
 impl<N: RealField> Shape<N> for ConvexHull<N> {
     impl_shape_common!();
@@ -2,6 +2,7 @@
 
 use crate::math::{Matrix, Point};
 use crate::shape::{Segment, Triangle};
+use crate::utils;
 use na::RealField;
 use std::mem;
 
@@ -131,6 +132,12 @@ impl<N: RealField> Tetrahedron<N> {
         &self.d
     }
 
+    /// The center of this tetrahedron, i.e., the average of its four vertices.
+    #[inline]
+    pub fn center(&self) -> Point<N> {
+        utils::tetrahedron_center(&self.a, &self.b, &self.c, &self.d)
+    }
+
     /// Returns the i-th face of this tetrahedron.
     ///
     /// The 0-th face is the triangle ABC.
@@ -205,11 +212,24 @@ impl<N: RealField> Tetrahedron<N> {
 
     /// Computes the barycentric coordinates of the given point in the coordinate system of this tetrahedron.
     ///
-    /// Returns `None` if this tetrahedron is degenerate.
+    /// The coordinates are signed: the `i`-th coordinate is negative iff `p` is on the other side
+    /// of the face opposite to the `i`-th vertex. Thus `p` is inside of this tetrahedron iff all
+    /// its coordinates are non-negative.
+    ///
+    /// Returns `None` if this tetrahedron is degenerate, i.e., flat up to a small relative tolerance.
     pub fn barycentric_coordinates(&self, p: &Point<N>) -> Option<[N; 4]> {
         let ab = self.b - self.a;
         let ac = self.c - self.a;
         let ad = self.d - self.a;
+
+        // The determinant alone is not scale-independent so compare it with the edge lengths.
+        let det = ab.dot(&ac.cross(&ad));
+        let scale = ab.norm() * ac.norm() * ad.norm();
+
+        if det.abs() <= N::default_epsilon().sqrt() * scale {
+            return None;
+        }
+
         let m = Matrix::new(ab.x, ac.x, ad.x, ab.y, ac.y, ad.y, ab.z, ac.z, ad.z);
 
         m.try_inverse().map(|im| {
@@ -5,9 +5,12 @@ use crate::shape::Segment;
 use crate::shape::SupportMap;
 #[cfg(feature = "dim3")]
 use crate::shape::{ConvexPolygonalFeature, ConvexPolyhedron, FeatureId};
+use crate::utils;
 use na::RealField;
 use na::{self, Unit};
 #[cfg(feature = "dim3")]
+use std::borrow::Cow;
+#[cfg(feature = "dim3")]
 use std::f64;
 use std::mem;
 
@@ -129,6 +132,12 @@ impl<N: RealField> Triangle<N> {
         unsafe { mem::transmute(self) }
     }
 
+    /// The center of this triangle, i.e., the average of its three vertices.
+    #[inline]
+    pub fn center(&self) -> Point<N> {
+        utils::triangle_center(&self.a, &self.b, &self.c)
+    }
+
     /// The normal of this triangle assuming it is oriented ccw.
     ///
     /// The normal points such that it is collinear to `AB × AC` (where `×` denotes the cross
@@ -320,6 +329,15 @@ impl<N: RealField> ConvexPolyhedron<N> for Triangle<N> {
             _ => panic!("Triangle vertex index out of bounds."),
         }
     }
+
+    fn vertices(&self) -> Cow<'_, [Point<N>]> {
+        Cow::Borrowed(&Triangle::vertices(self)[..])
+    }
+
+    fn edges(&self) -> Box<dyn Iterator<Item = (usize, usize)> + '_> {
+        Box::new([(0, 1), (1, 2), (2, 0)].iter().cloned())
+    }
+
     fn edge(&self, id: FeatureId) -> (Point<N>, Point<N>, FeatureId, FeatureId) {
         match id.unwrap_edge() {
             0 => (self.a, self.b, FeatureId::Vertex(0), FeatureId::Vertex(1)),
@@ -1,6 +1,6 @@
 //! 2d line strip, 3d triangle mesh, and nd subsimplex mesh.
 
-use crate::bounding_volume::{self, BoundingVolume, AABB};
+use crate::bounding_volume::{self, BoundingVolume, PolyhedralCone, AABB};
 use crate::math::{Isometry, Point, Vector, DIM};
 use crate::partitioning::{BVHImpl, BVT};
 use crate::procedural;
@@ -26,6 +26,7 @@ struct DeformationInfos<N: RealField> {
     timestamps: Vec<usize>,
     ref_vertices: Vec<Point<N>>,
     tri_to_update: Vec<usize>,
+    vtx_to_update: Vec<usize>,
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -91,6 +92,7 @@ pub struct TriMesh<N: RealField> {
     faces: Vec<TriMeshFace<N>>,
     adj_face_list: Vec<usize>,
     adj_vertex_list: Vec<usize>,
+    vertex_normal_cones: Vec<PolyhedralCone<N>>,
     deformations: DeformationInfos<N>,
     oriented: bool,
 }
@@ -180,9 +182,10 @@ impl<N: RealField> TriMesh<N> {
             timestamps: Vec::new(),
             ref_vertices: Vec::new(),
             tri_to_update: Vec::new(),
+            vtx_to_update: Vec::new(),
         };
 
-        TriMesh {
+        let mut res = TriMesh {
             bvt,
             points,
             uvs,
@@ -192,8 +195,29 @@ impl<N: RealField> TriMesh<N> {
             faces,
             adj_face_list,
             adj_vertex_list,
+            vertex_normal_cones: Vec::new(),
             oriented: false,
-        }
+        };
+
+        res.update_vertex_normal_cones();
+        res
+    }
+
+    fn update_vertex_normal_cones(&mut self) {
+        let faces = &self.faces;
+        let adj_face_list = &self.adj_face_list;
+
+        self.vertex_normal_cones = self
+            .vertices
+            .iter()
+            .map(|v| {
+                let normals = adj_face_list[v.adj_faces.clone()]
+                    .iter()
+                    .filter_map(|f| faces[*f].normal)
+                    .collect();
+                PolyhedralCone::new(normals)
+            })
+            .collect();
     }
 
     fn create_edges_list(indices: &[Point3<usize>]) -> Vec<TriMeshEdge> {
@@ -491,6 +515,14 @@ impl<N: RealField> TriMesh<N> {
         &self.bvt
     }
 
+    /// The cone spanned by the normals of the faces adjacent to the `i`-th vertex of this mesh.
+    ///
+    /// Degenerate adjacent faces are ignored.
+    #[inline]
+    pub fn vertex_normal_cone(&self, i: usize) -> &PolyhedralCone<N> {
+        &self.vertex_normal_cones[i]
+    }
+
     /// Tests that the given `dir` is on the tangent cone of the `i`th vertex
     /// of this mesh.
     pub fn vertex_tangent_cone_contains_dir(
@@ -738,6 +770,76 @@ impl<N: RealField> TriMesh<N> {
         }
     }
 
+    /// Clamps the direction `dir` into the normal cone of the given feature of this mesh.
+    ///
+    /// The direction is returned unchanged if it already lies in the normal cone. Otherwise the
+    /// normal of the adjacent face closest to `dir` is returned (negated if `dir` points toward
+    /// the back of this face). For a vertex, `dir` is instead projected on
+    /// `self.vertex_normal_cone(i)`, or on its opposite if `dir` points toward the back of the
+    /// adjacent faces. This is used to prevent contact normals from pointing out of the
+    /// neighboring faces at internal edges and corners of the mesh.
+    pub fn clamp_dir_to_normal_cone(
+        &self,
+        feature: FeatureId,
+        dir: &Unit<Vector<N>>,
+    ) -> Unit<Vector<N>> {
+        let nfaces = self.faces.len();
+
+        match feature {
+            FeatureId::Face(i) => {
+                let normal = self.faces[i % nfaces].normal;
+
+                match normal {
+                    Some(n) if i >= nfaces => -n,
+                    Some(n) => n,
+                    None => *dir,
+                }
+            }
+            FeatureId::Edge(i) => {
+                let e = &self.edges[i];
+                let edge_dir = self.points[e.indices.y] - self.points[e.indices.x];
+                let orth_dir = Unit::try_new(
+                    **dir - edge_dir * (edge_dir.dot(dir) / edge_dir.norm_squared()),
+                    N::default_epsilon(),
+                );
+
+                if let Some(orth_dir) = orth_dir {
+                    if self.edge_tangent_cone_polar_contains_orthogonal_dir(i, &orth_dir, N::zero())
+                    {
+                        return orth_dir;
+                    }
+                }
+
+                let faces = [e.adj_faces.0.face_id, e.adj_faces.1.face_id];
+                self.closest_face_normal(&faces, dir)
+            }
+            FeatureId::Vertex(i) => {
+                let cone = &self.vertex_normal_cones[i];
+                cone.clamp_dir(dir)
+                    .or_else(|| cone.clamp_dir(&-*dir).map(|n| -n))
+                    .unwrap_or(*dir)
+            }
+            FeatureId::Unknown => *dir,
+        }
+    }
+
+    // The normal of one of the given faces, or its opposite, that is the closest to `dir`.
+    fn closest_face_normal(&self, faces: &[usize], dir: &Unit<Vector<N>>) -> Unit<Vector<N>> {
+        let mut best = *dir;
+        let mut best_dot = -N::one();
+
+        for n in faces.iter().filter_map(|f| self.faces[*f].normal) {
+            let dot = n.dot(dir);
+
+            if dot.abs() > best_dot {
+                best_dot = dot.abs();
+                best = if dot < N::zero() { -n } else { n };
+            }
+        }
+
+        best
+    }
+
     fn init_deformation_infos(&mut self) -> bool {
         if self.deformations.ref_vertices.is_empty() {
             self.deformations.timestamps = iter::repeat(0).take(self.faces.len()).collect();
@@ -839,7 +941,16 @@ impl<N: RealField> DeformableShape<N> for TriMesh<N> {
             let ab = self.points[f.indices.y] - self.points[f.indices.x];
             let ac = self.points[f.indices.z] - self.points[f.indices.x];
 
-            if let Some(n) = Unit::try_new(ab.cross(&ac), N::default_epsilon()) {
+            let normal = Unit::try_new(ab.cross(&ac), N::default_epsilon());
+
+            if normal != f.normal {
+                // The normal cones of the vertices of this face must be rebuilt.
+                self.deformations
+                    .vtx_to_update
+                    .extend_from_slice(f.indices.coords.as_slice());
+            }
+
+            if let Some(n) = normal {
                 let bc = self.points[f.indices.z] - self.points[f.indices.y];
                 f.normal = Some(n);
                 f.side_normals = Some([
@@ -853,6 +964,18 @@ impl<N: RealField> DeformableShape<N> for TriMesh<N> {
             }
         }
 
+        // Update the normal cones of the vertices adjacent to a modified face.
+        self.deformations.vtx_to_update.sort_unstable();
+        self.deformations.vtx_to_update.dedup();
+
+        for i in self.deformations.vtx_to_update.drain(..) {
+            let faces = &self.faces;
+            let normals = self.adj_face_list[self.vertices[i].adj_faces.clone()]
+                .iter()
+                .filter_map(|f| faces[*f].normal);
+            self.vertex_normal_cones[i].set_generators(normals);
+        }
+
         // Apply the bounding volumes changes.
         for tri_id in self.deformations.tri_to_update.drain(..) {
             if self.deformations.timestamps[tri_id] != self.deformations.curr_timestamp {
@@ -8,7 +8,26 @@ use simba::scalar::RealField;
 impl<N: RealField> ToTriMesh<N> for Ball<N> {
     type DiscretizationParameter = (u32, u32);
 
+    /// Builds a closed UV sphere without texture coordinates.
+    ///
+    /// Texture coordinates would require duplicating the vertices along a seam, leaving the mesh
+    /// open. Use `Ball::to_trimesh_with_uvs` to generate them.
     fn to_trimesh(&self, (ntheta_subdiv, nphi_subdiv): (u32, u32)) -> TriMesh<N> {
+        procedural::sphere(
+            self.radius * na::convert(2.0f64),
+            ntheta_subdiv,
+            nphi_subdiv,
+            false,
+        )
+    }
+}
+
+impl<N: RealField> Ball<N> {
+    /// Builds a UV sphere with texture coordinates.
+    ///
+    /// This is what `to_trimesh` generated before it was made watertight: the vertices along the
+    /// texture seam are duplicated, so the resulting mesh is not closed.
+    pub fn to_trimesh_with_uvs(&self, ntheta_subdiv: u32, nphi_subdiv: u32) -> TriMesh<N> {
         procedural::sphere(
             self.radius * na::convert(2.0f64),
             ntheta_subdiv,
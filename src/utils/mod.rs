@@ -15,14 +15,17 @@ pub use self::point_cloud_support_point::{
 };
 pub use self::point_in_poly2d::point_in_poly2d;
 pub use self::ref_with_cost::RefWithCost;
-pub use self::sort::{sort2, sort3};
+pub use self::sort::{sort2, sort3, sort3_by};
 pub use self::sorted_pair::SortedPair;
+pub use self::tetrahedron::tetrahedron_center;
 #[cfg(feature = "dim3")]
-pub use self::tetrahedron::{tetrahedron_center, tetrahedron_signed_volume, tetrahedron_volume};
+pub use self::tetrahedron::{tetrahedron_signed_volume, tetrahedron_volume};
+pub use self::transform_points::transform_points;
 #[cfg(feature = "dim3")]
 pub use self::triangle::is_affinely_dependent_triangle;
 pub use self::triangle::{
-    circumcircle, is_point_in_triangle, triangle_area, triangle_center, triangle_perimeter,
+    circumcircle, is_point_in_triangle, is_point_in_triangle_eps, triangle_area, triangle_center,
+    triangle_perimeter,
 };
 
 #[doc(hidden)]
@@ -40,6 +43,6 @@ mod point_in_poly2d;
 mod ref_with_cost;
 mod sort;
 mod sorted_pair;
-#[cfg(feature = "dim3")]
 mod tetrahedron;
+mod transform_points;
 mod triangle;
@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 // FIXME: do this out-of-place?
 
 /// Sorts two values in increasing order.
@@ -13,9 +15,21 @@ pub fn sort2<'a, N: PartialOrd>(a: &'a N, b: &'a N) -> (&'a N, &'a N) {
 /// Sorts a set of three values in increasing order.
 #[inline]
 pub fn sort3<'a, N: PartialOrd>(a: &'a N, b: &'a N, c: &'a N) -> (&'a N, &'a N, &'a N) {
-    let a_b = *a > *b;
-    let a_c = *a > *c;
-    let b_c = *b > *c;
+    sort3_by(a, b, c, |x, y| x.partial_cmp(y).unwrap_or(Ordering::Equal))
+}
+
+/// Sorts a set of three values in increasing order, according to the given comparator.
+///
+/// This sort is stable: values that compare equal keep their relative order. The comparisons
+/// performed do not depend on the previous ones so the result is deterministic.
+#[inline]
+pub fn sort3_by<'a, T, F>(a: &'a T, b: &'a T, c: &'a T, mut compare: F) -> (&'a T, &'a T, &'a T)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let a_b = compare(a, b) == Ordering::Greater;
+    let a_c = compare(a, c) == Ordering::Greater;
+    let b_c = compare(b, c) == Ordering::Greater;
 
     let sa;
     let sb;
@@ -1,11 +1,12 @@
-use num;
-
-use na::{self, Matrix3, RealField};
+use na::RealField;
+#[cfg(feature = "dim3")]
+use na::{self, Matrix3};
 
 use crate::math::Point;
 use crate::utils;
 
 /// Computes the volume of a tetrahedron.
+#[cfg(feature = "dim3")]
 #[inline]
 pub fn tetrahedron_volume<N: RealField>(
     p1: &Point<N>,
@@ -20,6 +21,7 @@ pub fn tetrahedron_volume<N: RealField>(
 ///
 /// If it is positive, `p4` is on the half-space pointed by the normal of the oriented triangle
 /// `(p1, p2, p3)`.
+#[cfg(feature = "dim3")]
 #[inline]
 pub fn tetrahedron_signed_volume<N: RealField>(
     p1: &Point<N>,
@@ -0,0 +1,25 @@
+use crate::math::{Isometry, Point};
+use na::RealField;
+
+/// Applies the isometry `m` to each point of `points` and writes the results to `out`.
+///
+/// The rotation matrix of `m` is computed once so the loop only performs a matrix-vector product
+/// and a translation per point, which the compiler is able to vectorize.
+///
+/// # Panics
+/// Panics if `points` and `out` do not have the same length.
+#[inline]
+pub fn transform_points<N: RealField>(m: &Isometry<N>, points: &[Point<N>], out: &mut [Point<N>]) {
+    assert_eq!(
+        points.len(),
+        out.len(),
+        "The input and output point slices must have the same length."
+    );
+
+    let rot = m.rotation.to_rotation_matrix();
+    let tra = m.translation.vector;
+
+    for (res, pt) in out.iter_mut().zip(points.iter()) {
+        *res = Point::from(rot.matrix() * pt.coords + tra);
+    }
+}
@@ -94,30 +94,53 @@ pub fn is_affinely_dependent_triangle<N: RealField>(
 }
 
 /// Tests if a point is inside of a triangle.
+///
+/// This is the same as `is_point_in_triangle_eps(p, p1, p2, p3, 0)`.
 pub fn is_point_in_triangle<N: RealField>(
     p: &Point<N>,
     p1: &Point<N>,
     p2: &Point<N>,
     p3: &Point<N>,
 ) -> bool {
-    let p1p2 = *p2 - *p1;
-    let p2p3 = *p3 - *p2;
-    let p3p1 = *p1 - *p3;
-
-    let p1p = *p - *p1;
-    let p2p = *p - *p2;
-    let p3p = *p - *p3;
-
-    let d11 = p1p.dot(&p1p2);
-    let d12 = p2p.dot(&p2p3);
-    let d13 = p3p.dot(&p3p1);
-
-    d11 >= na::zero()
-        && d11 <= p1p2.norm_squared()
-        && d12 >= na::zero()
-        && d12 <= p2p3.norm_squared()
-        && d13 >= na::zero()
-        && d13 <= p3p1.norm_squared()
+    is_point_in_triangle_eps(p, p1, p2, p3, N::zero())
+}
+
+/// Tests if a point is inside of a triangle, up to a distance tolerance.
+///
+/// The points at a distance smaller than `eps` from the boundary of the triangle are considered
+/// inside, so that points on an edge shared by two triangles are classified consistently despite
+/// rounding errors. In 3D, the point is projected on the plane of the triangle first. Always
+/// returns `false` if the triangle is degenerate.
+pub fn is_point_in_triangle_eps<N: RealField>(
+    p: &Point<N>,
+    p1: &Point<N>,
+    p2: &Point<N>,
+    p3: &Point<N>,
+    eps: N,
+) -> bool {
+    let edges = [(p1, p2, p3), (p2, p3, p1), (p3, p1, p2)];
+
+    for (a, b, opposite) in edges.iter() {
+        let ab = **b - **a;
+        let ao = **opposite - **a;
+        let ab_sqnorm = ab.norm_squared();
+
+        if ab_sqnorm.is_zero() {
+            return false;
+        }
+
+        // Unit vector orthogonal to `ab` in the triangle plane, pointing toward its interior.
+        let inward = match (ao - ab * (ao.dot(&ab) / ab_sqnorm)).try_normalize(N::zero()) {
+            Some(inward) => inward,
+            None => return false,
+        };
+
+        if (*p - **a).dot(&inward) < -eps {
+            return false;
+        }
+    }
+
+    true
 }
 
 #[cfg(feature = "dim3")]
@@ -133,4 +156,68 @@ mod test {
 
         assert!(relative_eq!(super::triangle_area(&pa, &pb, &pc), 10.0));
     }
+
+    #[test]
+    fn test_point_in_triangle_eps() {
+        let pa = Point3::new(0.0f64, 0.0, 0.0);
+        let pb = Point3::new(4.0f64, 0.0, 0.0);
+        let pc = Point3::new(0.0f64, 0.0, 3.0);
+        let eps = 1.0e-6;
+
+        let just_inside = Point3::new(2.0, 0.0, 1.0e-8);
+        let on_edge_with_noise = Point3::new(2.0, 0.0, -1.0e-8);
+        let just_outside = Point3::new(2.0, 0.0, -1.0e-3);
+
+        assert!(super::is_point_in_triangle_eps(
+            &just_inside,
+            &pa,
+            &pb,
+            &pc,
+            eps
+        ));
+        assert!(super::is_point_in_triangle_eps(
+            &on_edge_with_noise,
+            &pa,
+            &pb,
+            &pc,
+            eps
+        ));
+        assert!(!super::is_point_in_triangle(
+            &on_edge_with_noise,
+            &pa,
+            &pb,
+            &pc
+        ));
+        assert!(!super::is_point_in_triangle_eps(
+            &just_outside,
+            &pa,
+            &pb,
+            &pc,
+            eps
+        ));
+
+        for vertex in [pa, pb, pc].iter() {
+            assert!(super::is_point_in_triangle_eps(vertex, &pa, &pb, &pc, eps));
+        }
+    }
+
+    #[test]
+    fn test_point_in_obtuse_triangle() {
+        let pa = Point3::new(0.0f64, 0.0, 0.0);
+        let pb = Point3::new(4.0f64, 0.0, 0.0);
+        let pc = Point3::new(-1.0f64, 1.0, 0.0);
+
+        assert!(super::is_point_in_triangle(
+            &Point3::new(0.5, 0.2, 0.0),
+            &pa,
+            &pb,
+            &pc
+        ));
+        assert!(!super::is_point_in_triangle(
+            &Point3::new(-0.5, 0.2, 0.0),
+            &pa,
+            &pb,
+            &pc
+        ));
+    }
 }